@@ -0,0 +1,337 @@
+//! Validators run on every registration and login request, so every public
+//! `check_*` function, policy `check` and bool helper is measured here.
+//!
+//! The `validate_*(ctx, ..)` HTTP wrappers are left out on purpose. Each one calls
+//! a function measured below and does extra work only on failure: it builds the
+//! `HttpFailResult` body and adds `traceId`. The success path costs the same as
+//! the wrapped function. They also need an `HttpContext`, which is built from
+//! a hyper request of the http server, so the benches stay on the plain API.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rest_api_wl_shared::*;
+use rust_common::country_code::CountryCode;
+
+fn text_validators(c: &mut Criterion) {
+    let mut group = c.benchmark_group("text");
+
+    group.bench_function("validate_latin_letters_only", |b| {
+        b.iter(|| validate_latin_letters_only(black_box("Jean-Luc")))
+    });
+    group.bench_function("validate_latin_letters_with_spaces", |b| {
+        b.iter(|| validate_latin_letters_with_spaces(black_box("Jean-Luc Picard")))
+    });
+    group.bench_function("validate_no_cyrillic", |b| {
+        b.iter(|| validate_no_cyrillic(black_box("Baker Street 221B")))
+    });
+    group.bench_function("validate_no_trimm_spaces", |b| {
+        b.iter(|| validate_no_trimm_spaces(black_box("Baker Street 221B")))
+    });
+    group.bench_function("validate_non_empty_text", |b| {
+        b.iter(|| validate_non_empty_text(black_box("Baker Street 221B")))
+    });
+    group.bench_function("validate_max", |b| {
+        b.iter(|| validate_max(black_box("Baker Street 221B"), 50))
+    });
+    group.bench_function("validate_min", |b| {
+        b.iter(|| validate_min(black_box("Baker Street 221B"), 3))
+    });
+    group.bench_function("contains_upper_letter", |b| {
+        b.iter(|| contains_upper_letter(black_box("valid123!X")))
+    });
+    group.bench_function("contains_no_space_characters", |b| {
+        b.iter(|| contains_no_space_characters(black_box("Valid123!")))
+    });
+    group.bench_function("contains_special_symbol", |b| {
+        b.iter(|| contains_special_symbol(black_box("Valid123!")))
+    });
+    group.bench_function("check_non_empty", |b| {
+        b.iter(|| check_non_empty(black_box("value")))
+    });
+    group.bench_function("check_max_len", |b| {
+        b.iter(|| check_max_len(black_box("value"), 10))
+    });
+    group.bench_function("check_min_len", |b| {
+        b.iter(|| check_min_len(black_box("value"), 3))
+    });
+    group.finish();
+}
+
+fn url_validators(c: &mut Criterion) {
+    let mut group = c.benchmark_group("url");
+
+    let redirect_policy = RedirectPolicy {
+        allowed_domains: vec!["example.com".to_string()],
+        ..Default::default()
+    };
+
+    group.bench_function("check_url", |b| {
+        b.iter(|| check_url(black_box("https://example.com/deposit?id=1"), HTTP_SCHEMES))
+    });
+    group.bench_function("RedirectPolicy::check", |b| {
+        b.iter(|| redirect_policy.check(black_box("https://app.example.com/deposit?id=1")))
+    });
+
+    group.finish();
+}
+
+fn email_validators(c: &mut Criterion) {
+    let mut group = c.benchmark_group("email");
+
+    let mut domains = DisposableEmailDomains::bundled();
+    domains.add("fresh-temp.io");
+
+    group.bench_function("check_email", |b| {
+        b.iter(|| check_email(black_box("john.doe+promo@gmail.com")))
+    });
+    group.bench_function("check_email/idn", |b| {
+        b.iter(|| check_email(black_box("info@bücher.de")))
+    });
+    group.bench_function("EmailAddress::parse", |b| {
+        b.iter(|| EmailAddress::parse(black_box("john.doe+promo@gmail.com")))
+    });
+    group.bench_function("get_canonical_email", |b| {
+        b.iter(|| get_canonical_email(black_box("John.Doe+promo@Gmail.com")))
+    });
+    group.bench_function("check_email_not_disposable", |b| {
+        b.iter(|| check_email_not_disposable(black_box("john@mailinator.com")))
+    });
+    group.bench_function("check_email_not_disposable_with_list", |b| {
+        b.iter(|| check_email_not_disposable_with_list(black_box("john@fresh-temp.io"), &domains))
+    });
+    group.bench_function("is_disposable_email_domain", |b| {
+        b.iter(|| is_disposable_email_domain(black_box("eu.mailinator.com")))
+    });
+
+    group.finish();
+}
+
+fn password_validators(c: &mut Criterion) {
+    let mut group = c.benchmark_group("password");
+
+    let policy = PasswordPolicy {
+        require_lower: true,
+        require_digit: true,
+        disallow_user_identifiers: true,
+        max_repeated_chars: Some(3),
+        ..Default::default()
+    };
+    let identifiers = PasswordUserIdentifiers {
+        username: Some("johndoe"),
+        email: Some("john.doe@gmail.com"),
+    };
+
+    group.bench_function("check_password", |b| {
+        b.iter(|| check_password(black_box("Valid123!")))
+    });
+    group.bench_function("validate_password_conditions", |b| {
+        b.iter(|| validate_password_conditions(black_box("Valid123!")))
+    });
+    group.bench_function("PasswordPolicy::check", |b| {
+        b.iter(|| policy.check(black_box("Valid123!"), &identifiers))
+    });
+    group.bench_function("is_common_password", |b| {
+        b.iter(|| is_common_password(black_box("Valid123!")))
+    });
+    group.bench_function("check_password_strength", |b| {
+        b.iter(|| check_password_strength(black_box("Kx!sdfghjk"), 3, &["johndoe"]))
+    });
+
+    group.finish();
+}
+
+fn person_validators(c: &mut Criterion) {
+    let mut group = c.benchmark_group("person");
+
+    let name_policy = NamePolicy {
+        allowed_scripts: vec![NameScript::Latin, NameScript::Cyrillic],
+        ..Default::default()
+    };
+    let date_of_birth_policy = DateOfBirthPolicy::default();
+    let today = CalendarDate::new(2024, 6, 15).unwrap();
+
+    group.bench_function("check_name", |b| {
+        b.iter(|| check_name(black_box("Jhon Doe")))
+    });
+    group.bench_function("check_name_with_max_len", |b| {
+        b.iter(|| check_name_with_max_len(black_box("Jhon Doe"), 64))
+    });
+    group.bench_function("check_name_with_spaces", |b| {
+        b.iter(|| check_name_with_spaces(black_box("Jhon Doe")))
+    });
+    group.bench_function("NamePolicy::check", |b| {
+        b.iter(|| name_policy.check(black_box("Łukasz O'Brien")))
+    });
+    group.bench_function("check_date_of_birth", |b| {
+        b.iter(|| check_date_of_birth(black_box("1990-06-15")))
+    });
+    group.bench_function("DateOfBirthPolicy::check", |b| {
+        b.iter(|| date_of_birth_policy.check(black_box("1990-06-15"), None, today))
+    });
+
+    group.finish();
+}
+
+fn phone_validators(c: &mut Criterion) {
+    let mut group = c.benchmark_group("phone");
+
+    let phone = normalize_phone("+44 7400 123456").unwrap();
+
+    group.bench_function("check_phone", |b| {
+        b.iter(|| check_phone(black_box("+1-202-555-0173")))
+    });
+    group.bench_function("normalize_phone", |b| {
+        b.iter(|| normalize_phone(black_box("+44 7400 123456")))
+    });
+    group.bench_function("normalize_mobile_phone", |b| {
+        b.iter(|| normalize_mobile_phone(black_box("+44 7400 123456")))
+    });
+    group.bench_function("check_phone_country", |b| {
+        b.iter(|| check_phone_country(black_box(&phone), &CountryCode::GBR))
+    });
+
+    group.finish();
+}
+
+fn address_validators(c: &mut Criterion) {
+    let mut group = c.benchmark_group("address");
+
+    group.bench_function("check_address", |b| {
+        b.iter(|| check_address(black_box("Baker Street 221B")))
+    });
+    group.bench_function("check_city", |b| b.iter(|| check_city(black_box("London"))));
+    group.bench_function("check_zip_code", |b| {
+        b.iter(|| check_zip_code(black_box("NW1 6XE")))
+    });
+    group.bench_function("normalize_zip_code", |b| {
+        b.iter(|| normalize_zip_code(&CountryCode::GBR, black_box("nw16xe")))
+    });
+    group.bench_function("check_country", |b| {
+        b.iter(|| check_country(black_box("GB")))
+    });
+
+    group.finish();
+}
+
+fn banking_validators(c: &mut Criterion) {
+    let mut group = c.benchmark_group("banking");
+
+    let iban = Iban::parse("DE89 3704 0044 0532 0130 00").unwrap();
+    let bic = Bic::parse("DEUTDEFF500").unwrap();
+
+    group.bench_function("check_iban", |b| {
+        b.iter(|| check_iban(black_box("GB82 WEST 1234 5698 7654 32")))
+    });
+    group.bench_function("check_bic", |b| {
+        b.iter(|| check_bic(black_box("DEUTDEFF500")))
+    });
+    group.bench_function("check_bic_matches_iban", |b| {
+        b.iter(|| check_bic_matches_iban(black_box(&bic), black_box(&iban)))
+    });
+
+    group.finish();
+}
+
+fn crypto_wallet_validators(c: &mut Criterion) {
+    let mut group = c.benchmark_group("crypto_wallet");
+
+    group.bench_function("check_wallet_address/bitcoin", |b| {
+        b.iter(|| {
+            check_wallet_address(
+                CryptoNetwork::Bitcoin,
+                black_box("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"),
+            )
+        })
+    });
+    group.bench_function("check_wallet_address/ethereum", |b| {
+        b.iter(|| {
+            check_wallet_address(
+                CryptoNetwork::Ethereum,
+                black_box("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+            )
+        })
+    });
+    group.bench_function("check_wallet_address/tron", |b| {
+        b.iter(|| {
+            check_wallet_address(
+                CryptoNetwork::Tron,
+                black_box("TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t"),
+            )
+        })
+    });
+    group.bench_function("check_wallet_address/solana", |b| {
+        b.iter(|| {
+            check_wallet_address(
+                CryptoNetwork::Solana,
+                black_box("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"),
+            )
+        })
+    });
+
+    group.finish();
+}
+
+fn money_validators(c: &mut Criterion) {
+    let mut group = c.benchmark_group("money");
+
+    let (amount, currency) = check_amount("1250.75", "EUR").unwrap();
+    let limits = AmountLimits {
+        min: Some(Amount::parse("10").unwrap()),
+        max: Some(Amount::parse("5000").unwrap()),
+    };
+
+    group.bench_function("check_amount", |b| {
+        b.iter(|| check_amount(black_box("1250.75"), black_box("EUR")))
+    });
+    group.bench_function("check_amount_precision", |b| {
+        b.iter(|| check_amount_precision(black_box(&amount), currency))
+    });
+    group.bench_function("AmountLimits::check", |b| {
+        b.iter(|| limits.check(black_box(&amount), currency))
+    });
+
+    group.finish();
+}
+
+fn discount_validators(c: &mut Criterion) {
+    let mut group = c.benchmark_group("discount");
+
+    group.bench_function("normalize_discount_code", |b| {
+        b.iter(|| normalize_discount_code(black_box(" summer-24 ")))
+    });
+
+    group.finish();
+}
+
+fn file_upload_validators(c: &mut Criterion) {
+    let mut group = c.benchmark_group("file_upload");
+
+    let policy = FileUploadPolicy::default();
+
+    // 1 MiB document, the whole content is scanned for embedded scripts
+    let mut pdf = b"%PDF-1.7\n".to_vec();
+    pdf.resize(1024 * 1024 - 6, b'x');
+    pdf.extend_from_slice(b"%%EOF\n");
+
+    group.bench_function("FileUploadPolicy::check/pdf_1mb", |b| {
+        b.iter(|| policy.check(black_box("passport.pdf"), Some("application/pdf"), &pdf))
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    text_validators,
+    url_validators,
+    email_validators,
+    password_validators,
+    person_validators,
+    phone_validators,
+    address_validators,
+    banking_validators,
+    crypto_wallet_validators,
+    money_validators,
+    discount_validators,
+    file_upload_validators
+);
+criterion_main!(benches);
@@ -0,0 +1,33 @@
+extern crate proc_macro;
+use proc_macro::TokenStream;
+
+mod validate;
+
+/// Generates `rest_api_wl_shared::Validate` for a request model.
+///
+/// Validated fields should be `String` or `Option<String>`, `None` is not checked.
+/// Errors use the serde names of the fields: `rename` and container `rename_all` are applied.
+///
+/// ```ignore
+/// #[derive(Validate)]
+/// pub struct RegisterRequest {
+///     #[serde(rename = "email")]
+///     #[validate(email)]
+///     pub email: String,
+///     #[validate(password)]
+///     pub password: String,
+///     #[validate(country)]
+///     pub country: Option<String>,
+///     #[validate(non_empty, max_len = 32)]
+///     pub nickname: String,
+/// }
+/// ```
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn validate_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    match validate::generate(&ast) {
+        Ok(result) => result.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
@@ -0,0 +1,486 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::ext::IdentExt;
+
+const CHECKS: &[(&str, &str)] = &[
+    ("non_empty", "check_non_empty"),
+    ("email", "check_email"),
+    ("password", "check_password"),
+    ("phone", "check_phone"),
+    ("name", "check_name"),
+    ("name_with_spaces", "check_name_with_spaces"),
+    ("date_of_birth", "check_date_of_birth"),
+    ("address", "check_address"),
+    ("city", "check_city"),
+    ("zip_code", "check_zip_code"),
+    ("country", "check_country"),
+];
+
+const LENGTH_CHECKS: &[(&str, &str)] = &[
+    ("max_len", "check_max_len"),
+    ("min_len", "check_min_len"),
+];
+
+pub fn generate(ast: &syn::DeriveInput) -> Result<TokenStream, syn::Error> {
+    let struct_name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let fields = match &ast.data {
+        syn::Data::Struct(data) => match &data.fields {
+            syn::Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    struct_name,
+                    "Validate can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                struct_name,
+                "Validate can only be derived for structs",
+            ))
+        }
+    };
+
+    let rename_all = get_rename_all(&ast.attrs)?;
+    let mut checks = Vec::new();
+
+    for field in fields {
+        let ident = field.ident.as_ref().unwrap();
+        let field_checks = get_checks(field)?;
+
+        if field_checks.is_empty() {
+            continue;
+        }
+
+        let field_name = get_field_name(field, rename_all)?;
+        let is_optional = get_field_type(&field.ty)? == FieldType::OptionalString;
+
+        for check in field_checks {
+            let value = if is_optional {
+                quote!(value)
+            } else {
+                quote!(self.#ident.as_str())
+            };
+
+            let result = build_call(&check, value);
+
+            let check = if is_optional {
+                quote! {
+                    if let Some(value) = self.#ident.as_deref() {
+                        errors.check(#field_name, #result);
+                    }
+                }
+            } else {
+                quote! {
+                    errors.check(#field_name, #result);
+                }
+            };
+
+            checks.push(check);
+        }
+    }
+
+    Ok(quote! {
+        impl #impl_generics rest_api_wl_shared::Validate for #struct_name #ty_generics #where_clause {
+            fn validate_fields(&self, errors: &mut rest_api_wl_shared::ValidationErrors) {
+                #(#checks)*
+            }
+        }
+    })
+}
+
+enum FieldCheck {
+    Simple(syn::Ident),
+    WithLength(syn::Ident, syn::LitInt),
+}
+
+fn build_call(check: &FieldCheck, value: TokenStream) -> TokenStream {
+    match check {
+        FieldCheck::Simple(ident) => quote!(rest_api_wl_shared::#ident(#value)),
+        FieldCheck::WithLength(ident, len) => quote!(rest_api_wl_shared::#ident(#value, #len)),
+    }
+}
+
+fn get_checks(field: &syn::Field) -> Result<Vec<FieldCheck>, syn::Error> {
+    let mut result = Vec::new();
+
+    for attr in &field.attrs {
+        if !attr.path().is_ident("validate") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if let Some((_, func)) = CHECKS.iter().find(|(name, _)| meta.path.is_ident(name)) {
+                let span = meta.path.get_ident().unwrap().span();
+                result.push(FieldCheck::Simple(syn::Ident::new(func, span)));
+                return Ok(());
+            }
+
+            if let Some((_, func)) = LENGTH_CHECKS.iter().find(|(name, _)| meta.path.is_ident(name)) {
+                let span = meta.path.get_ident().unwrap().span();
+                let len: syn::LitInt = meta.value()?.parse()?;
+                result.push(FieldCheck::WithLength(syn::Ident::new(func, span), len));
+                return Ok(());
+            }
+
+            Err(meta.error("unsupported validate attribute"))
+        })?;
+    }
+
+    Ok(result)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    const ALL: [(&'static str, RenameRule); 8] = [
+        ("lowercase", RenameRule::Lower),
+        ("UPPERCASE", RenameRule::Upper),
+        ("PascalCase", RenameRule::Pascal),
+        ("camelCase", RenameRule::Camel),
+        ("snake_case", RenameRule::Snake),
+        ("SCREAMING_SNAKE_CASE", RenameRule::ScreamingSnake),
+        ("kebab-case", RenameRule::Kebab),
+        ("SCREAMING-KEBAB-CASE", RenameRule::ScreamingKebab),
+    ];
+
+    fn parse(value: &syn::LitStr) -> Result<Self, syn::Error> {
+        Self::ALL
+            .iter()
+            .find(|(name, _)| *name == value.value())
+            .map(|(_, rule)| *rule)
+            .ok_or_else(|| syn::Error::new_spanned(value, "unknown serde rename_all rule"))
+    }
+
+    // Same conversion of snake_case field names as serde does
+    fn apply(&self, field: &str) -> String {
+        match self {
+            RenameRule::Lower | RenameRule::Snake => field.to_string(),
+            RenameRule::Upper | RenameRule::ScreamingSnake => field.to_ascii_uppercase(),
+            RenameRule::Pascal => {
+                let mut result = String::with_capacity(field.len());
+                let mut is_word_start = true;
+
+                for c in field.chars() {
+                    if c == '_' {
+                        is_word_start = true;
+                    } else if is_word_start {
+                        result.push(c.to_ascii_uppercase());
+                        is_word_start = false;
+                    } else {
+                        result.push(c);
+                    }
+                }
+
+                result
+            }
+            RenameRule::Camel => {
+                let pascal = RenameRule::Pascal.apply(field);
+                pascal[..1].to_ascii_lowercase() + &pascal[1..]
+            }
+            RenameRule::Kebab => field.replace('_', "-"),
+            RenameRule::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+}
+
+// `rename = "name"` or `rename(deserialize = "name")`, requests are deserialized.
+// `None` for other serde attributes and for `rename(serialize = "name")` only.
+fn parse_serde_name(meta: &syn::meta::ParseNestedMeta) -> Result<Option<syn::LitStr>, syn::Error> {
+    if meta.input.peek(syn::Token![=]) {
+        return Ok(Some(meta.value()?.parse()?));
+    }
+
+    let mut result = None;
+
+    meta.parse_nested_meta(|nested| {
+        let value: syn::LitStr = nested.value()?.parse()?;
+
+        if nested.path.is_ident("deserialize") {
+            result = Some(value);
+        }
+
+        Ok(())
+    })?;
+
+    Ok(result)
+}
+
+// Skips values of serde attributes which are not interesting here, e.g. `default = "path"`
+fn skip_serde_meta(meta: syn::meta::ParseNestedMeta) -> Result<(), syn::Error> {
+    if meta.input.peek(syn::Token![=]) {
+        let _: syn::Expr = meta.value()?.parse()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(skip_serde_meta)?;
+    }
+
+    Ok(())
+}
+
+fn get_rename_all(attrs: &[syn::Attribute]) -> Result<Option<RenameRule>, syn::Error> {
+    let mut result = None;
+
+    for attr in attrs {
+        if !attr.path().is_ident("serde") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("rename_all") {
+                return skip_serde_meta(meta);
+            }
+
+            if let Some(value) = parse_serde_name(&meta)? {
+                result = Some(RenameRule::parse(&value)?);
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(result)
+}
+
+// Errors are reported with the name the client sent, so serde rename wins over rename_all
+// and both over the rust name
+fn get_field_name(
+    field: &syn::Field,
+    rename_all: Option<RenameRule>,
+) -> Result<String, syn::Error> {
+    let ident = field.ident.as_ref().unwrap().unraw().to_string();
+    let mut result = match rename_all {
+        Some(rule) => rule.apply(&ident),
+        None => ident,
+    };
+
+    for attr in &field.attrs {
+        if !attr.path().is_ident("serde") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("rename") {
+                return skip_serde_meta(meta);
+            }
+
+            if let Some(value) = parse_serde_name(&meta)? {
+                result = value.value();
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(result)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum FieldType {
+    String,
+    OptionalString,
+}
+
+// Checks take `&str`, so only `String` and `Option<String>` fields are supported
+fn get_field_type(ty: &syn::Type) -> Result<FieldType, syn::Error> {
+    let unsupported = || {
+        syn::Error::new_spanned(
+            ty,
+            "validate supports only String and Option<String> fields",
+        )
+    };
+
+    let segment = get_last_segment(ty).ok_or_else(unsupported)?;
+
+    if segment.ident == "String" && segment.arguments.is_none() {
+        return Ok(FieldType::String);
+    }
+
+    if segment.ident != "Option" {
+        return Err(unsupported());
+    }
+
+    let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return Err(unsupported());
+    };
+
+    match arguments.args.first() {
+        Some(syn::GenericArgument::Type(inner)) if arguments.args.len() == 1 => {
+            match get_last_segment(inner) {
+                Some(inner) if inner.ident == "String" && inner.arguments.is_none() => {
+                    Ok(FieldType::OptionalString)
+                }
+                _ => Err(unsupported()),
+            }
+        }
+        _ => Err(unsupported()),
+    }
+}
+
+fn get_last_segment(ty: &syn::Type) -> Option<&syn::PathSegment> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+
+    if type_path.qself.is_some() {
+        return None;
+    }
+
+    type_path.path.segments.last()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_field_names(ast: syn::DeriveInput) -> Vec<String> {
+        let code = generate(&ast).unwrap().to_string();
+
+        code.split("errors . check (")
+            .skip(1)
+            .map(|call| call.split('"').nth(1).unwrap().to_string())
+            .collect()
+    }
+
+    fn get_error(ast: syn::DeriveInput) -> String {
+        generate(&ast).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn field_names_follow_serde() {
+        let ast = syn::parse_quote! {
+            #[derive(Deserialize)]
+            #[serde(rename_all = "camelCase", deny_unknown_fields)]
+            pub struct RegisterRequest {
+                #[validate(email)]
+                pub email_address: String,
+                #[serde(rename = "pwd")]
+                #[validate(password)]
+                pub password: String,
+                #[serde(default, rename(serialize = "x", deserialize = "countryCode"))]
+                #[validate(country)]
+                pub country: Option<String>,
+                #[validate(non_empty, max_len = 32)]
+                pub r#type: std::string::String,
+                pub not_validated: i32,
+            }
+        };
+
+        assert_eq!(
+            get_field_names(ast),
+            vec!["emailAddress", "pwd", "countryCode", "type", "type"]
+        );
+    }
+
+    #[test]
+    fn rename_all_rules() {
+        for (rule, expected) in [
+            ("lowercase", "zip_code"),
+            ("UPPERCASE", "ZIP_CODE"),
+            ("PascalCase", "ZipCode"),
+            ("camelCase", "zipCode"),
+            ("snake_case", "zip_code"),
+            ("SCREAMING_SNAKE_CASE", "ZIP_CODE"),
+            ("kebab-case", "zip-code"),
+            ("SCREAMING-KEBAB-CASE", "ZIP-CODE"),
+        ] {
+            let rule = syn::LitStr::new(rule, proc_macro2::Span::call_site());
+            let ast = syn::parse_quote! {
+                #[serde(rename_all = #rule)]
+                struct Request {
+                    #[validate(zip_code)]
+                    zip_code: String,
+                }
+            };
+
+            assert_eq!(get_field_names(ast), vec![expected]);
+        }
+
+        let ast = syn::parse_quote! {
+            #[serde(rename_all(serialize = "UPPERCASE", deserialize = "kebab-case"))]
+            struct Request {
+                #[validate(zip_code)]
+                zip_code: Option<String>,
+            }
+        };
+
+        assert_eq!(get_field_names(ast), vec!["zip-code"]);
+    }
+
+    #[test]
+    fn optional_fields_are_checked_when_set() {
+        let ast = syn::parse_quote! {
+            struct Request {
+                #[validate(country)]
+                country: Option<String>,
+            }
+        };
+
+        let code = generate(&ast).unwrap().to_string();
+        assert!(code.contains("if let Some (value) = self . country . as_deref ()"));
+    }
+
+    #[test]
+    fn reports_unsupported_input() {
+        for ast in [
+            syn::parse_quote! {
+                struct Request {
+                    #[validate(email)]
+                    email: Vec<String>,
+                }
+            },
+            syn::parse_quote! {
+                struct Request {
+                    #[validate(email)]
+                    email: Option<u32>,
+                }
+            },
+            syn::parse_quote! {
+                struct Request {
+                    #[validate(email)]
+                    email: &'static str,
+                }
+            },
+        ] {
+            assert_eq!(
+                get_error(ast),
+                "validate supports only String and Option<String> fields"
+            );
+        }
+
+        let ast = syn::parse_quote! {
+            #[serde(rename_all = "Title Case")]
+            struct Request {
+                #[validate(email)]
+                email: String,
+            }
+        };
+        assert_eq!(get_error(ast), "unknown serde rename_all rule");
+
+        let ast = syn::parse_quote! {
+            struct Request {
+                #[validate(uuid)]
+                id: String,
+            }
+        };
+        assert_eq!(get_error(ast), "unsupported validate attribute");
+
+        let ast = syn::parse_quote! {
+            enum Request {
+                A,
+            }
+        };
+        assert_eq!(get_error(ast), "Validate can only be derived for structs");
+    }
+}
@@ -0,0 +1,259 @@
+service_sdk::macros::use_my_http_server!();
+
+use my_http_server::{HttpFailResult, WebContentType};
+use serde::Serialize;
+use service_sdk::my_telemetry::TelemetryEventTagsBuilder;
+
+use crate::ApiResultStatus;
+
+pub type ApiErrorSource = Box<dyn std::error::Error + Send + Sync>;
+
+/// Error which services return from their domain layer.
+///
+/// Only `status` and `data` reach the response body. `source` is internal
+/// and goes to logs/telemetry only, it is also returned by [`std::error::Error::source`].
+#[derive(Debug)]
+pub struct ApiError {
+    pub status: ApiResultStatus,
+    pub data: Option<serde_json::Value>,
+    pub source: Option<ApiErrorSource>,
+    pub write_to_log: bool,
+    pub write_telemetry: bool,
+}
+
+#[derive(Serialize)]
+struct ApiErrorBody<'s> {
+    result: ApiResultStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<&'s serde_json::Value>,
+}
+
+impl ApiError {
+    pub fn new(status: ApiResultStatus) -> Self {
+        let is_server_error = status.get_status_code() >= 500;
+
+        Self {
+            status,
+            data: None,
+            source: None,
+            write_to_log: is_server_error,
+            write_telemetry: true,
+        }
+    }
+
+    /// Unexpected failure. Client gets [`ApiResultStatus::SystemError`], details are only logged.
+    pub fn internal(source: impl Into<ApiErrorSource>) -> Self {
+        Self::new(ApiResultStatus::SystemError)
+            .with_source(source)
+            .with_log(true)
+    }
+
+    /// Data which can not be serialized is not sent, the error is kept as `source` and logged.
+    /// A `source` set before stays reachable through [`std::error::Error::source`] of the new one.
+    pub fn with_data<T: Serialize>(mut self, data: T) -> Self {
+        match serde_json::to_value(data) {
+            Ok(data) => self.data = Some(data),
+            Err(err) => {
+                self.data = None;
+                self.source = Some(Box::new(DataSerializationError {
+                    message: err.to_string(),
+                    source: self.source.take(),
+                }));
+                self.write_to_log = true;
+            }
+        }
+
+        self
+    }
+
+    pub fn with_source(mut self, source: impl Into<ApiErrorSource>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    pub fn with_log(mut self, write_to_log: bool) -> Self {
+        self.write_to_log = write_to_log;
+        self
+    }
+
+    pub fn with_telemetry(mut self, write_telemetry: bool) -> Self {
+        self.write_telemetry = write_telemetry;
+        self
+    }
+
+    fn get_body(&self) -> Vec<u8> {
+        let body = ApiErrorBody {
+            result: self.status,
+            data: self.data.as_ref(),
+        };
+
+        serde_json::to_vec(&body).unwrap()
+    }
+}
+
+#[derive(Debug)]
+struct DataSerializationError {
+    message: String,
+    source: Option<ApiErrorSource>,
+}
+
+impl std::fmt::Display for DataSerializationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Can not serialize error data: {}", self.message)?;
+
+        match &self.source {
+            Some(source) => write!(f, ". Original error: {}", source),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for DataSerializationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|source| source as &(dyn std::error::Error + 'static))
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.source {
+            Some(source) => write!(f, "{:?}: {}", self.status, source),
+            None => write!(f, "{:?}", self.status),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|source| source as &(dyn std::error::Error + 'static))
+    }
+}
+
+impl From<ApiResultStatus> for ApiError {
+    fn from(status: ApiResultStatus) -> Self {
+        Self::new(status)
+    }
+}
+
+impl From<std::io::Error> for ApiError {
+    fn from(err: std::io::Error) -> Self {
+        Self::internal(err)
+    }
+}
+
+impl From<serde_json::Error> for ApiError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::internal(err)
+    }
+}
+
+impl From<std::num::ParseIntError> for ApiError {
+    fn from(err: std::num::ParseIntError) -> Self {
+        Self::new(ApiResultStatus::RequestIsNoValid).with_source(err)
+    }
+}
+
+impl From<std::num::ParseFloatError> for ApiError {
+    fn from(err: std::num::ParseFloatError) -> Self {
+        Self::new(ApiResultStatus::RequestIsNoValid).with_source(err)
+    }
+}
+
+impl Into<HttpFailResult> for ApiError {
+    fn into(self) -> HttpFailResult {
+        let mut result = HttpFailResult::new(
+            WebContentType::Json,
+            self.status.get_status_code(),
+            self.get_body(),
+            self.write_telemetry,
+            self.write_to_log,
+        );
+
+        let mut tags =
+            TelemetryEventTagsBuilder::new().add("api_result", format!("{:?}", self.status));
+
+        if let Some(source) = self.source {
+            tags = tags.add("error", source.to_string());
+        }
+
+        result.add_telemetry_tags = tags;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_is_not_written_to_body() {
+        let err = ApiError::internal("connection refused: 10.0.0.5:5432");
+
+        assert_eq!(err.get_body(), br#"{"result":-8}"#.to_vec());
+        assert!(err.write_to_log);
+        assert!(err.to_string().contains("10.0.0.5"));
+    }
+
+    #[test]
+    fn source_chain_is_kept() {
+        use std::error::Error;
+
+        let io_error = std::io::Error::new(std::io::ErrorKind::TimedOut, "db timeout");
+        let err = ApiError::from(io_error);
+
+        let source = err.source().unwrap();
+        assert_eq!(source.to_string(), "db timeout");
+        assert!(source.downcast_ref::<std::io::Error>().is_some());
+        assert!(ApiError::new(ApiResultStatus::UserNotFound)
+            .source()
+            .is_none());
+    }
+
+    #[test]
+    fn data_serialization_failure_is_not_dropped_silently() {
+        let data: std::collections::HashMap<(i32, i32), i32> = [((1, 2), 3)].into_iter().collect();
+        let err = ApiError::new(ApiResultStatus::AmountExceedsMax).with_data(data);
+
+        assert!(err.data.is_none());
+        assert!(err.write_to_log);
+        assert!(err
+            .source
+            .as_ref()
+            .unwrap()
+            .to_string()
+            .starts_with("Can not serialize error data"));
+    }
+
+    #[test]
+    fn data_serialization_failure_keeps_source() {
+        use std::error::Error;
+
+        let data: std::collections::HashMap<(i32, i32), i32> = [((1, 2), 3)].into_iter().collect();
+        let err = ApiError::internal("db timeout").with_data(data);
+
+        let source = err.source().unwrap();
+        assert!(source.to_string().contains("db timeout"));
+        assert_eq!(source.source().unwrap().to_string(), "db timeout");
+    }
+
+    #[test]
+    fn data_is_written_to_body() {
+        let err = ApiError::new(ApiResultStatus::AmountExceedsMax).with_data(100);
+
+        assert_eq!(err.get_body(), br#"{"result":-201,"data":100}"#.to_vec());
+        assert!(!err.write_to_log);
+    }
+
+    #[test]
+    fn status_code_follows_api_result_status() {
+        let result: HttpFailResult = ApiError::from(ApiResultStatus::AccessClaimRequired).into();
+        assert_eq!(result.status_code, 403);
+
+        let err: ApiError = "abc".parse::<i32>().unwrap_err().into();
+        assert!(matches!(err.status, ApiResultStatus::RequestIsNoValid));
+    }
+}
@@ -45,6 +45,9 @@ pub enum ApiResultStatus {
     #[http_enum_case(id="-11"; description="CountryRestriction")]
     CountryIsRestricted = -11,
 
+    #[http_enum_case(id="-12"; description="Invalid file content")]
+    InvalidFileContent = -12,
+
     #[http_enum_case(id="-17"; description="AccessTokenInvalid")]
     AccessTokenInvalid = -17,
 
@@ -131,6 +134,54 @@ pub enum ApiResultStatus {
 }
 
 impl ApiResultStatus {
+    pub const ALL: [ApiResultStatus; 41] = [
+        ApiResultStatus::Ok,
+        ApiResultStatus::InvalidUserNameOrPassword,
+        ApiResultStatus::UserExists,
+        ApiResultStatus::UserNotFound,
+        ApiResultStatus::OldPasswordIsWrong,
+        ApiResultStatus::WrongFileExtension,
+        ApiResultStatus::FileNotFound,
+        ApiResultStatus::PersonalDataNotValid,
+        ApiResultStatus::SystemError,
+        ApiResultStatus::AccessTokenExpired,
+        ApiResultStatus::TechnicalError,
+        ApiResultStatus::CountryIsRestricted,
+        ApiResultStatus::InvalidFileContent,
+        ApiResultStatus::AccessTokenInvalid,
+        ApiResultStatus::AccessClaimRequired,
+        ApiResultStatus::TraderPackageNotFound,
+        ApiResultStatus::OrderNotFound,
+        ApiResultStatus::OrderNotPaid,
+        ApiResultStatus::PasswordWasUsedBefore,
+        ApiResultStatus::InvalidCodeEntered,
+        ApiResultStatus::NotEnoughBalance,
+        ApiResultStatus::NotAuthorized,
+        ApiResultStatus::RefreshTokenExpired,
+        ApiResultStatus::PayoutIsBlocked,
+        ApiResultStatus::TraderIsNotVerified,
+        ApiResultStatus::TraderIsAlreadyVerified,
+        ApiResultStatus::InvalidCode,
+        ApiResultStatus::InvalidDiscountCode,
+        ApiResultStatus::DiscountCodeUsageExceeded,
+        ApiResultStatus::DiscountCodeExpired,
+        ApiResultStatus::DiscountCodeForAnotherPackage,
+        ApiResultStatus::InvalidSiteLanguage,
+        ApiResultStatus::UserHasOpenPositions,
+        ApiResultStatus::RequestIsNoValid,
+        ApiResultStatus::AmountExceedsMax,
+        ApiResultStatus::AmountLessThanMin,
+        ApiResultStatus::TradingPlatformIsNotValid,
+        ApiResultStatus::RecaptchaVerificationIsFailed,
+        ApiResultStatus::RecaptchaIsRequired,
+        ApiResultStatus::BrandIsNotSetUp,
+        ApiResultStatus::ForceUpdateIsRequired,
+    ];
+
+    pub fn get_id(&self) -> i16 {
+        *self as i16
+    }
+
     pub fn get_status_code(&self) -> u16 {
         match self {
             ApiResultStatus::Ok => 200,
@@ -145,6 +196,7 @@ impl ApiResultStatus {
             ApiResultStatus::AccessTokenExpired => 401,
             ApiResultStatus::TechnicalError => 200,
             ApiResultStatus::CountryIsRestricted => 200,
+            ApiResultStatus::InvalidFileContent => 400,
             ApiResultStatus::AccessTokenInvalid => 401,
             ApiResultStatus::AccessClaimRequired => 403,
             ApiResultStatus::ForceUpdateIsRequired => 200,
@@ -239,4 +291,18 @@ mod test {
 
         println!("{}", result);
     }
+
+    #[test]
+    pub fn all_lists_every_variant() {
+        let mut ids: Vec<i16> = ApiResultStatus::ALL.iter().map(|x| x.get_id()).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), ApiResultStatus::ALL.len());
+
+        let deserializable: Vec<i16> = (i16::MIN..=i16::MAX)
+            .filter(|id| serde_json::from_str::<ApiResultStatus>(&id.to_string()).is_ok())
+            .collect();
+
+        assert_eq!(ids, deserializable);
+    }
 }
@@ -0,0 +1,62 @@
+use service_sdk::my_http_server::{HttpContext, HttpFailResult};
+
+use crate::{into_http_fail, ValidationFailure};
+
+use super::{check_bic_matches_iban, Bic, Iban};
+
+pub fn check_iban(value: &str) -> Result<Iban, ValidationFailure> {
+    Iban::parse(value)
+}
+
+pub fn check_bic(value: &str) -> Result<Bic, ValidationFailure> {
+    Bic::parse(value)
+}
+
+/// Validates an IBAN and returns it normalized.
+pub fn validate_iban(ctx: &HttpContext, value: &str) -> Result<Iban, HttpFailResult> {
+    into_http_fail(ctx, check_iban(value))
+}
+
+pub fn validate_iban_optional(
+    ctx: &HttpContext,
+    value: &Option<String>,
+) -> Result<Option<Iban>, HttpFailResult> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+
+    validate_iban(ctx, value).map(Some)
+}
+
+/// Validates a BIC and returns it normalized.
+pub fn validate_bic(ctx: &HttpContext, value: &str) -> Result<Bic, HttpFailResult> {
+    into_http_fail(ctx, check_bic(value))
+}
+
+pub fn validate_bic_optional(
+    ctx: &HttpContext,
+    value: &Option<String>,
+) -> Result<Option<Bic>, HttpFailResult> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+
+    validate_bic(ctx, value).map(Some)
+}
+
+/// Validates payout bank details and returns them normalized.
+pub fn validate_bank_account(
+    ctx: &HttpContext,
+    iban: &str,
+    bic: &str,
+) -> Result<(Iban, Bic), HttpFailResult> {
+    into_http_fail(ctx, parse_bank_account(iban, bic))
+}
+
+fn parse_bank_account(iban: &str, bic: &str) -> Result<(Iban, Bic), ValidationFailure> {
+    let iban = Iban::parse(iban)?;
+    let bic = Bic::parse(bic)?;
+    check_bic_matches_iban(&bic, &iban)?;
+
+    Ok((iban, bic))
+}
@@ -0,0 +1,169 @@
+use crate::{Iban, ValidationFailure, CODE_INVALID_FORMAT};
+
+pub const CODE_BIC_COUNTRY_MISMATCH: &str = "bic_country_mismatch";
+
+// Banks of these territories use IBANs of the first country and BICs with their own country code
+const BIC_COUNTRY_ALIASES: &[(&str, &[&str])] = &[
+    ("GB", &["JE", "GG", "IM"]),
+    (
+        "FR",
+        &[
+            "GF", "GP", "MQ", "RE", "YT", "NC", "PF", "PM", "WF", "BL", "MF",
+        ],
+    ),
+    ("FI", &["AX"]),
+];
+
+/// SWIFT/BIC code (ISO 9362): bank, country, location and optional branch code.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bic {
+    value: String,
+}
+
+impl Bic {
+    pub fn parse(value: &str) -> Result<Self, ValidationFailure> {
+        let value = value.trim().to_ascii_uppercase();
+        let bytes = value.as_bytes();
+
+        if bytes.len() != 8 && bytes.len() != 11 {
+            return Err(ValidationFailure::new(
+                CODE_INVALID_FORMAT,
+                "BIC: Should contain 8 or 11 symbols",
+            ));
+        }
+
+        let is_valid = bytes[0..4].iter().all(u8::is_ascii_alphanumeric)
+            && bytes[4..6].iter().all(u8::is_ascii_uppercase)
+            && bytes[6..].iter().all(u8::is_ascii_alphanumeric);
+
+        if !is_valid {
+            return Err(ValidationFailure::new(
+                CODE_INVALID_FORMAT,
+                "BIC: Invalid format",
+            ));
+        }
+
+        let country = &value[4..6];
+
+        if !is_bic_country(country) {
+            return Err(ValidationFailure::new(
+                CODE_INVALID_FORMAT,
+                format!("BIC: Unknown country {}", country),
+            ));
+        }
+
+        Ok(Self { value })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    pub fn get_bank_code(&self) -> &str {
+        &self.value[0..4]
+    }
+
+    /// ISO2 country code
+    pub fn get_country_code(&self) -> &str {
+        &self.value[4..6]
+    }
+
+    pub fn get_location_code(&self) -> &str {
+        &self.value[6..8]
+    }
+
+    /// `None` for 8 symbol codes, which point to the primary office
+    pub fn get_branch_code(&self) -> Option<&str> {
+        self.value.get(8..11)
+    }
+
+    /// "0" as the second location symbol marks test and training codes
+    pub fn is_test(&self) -> bool {
+        self.value.as_bytes()[7] == b'0'
+    }
+
+    pub fn is_matching_iban_country(&self, iban_country: &str) -> bool {
+        let bic_country = self.get_country_code();
+
+        if bic_country == iban_country {
+            return true;
+        }
+
+        BIC_COUNTRY_ALIASES
+            .iter()
+            .any(|(country, aliases)| *country == iban_country && aliases.contains(&bic_country))
+    }
+}
+
+impl std::fmt::Display for Bic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.value)
+    }
+}
+
+fn is_bic_country(country: &str) -> bool {
+    // Kosovo has no ISO 3166 code yet, but has banks with BICs
+    country == "XK" || rust_common::country_code::CountryCode::parse(country).is_ok()
+}
+
+/// Checks that the bank of the BIC is located in the country of the IBAN.
+pub fn check_bic_matches_iban(bic: &Bic, iban: &Iban) -> Result<(), ValidationFailure> {
+    if !bic.is_matching_iban_country(iban.get_country_code()) {
+        return Err(ValidationFailure::new(
+            CODE_BIC_COUNTRY_MISMATCH,
+            "BIC: Country does not match the IBAN country",
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bic() {
+        let bic = Bic::parse("deutdeff500").unwrap();
+
+        assert_eq!(bic.as_str(), "DEUTDEFF500");
+        assert_eq!(bic.get_bank_code(), "DEUT");
+        assert_eq!(bic.get_country_code(), "DE");
+        assert_eq!(bic.get_location_code(), "FF");
+        assert_eq!(bic.get_branch_code(), Some("500"));
+        assert!(!bic.is_test());
+
+        let bic = Bic::parse("NWBKGB2L").unwrap();
+        assert_eq!(bic.get_branch_code(), None);
+
+        assert!(Bic::parse("ABCDGB20").unwrap().is_test());
+    }
+
+    #[test]
+    fn rejects_invalid_bic() {
+        for value in [
+            "",
+            "DEUTDE",
+            "DEUTDEFF5",
+            "DEUT1EFF",
+            "DEUTQQFF",
+            "DEUTDE-F500",
+        ] {
+            assert!(Bic::parse(value).is_err(), "{}", value);
+        }
+    }
+
+    #[test]
+    fn bic_country_should_match_iban() {
+        let iban = Iban::parse("GB82 WEST 1234 5698 7654 32").unwrap();
+
+        assert!(check_bic_matches_iban(&Bic::parse("NWBKGB2L").unwrap(), &iban).is_ok());
+        assert!(check_bic_matches_iban(&Bic::parse("RBOSJESH").unwrap(), &iban).is_ok());
+        assert_eq!(
+            check_bic_matches_iban(&Bic::parse("DEUTDEFF").unwrap(), &iban)
+                .unwrap_err()
+                .code,
+            CODE_BIC_COUNTRY_MISMATCH
+        );
+    }
+}
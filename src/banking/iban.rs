@@ -0,0 +1,322 @@
+use crate::{ValidationFailure, CODE_INVALID_FORMAT};
+
+pub const CODE_INVALID_CHECKSUM: &str = "invalid_checksum";
+pub const CODE_UNSUPPORTED_COUNTRY: &str = "unsupported_country";
+
+// BBAN structure from the SWIFT IBAN registry: n - digits, a - upper letters, c - letters and digits
+const IBAN_FORMATS: &[(&str, &str)] = &[
+    ("AD", "4n4n12c"),
+    ("AE", "3n16n"),
+    ("AL", "8n16c"),
+    ("AT", "5n11n"),
+    ("AZ", "4a20c"),
+    ("BA", "3n3n8n2n"),
+    ("BE", "3n7n2n"),
+    ("BG", "4a4n2n8c"),
+    ("BH", "4a14c"),
+    ("BR", "8n5n10n1a1c"),
+    ("BY", "4c4n16c"),
+    ("CH", "5n12c"),
+    ("CR", "4n14n"),
+    ("CY", "3n5n16c"),
+    ("CZ", "4n6n10n"),
+    ("DE", "8n10n"),
+    ("DK", "4n9n1n"),
+    ("DO", "4c20n"),
+    ("EE", "2n2n11n1n"),
+    ("EG", "4n4n17n"),
+    ("ES", "4n4n1n1n10n"),
+    ("FI", "3n11n"),
+    ("FO", "4n9n1n"),
+    ("FR", "5n5n11c2n"),
+    ("GB", "4a6n8n"),
+    ("GE", "2a16n"),
+    ("GI", "4a15c"),
+    ("GL", "4n9n1n"),
+    ("GR", "3n4n16c"),
+    ("GT", "4c20c"),
+    ("HR", "7n10n"),
+    ("HU", "3n4n1n15n1n"),
+    ("IE", "4a6n8n"),
+    ("IL", "3n3n13n"),
+    ("IQ", "4a3n12n"),
+    ("IS", "4n2n6n10n"),
+    ("IT", "1a5n5n12c"),
+    ("JO", "4a4n18c"),
+    ("KW", "4a22c"),
+    ("KZ", "3n13c"),
+    ("LB", "4n20c"),
+    ("LC", "4a24c"),
+    ("LI", "5n12c"),
+    ("LT", "5n11n"),
+    ("LU", "3n13c"),
+    ("LV", "4a13c"),
+    ("LY", "3n3n15n"),
+    ("MC", "5n5n11c2n"),
+    ("MD", "2c18c"),
+    ("ME", "3n13n2n"),
+    ("MK", "3n10c2n"),
+    ("MR", "5n5n11n2n"),
+    ("MT", "4a5n18c"),
+    ("MU", "4a2n2n12n3n3a"),
+    ("NL", "4a10n"),
+    ("NO", "4n6n1n"),
+    ("PK", "4a16c"),
+    ("PL", "8n16n"),
+    ("PS", "4a21c"),
+    ("PT", "4n4n11n2n"),
+    ("QA", "4a21c"),
+    ("RO", "4a16c"),
+    ("RS", "3n13n2n"),
+    ("SA", "2n18c"),
+    ("SC", "4a2n2n16n3a"),
+    ("SD", "2n12n"),
+    ("SE", "3n16n1n"),
+    ("SI", "5n8n2n"),
+    ("SK", "4n6n10n"),
+    ("SM", "1a5n5n12c"),
+    ("ST", "8n11n2n"),
+    ("SV", "4a20n"),
+    ("TL", "3n14n2n"),
+    ("TN", "2n3n13n2n"),
+    ("TR", "5n1n16c"),
+    ("UA", "6n19c"),
+    ("VA", "3n15n"),
+    ("VG", "4a16n"),
+    ("XK", "4n10n2n"),
+];
+
+/// IBAN in the electronic format: uppercase, without spaces.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Iban {
+    value: String,
+}
+
+impl Iban {
+    /// Accepts the print format ("GB82 WEST 1234 5698 7654 32") and lowercase letters.
+    pub fn parse(value: &str) -> Result<Self, ValidationFailure> {
+        let value: String = value
+            .chars()
+            .filter(|c| *c != ' ')
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+
+        if value.len() < 4 || !value.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            return Err(ValidationFailure::new(
+                CODE_INVALID_FORMAT,
+                "IBAN: Only letters and digits are allowed",
+            ));
+        }
+
+        let country = &value[0..2];
+
+        let Some(bban_format) = get_bban_format(country) else {
+            return Err(ValidationFailure::new(
+                CODE_UNSUPPORTED_COUNTRY,
+                format!("IBAN: Country {} does not use IBAN", country),
+            ));
+        };
+
+        let expected_len = 4 + get_bban_len(bban_format);
+
+        if value.len() != expected_len {
+            return Err(ValidationFailure::new(
+                CODE_INVALID_FORMAT,
+                format!(
+                    "IBAN: Length for {} should be {} symbols",
+                    country, expected_len
+                ),
+            ));
+        }
+
+        let check_digits = &value.as_bytes()[2..4];
+
+        if !check_digits.iter().all(u8::is_ascii_digit)
+            || !is_bban_matching(&value[4..], bban_format)
+        {
+            return Err(ValidationFailure::new(
+                CODE_INVALID_FORMAT,
+                format!("IBAN: Invalid account number format for {}", country),
+            ));
+        }
+
+        if get_mod_97(&value) != 1 {
+            return Err(ValidationFailure::new(
+                CODE_INVALID_CHECKSUM,
+                "IBAN: Invalid check digits",
+            ));
+        }
+
+        Ok(Self { value })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// ISO2 country code
+    pub fn get_country_code(&self) -> &str {
+        &self.value[0..2]
+    }
+
+    pub fn get_check_digits(&self) -> &str {
+        &self.value[2..4]
+    }
+
+    pub fn get_bban(&self) -> &str {
+        &self.value[4..]
+    }
+
+    /// Groups of four symbols separated by spaces, as printed on statements
+    pub fn to_print_format(&self) -> String {
+        let mut result = String::with_capacity(self.value.len() + self.value.len() / 4);
+
+        for (index, c) in self.value.chars().enumerate() {
+            if index > 0 && index % 4 == 0 {
+                result.push(' ');
+            }
+
+            result.push(c);
+        }
+
+        result
+    }
+}
+
+impl std::fmt::Display for Iban {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.value)
+    }
+}
+
+fn get_bban_format(country: &str) -> Option<&'static str> {
+    IBAN_FORMATS
+        .iter()
+        .find(|(code, _)| *code == country)
+        .map(|(_, format)| *format)
+}
+
+// Iterates "4a6n8n" as (4, 'a'), (6, 'n'), (8, 'n')
+fn iter_bban_format(format: &str) -> impl Iterator<Item = (usize, u8)> + '_ {
+    let bytes = format.as_bytes();
+    let mut index = 0;
+
+    std::iter::from_fn(move || {
+        let mut len = 0;
+
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            len = len * 10 + (bytes[index] - b'0') as usize;
+            index += 1;
+        }
+
+        let kind = *bytes.get(index)?;
+        index += 1;
+
+        Some((len, kind))
+    })
+}
+
+fn get_bban_len(format: &str) -> usize {
+    iter_bban_format(format).map(|(len, _)| len).sum()
+}
+
+fn is_bban_matching(bban: &str, format: &str) -> bool {
+    let mut symbols = bban.bytes();
+
+    for (len, kind) in iter_bban_format(format) {
+        for _ in 0..len {
+            let Some(symbol) = symbols.next() else {
+                return false;
+            };
+
+            let is_matching = match kind {
+                b'n' => symbol.is_ascii_digit(),
+                b'a' => symbol.is_ascii_uppercase(),
+                _ => symbol.is_ascii_uppercase() || symbol.is_ascii_digit(),
+            };
+
+            if !is_matching {
+                return false;
+            }
+        }
+    }
+
+    symbols.next().is_none()
+}
+
+// ISO 7064 MOD 97-10: first four symbols are moved to the end, letters become 10..35
+fn get_mod_97(value: &str) -> u32 {
+    let bytes = value.as_bytes();
+
+    bytes[4..]
+        .iter()
+        .chain(&bytes[..4])
+        .fold(0, |remainder, symbol| {
+            if symbol.is_ascii_digit() {
+                (remainder * 10 + (symbol - b'0') as u32) % 97
+            } else {
+                let value = (symbol - b'A') as u32 + 10;
+                (remainder * 100 + value) % 97
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_valid_ibans() {
+        for value in [
+            "GB82 WEST 1234 5698 7654 32",
+            "DE89 3704 0044 0532 0130 00",
+            "FR14 2004 1010 0505 0001 3M02 606",
+            "NL91ABNA0417164300",
+            "BE68 5390 0754 7034",
+            "CH93 0076 2011 6238 5295 7",
+            "ES91 2100 0418 4502 0005 1332",
+            "IT60 X054 2811 1010 0000 0123 456",
+            "NO93 8601 1117 947",
+            "PL61 1090 1014 0000 0712 1981 2874",
+            "MT84 MALT 0110 0001 2345 MTLC AST0 01S",
+            "SA03 8000 0000 6080 1016 7519",
+        ] {
+            assert!(Iban::parse(value).is_ok(), "{}", value);
+        }
+    }
+
+    #[test]
+    fn normalizes_to_electronic_format() {
+        let iban = Iban::parse("gb82 west 1234 5698 7654 32").unwrap();
+
+        assert_eq!(iban.as_str(), "GB82WEST12345698765432");
+        assert_eq!(iban.get_country_code(), "GB");
+        assert_eq!(iban.get_check_digits(), "82");
+        assert_eq!(iban.get_bban(), "WEST12345698765432");
+        assert_eq!(iban.to_print_format(), "GB82 WEST 1234 5698 7654 32");
+    }
+
+    #[test]
+    fn rejects_invalid_ibans() {
+        let code = |value: &str| Iban::parse(value).unwrap_err().code;
+
+        assert_eq!(code("GB82 WEST 1234 5698 7654 33"), CODE_INVALID_CHECKSUM);
+        assert_eq!(code("GB82 WEST 1234 5698 7654 3"), CODE_INVALID_FORMAT);
+        assert_eq!(code("GB82 1234 1234 5698 7654 32"), CODE_INVALID_FORMAT);
+        assert_eq!(code("GB82-WEST-1234-5698-7654-32"), CODE_INVALID_FORMAT);
+        assert_eq!(code("US12 3456 7890 1234"), CODE_UNSUPPORTED_COUNTRY);
+        assert_eq!(code("GB"), CODE_INVALID_FORMAT);
+        assert_eq!(code(""), CODE_INVALID_FORMAT);
+    }
+
+    #[test]
+    fn bban_formats_are_consistent() {
+        assert_eq!(get_bban_len("4a6n8n"), 18);
+        assert_eq!(get_bban_len("8n16n"), 24);
+
+        for (country, format) in IBAN_FORMATS {
+            let len = 4 + get_bban_len(format);
+            assert!((15..=34).contains(&len), "{}", country);
+        }
+    }
+}
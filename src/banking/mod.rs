@@ -0,0 +1,8 @@
+mod iban;
+pub use iban::*;
+
+mod bic;
+pub use bic::*;
+
+mod banking_validators;
+pub use banking_validators::*;
@@ -0,0 +1,24 @@
+//! Writes the shared api contract for frontend clients.
+//!
+//! Usage: `cargo run --bin export_api_contract -- <output dir>`
+
+use std::path::PathBuf;
+
+fn main() {
+    let output_dir = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("."));
+
+    std::fs::create_dir_all(&output_dir).unwrap();
+
+    let ts_path = output_dir.join("api-contract.ts");
+    std::fs::write(&ts_path, rest_api_wl_shared::generate_typescript()).unwrap();
+
+    let schema_path = output_dir.join("api-contract.schema.json");
+    let schema = serde_json::to_string_pretty(&rest_api_wl_shared::generate_json_schema()).unwrap();
+    std::fs::write(&schema_path, schema).unwrap();
+
+    println!("{}", ts_path.display());
+    println!("{}", schema_path.display());
+}
@@ -0,0 +1,18 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Policies of white-label brands. Brands without own policy use `default`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BrandPolicies<T> {
+    #[serde(default)]
+    pub default: T,
+    #[serde(default)]
+    pub brands: HashMap<String, T>,
+}
+
+impl<T> BrandPolicies<T> {
+    pub fn get(&self, brand_id: &str) -> &T {
+        self.brands.get(brand_id).unwrap_or(&self.default)
+    }
+}
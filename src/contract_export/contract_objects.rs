@@ -0,0 +1,193 @@
+/// Type of a field of an exported object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContractFieldType {
+    ApiResultStatus,
+    String,
+    /// Type parameter of the object, e.g. `TData`
+    Generic(&'static str),
+}
+
+#[derive(Debug)]
+pub struct ContractField {
+    pub name: &'static str,
+    pub field_type: ContractFieldType,
+    pub required: bool,
+}
+
+/// Shape of a response body. TypeScript and JSON Schema are both generated from it,
+/// tests below check it against the serialized Rust types.
+#[derive(Debug)]
+pub struct ContractObject {
+    pub name: &'static str,
+    pub fields: &'static [ContractField],
+}
+
+impl ContractObject {
+    pub fn get_type_params(&self) -> Vec<&'static str> {
+        self.fields
+            .iter()
+            .filter_map(|field| match field.field_type {
+                ContractFieldType::Generic(name) => Some(name),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+const fn field(name: &'static str, field_type: ContractFieldType, required: bool) -> ContractField {
+    ContractField {
+        name,
+        field_type,
+        required,
+    }
+}
+
+use ContractFieldType as T;
+
+pub const CONTRACT_OBJECTS: &[ContractObject] = &[
+    ContractObject {
+        name: "ApiHttpResult",
+        fields: &[field("result", T::ApiResultStatus, true)],
+    },
+    ContractObject {
+        name: "ApiHttpResultWithData",
+        fields: &[
+            field("result", T::ApiResultStatus, true),
+            field("data", T::Generic("TData"), false),
+        ],
+    },
+    ContractObject {
+        name: "AuthenticationFailedApiResponse",
+        fields: &[
+            field("result", T::ApiResultStatus, true),
+            field("description", T::String, true),
+        ],
+    },
+    ContractObject {
+        name: "AuthorizationFailedApiResponse",
+        fields: &[
+            field("result", T::ApiResultStatus, true),
+            field("description", T::String, true),
+            field("claim", T::String, false),
+        ],
+    },
+    ContractObject {
+        name: "AccessClaimRequired",
+        fields: &[
+            field("result", T::ApiResultStatus, true),
+            field("data", T::String, true),
+        ],
+    },
+    // Added to error bodies by `WithTraceId`, combined with the types above
+    ContractObject {
+        name: "WithTraceId",
+        fields: &[field(crate::TRACE_ID_FIELD, T::String, false)],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AccessClaimRequired, ApiHttpResult, ApiHttpResultWithData, ApiResultStatus,
+        AuthenticationFailedApiResponse, AuthorizationFailedApiResponse,
+    };
+
+    // Serialized value has only the declared fields, all required ones and the declared types
+    fn assert_matches(checked: &mut Vec<&'static str>, name: &str, value: impl serde::Serialize) {
+        let object = CONTRACT_OBJECTS
+            .iter()
+            .find(|object| object.name == name)
+            .unwrap();
+        checked.push(object.name);
+
+        let value = serde_json::to_value(value).unwrap();
+        let value = value.as_object().unwrap();
+
+        for (key, value) in value {
+            let field = object
+                .fields
+                .iter()
+                .find(|field| field.name == key)
+                .unwrap_or_else(|| panic!("{}.{} is not exported", name, key));
+
+            match field.field_type {
+                ContractFieldType::ApiResultStatus => assert!(value.is_i64(), "{}.{}", name, key),
+                ContractFieldType::String => assert!(value.is_string(), "{}.{}", name, key),
+                ContractFieldType::Generic(_) => {}
+            }
+        }
+
+        for field in object.fields.iter().filter(|field| field.required) {
+            assert!(value.contains_key(field.name), "{}.{}", name, field.name);
+        }
+    }
+
+    #[test]
+    fn objects_match_rust_types() {
+        let mut checked = Vec::new();
+
+        assert_matches(
+            &mut checked,
+            "ApiHttpResult",
+            ApiHttpResult {
+                result: ApiResultStatus::UserNotFound,
+            },
+        );
+
+        for data in [None, Some("data".to_string())] {
+            assert_matches(
+                &mut checked,
+                "ApiHttpResultWithData",
+                ApiHttpResultWithData {
+                    result: ApiResultStatus::RequestIsNoValid,
+                    data,
+                },
+            );
+        }
+
+        assert_matches(
+            &mut checked,
+            "AuthenticationFailedApiResponse",
+            AuthenticationFailedApiResponse {
+                result: ApiResultStatus::AccessTokenInvalid,
+                description: AuthenticationFailedApiResponse::default_desc(),
+            },
+        );
+
+        for claim in [None, Some("EmailConfirmed".to_string())] {
+            assert_matches(
+                &mut checked,
+                "AuthorizationFailedApiResponse",
+                AuthorizationFailedApiResponse {
+                    result: ApiResultStatus::AccessClaimRequired,
+                    description: AuthorizationFailedApiResponse::default_desc(),
+                    claim,
+                },
+            );
+        }
+
+        assert_matches(
+            &mut checked,
+            "AccessClaimRequired",
+            AccessClaimRequired {
+                result: ApiResultStatus::AccessClaimRequired,
+                data: "EmailConfirmed".to_string(),
+            },
+        );
+
+        assert_matches(
+            &mut checked,
+            "WithTraceId",
+            serde_json::json!({ crate::TRACE_ID_FIELD: "req-1" }),
+        );
+
+        for object in CONTRACT_OBJECTS {
+            assert!(
+                checked.contains(&object.name),
+                "{} is not checked",
+                object.name
+            );
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use serde_json::{json, Map, Value};
+
+use super::{
+    get_access_claim_type_cases, get_api_result_status_cases, get_countries, ContractFieldType,
+    ContractObject, CONTRACT_OBJECTS,
+};
+
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+pub fn generate_json_schema() -> Value {
+    let mut defs = Map::new();
+
+    defs.insert(
+        "ApiResultStatus".to_string(),
+        integer_enum_schema(&get_api_result_status_cases()),
+    );
+    defs.insert(
+        "AccessClaimType".to_string(),
+        integer_enum_schema(&get_access_claim_type_cases()),
+    );
+
+    for object in CONTRACT_OBJECTS {
+        defs.insert(object.name.to_string(), object_schema(object));
+    }
+
+    let countries = get_countries();
+
+    defs.insert(
+        "CountryCode".to_string(),
+        json!({
+            "type": "string",
+            "enum": countries.iter().map(|(_, code)| *code).collect::<Vec<_>>(),
+        }),
+    );
+
+    defs.insert(
+        "Country".to_string(),
+        json!({
+            "type": "object",
+            "properties": {
+                "code": { "$ref": "#/$defs/CountryCode" },
+                "name": { "type": "string" }
+            },
+            "required": ["code", "name"],
+            "examples": countries
+                .iter()
+                .map(|(name, code)| json!({ "code": code, "name": name }))
+                .collect::<Vec<_>>(),
+        }),
+    );
+
+    json!({
+        "$schema": JSON_SCHEMA_DIALECT,
+        "$id": format!("rest-api-wl-shared/{}", env!("CARGO_PKG_VERSION")),
+        "$defs": defs,
+    })
+}
+
+fn object_schema(object: &ContractObject) -> Value {
+    let mut properties = Map::new();
+
+    for field in object.fields {
+        let schema = match field.field_type {
+            ContractFieldType::ApiResultStatus => json!({ "$ref": "#/$defs/ApiResultStatus" }),
+            ContractFieldType::String => json!({ "type": "string" }),
+            ContractFieldType::Generic(_) => json!({}),
+        };
+
+        properties.insert(field.name.to_string(), schema);
+    }
+
+    let required: Vec<&str> = object
+        .fields
+        .iter()
+        .filter(|field| field.required)
+        .map(|field| field.name)
+        .collect();
+
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    })
+}
+
+fn integer_enum_schema(cases: &[(String, i16)]) -> Value {
+    json!({
+        "type": "integer",
+        "enum": cases.iter().map(|(_, id)| *id).collect::<Vec<_>>(),
+        "x-enum-varnames": cases.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_schema_keeps_ids_and_names_aligned() {
+        let schema = generate_json_schema();
+        let status = &schema["$defs"]["ApiResultStatus"];
+
+        let ids = status["enum"].as_array().unwrap();
+        let names = status["x-enum-varnames"].as_array().unwrap();
+
+        assert_eq!(ids.len(), names.len());
+
+        let index = names
+            .iter()
+            .position(|name| name == "AccessClaimRequired")
+            .unwrap();
+        assert_eq!(ids[index], -18);
+    }
+
+    #[test]
+    fn json_schema_objects_follow_contract_objects() {
+        let schema = generate_json_schema();
+        let claim_required = &schema["$defs"]["AuthorizationFailedApiResponse"];
+
+        assert_eq!(claim_required["properties"]["claim"]["type"], "string");
+        assert_eq!(
+            claim_required["required"],
+            serde_json::json!(["result", "description"])
+        );
+        assert!(schema["$defs"]["ApiHttpResult"]["properties"]
+            .get("traceId")
+            .is_none());
+    }
+
+    #[test]
+    fn json_schema_contains_country_codes() {
+        let schema = generate_json_schema();
+        let codes = schema["$defs"]["CountryCode"]["enum"].as_array().unwrap();
+
+        assert!(codes.iter().any(|code| code == "DE"));
+    }
+}
@@ -0,0 +1,30 @@
+mod contract_objects;
+mod json_schema;
+mod typescript;
+
+pub use contract_objects::*;
+pub use json_schema::*;
+pub use typescript::*;
+
+use crate::token::access_claim::AccessClaimType;
+use crate::ApiResultStatus;
+
+fn get_api_result_status_cases() -> Vec<(String, i16)> {
+    ApiResultStatus::ALL
+        .iter()
+        .map(|status| (format!("{:?}", status), status.get_id()))
+        .collect()
+}
+
+fn get_access_claim_type_cases() -> Vec<(String, i16)> {
+    AccessClaimType::ALL
+        .iter()
+        .map(|claim| (claim.to_string(), *claim as i16))
+        .collect()
+}
+
+fn get_countries() -> Vec<(&'static str, &'static str)> {
+    let mut countries = crate::get_country_pairs().to_vec();
+    countries.sort_by(|a, b| a.1.cmp(b.1));
+    countries
+}
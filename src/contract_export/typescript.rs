@@ -0,0 +1,116 @@
+use std::fmt::Write;
+
+use super::{
+    get_access_claim_type_cases, get_api_result_status_cases, get_countries, ContractFieldType,
+    ContractObject, CONTRACT_OBJECTS,
+};
+
+pub fn generate_typescript() -> String {
+    let mut result = String::new();
+
+    writeln!(result, "// Generated by rest-api-wl-shared {}. Do not edit.", env!("CARGO_PKG_VERSION")).unwrap();
+    writeln!(result).unwrap();
+
+    write_enum(&mut result, "ApiResultStatus", &get_api_result_status_cases());
+    write_enum(&mut result, "AccessClaimType", &get_access_claim_type_cases());
+
+    for object in CONTRACT_OBJECTS {
+        write_interface(&mut result, object);
+    }
+
+    let countries = get_countries();
+
+    writeln!(result, "export type CountryCode =").unwrap();
+    for (index, (_, code)) in countries.iter().enumerate() {
+        let end = if index == countries.len() - 1 { ";" } else { "" };
+        writeln!(result, "    | \"{}\"{}", code, end).unwrap();
+    }
+    writeln!(result).unwrap();
+
+    writeln!(
+        result,
+        "export const COUNTRIES: ReadonlyArray<{{ code: CountryCode; name: string }}> = ["
+    )
+    .unwrap();
+    for (name, code) in countries {
+        writeln!(
+            result,
+            "    {{ code: \"{}\", name: {} }},",
+            code,
+            serde_json::to_string(name).unwrap()
+        )
+        .unwrap();
+    }
+    writeln!(result, "];").unwrap();
+
+    result
+}
+
+fn write_interface(result: &mut String, object: &ContractObject) {
+    let type_params = object.get_type_params();
+
+    if type_params.is_empty() {
+        writeln!(result, "export interface {} {{", object.name).unwrap();
+    } else {
+        writeln!(
+            result,
+            "export interface {}<{}> {{",
+            object.name,
+            type_params.join(", ")
+        )
+        .unwrap();
+    }
+
+    for field in object.fields {
+        let field_type = match field.field_type {
+            ContractFieldType::ApiResultStatus => "ApiResultStatus",
+            ContractFieldType::String => "string",
+            ContractFieldType::Generic(name) => name,
+        };
+        let optional = if field.required { "" } else { "?" };
+
+        writeln!(result, "    {}{}: {};", field.name, optional, field_type).unwrap();
+    }
+
+    writeln!(result, "}}").unwrap();
+    writeln!(result).unwrap();
+}
+
+fn write_enum(result: &mut String, name: &str, cases: &[(String, i16)]) {
+    writeln!(result, "export enum {} {{", name).unwrap();
+    for (case, id) in cases {
+        writeln!(result, "    {} = {},", case, id).unwrap();
+    }
+    writeln!(result, "}}").unwrap();
+    writeln!(result).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typescript_contains_all_statuses_and_countries() {
+        let result = generate_typescript();
+
+        assert!(result.contains("export enum ApiResultStatus {"));
+        assert!(result.contains("    Ok = 0,"));
+        assert!(result.contains("    RecaptchaIsRequired = -801,"));
+        assert!(result.contains("    ForceUpdateIsRequired = -999,"));
+        assert!(result.contains("    EmailConfirmed = 0,"));
+        assert!(result.contains(r#"{ code: "CI", name: "Côte d'Ivoire" },"#));
+    }
+
+    #[test]
+    fn typescript_interfaces_follow_contract_objects() {
+        let result = generate_typescript();
+
+        assert!(result.contains(
+            "export interface ApiHttpResultWithData<TData> {\n    result: ApiResultStatus;\n    data?: TData;\n}"
+        ));
+        assert!(result.contains(
+            "export interface AuthorizationFailedApiResponse {\n    result: ApiResultStatus;\n    description: string;\n    claim?: string;\n}"
+        ));
+        assert!(result.contains("export interface WithTraceId {\n    traceId?: string;\n}"));
+    }
+}
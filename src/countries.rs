@@ -3,7 +3,21 @@ use rust_common::country_code::CountryCode;
 use service_sdk::my_http_server::{HttpContext, HttpFailResult};
 use std::collections::HashMap;
 
+use crate::{ValidationFailure, WithTraceId};
+
 lazy_static! {
+    static ref COUNTRY_CODE_TO_ISO_2: HashMap<CountryCode, &'static str> = {
+        let pairs = get_country_pairs();
+        let mut map = HashMap::new();
+        for &(_, code) in pairs.iter() {
+            map.insert(
+                rust_common::country_code::CountryCode::parse(code).unwrap(),
+                code,
+            );
+        }
+        map
+    };
+
     pub static ref COUNTRY_NAME_TO_ISO_2: HashMap<String, CountryCode> = {
         let pairs = get_country_pairs();
         let mut map = HashMap::new();
@@ -29,7 +43,21 @@ lazy_static! {
     };
 }
 
-pub fn validate_country(_ctx: &HttpContext, value: &str) -> Result<(), HttpFailResult> {
+pub fn get_country_iso2(code: &CountryCode) -> Option<&'static str> {
+    COUNTRY_CODE_TO_ISO_2.get(code).copied()
+}
+
+pub fn check_country(value: &str) -> Result<(), ValidationFailure> {
+    match rust_common::country_code::CountryCode::parse(value) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(ValidationFailure::new(
+            crate::CODE_INVALID_FORMAT,
+            "Invalid country",
+        )),
+    }
+}
+
+pub fn validate_country(ctx: &HttpContext, value: &str) -> Result<(), HttpFailResult> {
     let valid_code = rust_common::country_code::CountryCode::parse(value);
 
     match valid_code {
@@ -37,25 +65,26 @@ pub fn validate_country(_ctx: &HttpContext, value: &str) -> Result<(), HttpFailR
             return Ok(());
         }
         Err(_) => {
-            return Err(HttpFailResult::as_validation_error(
-                "Invalid country".to_string(),
-            ));
+            return Err(
+                HttpFailResult::as_validation_error("Invalid country".to_string())
+                    .with_trace_id(ctx),
+            );
         }
     }
 }
 
 pub fn validate_country_optional(
-    _ctx: &HttpContext,
+    ctx: &HttpContext,
     value: &Option<String>,
 ) -> Result<(), HttpFailResult> {
     let Some(value) = value else {
         return Ok(());
     };
 
-    validate_country(_ctx, value)
+    validate_country(ctx, value)
 }
 
-fn get_country_pairs() -> [(&'static str, &'static str); 247] {
+pub(crate) fn get_country_pairs() -> [(&'static str, &'static str); 247] {
     [
         ("Afghanistan", "AF"),
         ("Åland Islands", "AX"),
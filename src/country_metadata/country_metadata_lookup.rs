@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+use rust_common::country_code::CountryCode;
+
+use super::{CountryMetadata, COUNTRY_METADATA, EEA_NON_EU_COUNTRIES, EU_COUNTRIES};
+use crate::{get_country_iso2, ISO_2_TO_COUNTRY_NAME};
+
+// Regional indicator symbol letter A
+const REGIONAL_INDICATOR_A: u32 = 0x1F1E6;
+
+lazy_static! {
+    static ref COUNTRY_METADATA_BY_ISO: HashMap<&'static str, &'static CountryMetadata> = {
+        let mut map = HashMap::new();
+        for country in COUNTRY_METADATA {
+            map.insert(country.iso2, country);
+            map.insert(country.iso3, country);
+        }
+        map
+    };
+    static ref COUNTRY_METADATA_BY_NUMERIC: HashMap<u16, &'static CountryMetadata> = {
+        let mut map = HashMap::new();
+        for country in COUNTRY_METADATA {
+            map.insert(country.numeric, country);
+        }
+        map
+    };
+}
+
+impl CountryMetadata {
+    pub fn get_country_code(&self) -> CountryCode {
+        CountryCode::parse(self.iso2).unwrap()
+    }
+
+    /// English name from `ISO_2_TO_COUNTRY_NAME`
+    pub fn get_name(&self) -> &'static str {
+        ISO_2_TO_COUNTRY_NAME
+            .get(&self.get_country_code())
+            .map(String::as_str)
+            .unwrap_or(self.iso2)
+    }
+
+    pub fn is_eu(&self) -> bool {
+        EU_COUNTRIES.contains(&self.iso2)
+    }
+
+    pub fn is_eea(&self) -> bool {
+        self.is_eu() || EEA_NON_EU_COUNTRIES.contains(&self.iso2)
+    }
+
+    /// Pair of regional indicator symbols: "GB" -> "🇬🇧"
+    pub fn get_flag_emoji(&self) -> String {
+        self.iso2
+            .bytes()
+            .filter_map(|b| char::from_u32(REGIONAL_INDICATOR_A + (b - b'A') as u32))
+            .collect()
+    }
+}
+
+pub fn get_country_metadata(country: &CountryCode) -> Option<&'static CountryMetadata> {
+    COUNTRY_METADATA_BY_ISO
+        .get(get_country_iso2(country)?)
+        .copied()
+}
+
+/// Looks up by ISO 3166-1 alpha-2, alpha-3 or numeric code: "DE", "deu", "276", "040".
+pub fn find_country_metadata(code: &str) -> Option<&'static CountryMetadata> {
+    let code = code.trim();
+
+    if !code.is_empty() && code.len() <= 3 && code.bytes().all(|b| b.is_ascii_digit()) {
+        return COUNTRY_METADATA_BY_NUMERIC
+            .get(&code.parse().ok()?)
+            .copied();
+    }
+
+    COUNTRY_METADATA_BY_ISO
+        .get(code.to_ascii_uppercase().as_str())
+        .copied()
+}
+
+/// Countries sharing the calling code, e.g. GB, GG, IM and JE for "+44". The `+` is optional.
+pub fn get_countries_by_dial_code(dial_code: &str) -> Vec<&'static CountryMetadata> {
+    let dial_code = dial_code.trim().trim_start_matches('+');
+
+    COUNTRY_METADATA
+        .iter()
+        .filter(|country| &country.dial_code[1..] == dial_code)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{get_country_pairs, get_currency, Continent};
+
+    #[test]
+    fn consistent_with_country_codes() {
+        let pairs = get_country_pairs();
+
+        assert_eq!(COUNTRY_METADATA.len(), pairs.len());
+
+        for (country, (name, iso2)) in COUNTRY_METADATA.iter().zip(pairs.iter()) {
+            assert_eq!(country.iso2, *iso2);
+            assert_eq!(country.get_name(), *name);
+
+            let code = CountryCode::parse(iso2).unwrap();
+            assert_eq!(format!("{:?}", code), country.iso3, "{}", iso2);
+            assert_eq!(get_country_metadata(&code).unwrap().iso3, country.iso3);
+        }
+
+        assert_eq!(COUNTRY_METADATA_BY_ISO.len(), pairs.len() * 2);
+        assert_eq!(COUNTRY_METADATA_BY_NUMERIC.len(), pairs.len());
+    }
+
+    #[test]
+    fn fields_are_valid() {
+        for country in COUNTRY_METADATA {
+            assert!(
+                country.dial_code.starts_with('+')
+                    && country.dial_code.len() > 1
+                    && country.dial_code[1..].bytes().all(|b| b.is_ascii_digit()),
+                "{}",
+                country.iso2
+            );
+
+            if let Some(currency) = country.currency {
+                assert!(get_currency(currency).is_some(), "{}", country.iso2);
+            }
+        }
+
+        assert_eq!(EU_COUNTRIES.len(), 27);
+        assert!(EU_COUNTRIES
+            .iter()
+            .chain(EEA_NON_EU_COUNTRIES)
+            .all(|iso2| COUNTRY_METADATA_BY_ISO.contains_key(iso2)));
+    }
+
+    #[test]
+    fn finds_by_any_code() {
+        for code in ["DE", "de", "DEU", " deu ", "276"] {
+            assert_eq!(find_country_metadata(code).unwrap().iso2, "DE", "{}", code);
+        }
+
+        assert_eq!(find_country_metadata("040").unwrap().iso2, "AT");
+        assert_eq!(find_country_metadata("40").unwrap().iso2, "AT");
+        assert!(find_country_metadata("XX").is_none());
+        assert!(find_country_metadata("999").is_none());
+        assert!(find_country_metadata("").is_none());
+    }
+
+    #[test]
+    fn metadata() {
+        let de = find_country_metadata("DE").unwrap();
+        assert_eq!(de.get_flag_emoji(), "🇩🇪");
+        assert_eq!(de.currency, Some("EUR"));
+        assert_eq!(de.continent, Continent::Europe);
+        assert!(de.is_eu() && de.is_eea());
+
+        let no = find_country_metadata("NO").unwrap();
+        assert!(!no.is_eu() && no.is_eea());
+
+        let ch = find_country_metadata("CH").unwrap();
+        assert!(!ch.is_eu() && !ch.is_eea());
+
+        let iso2: Vec<&str> = get_countries_by_dial_code("+44")
+            .iter()
+            .map(|country| country.iso2)
+            .collect();
+        assert_eq!(iso2, ["GG", "IM", "JE", "GB"]);
+        assert_eq!(get_countries_by_dial_code("1684")[0].iso2, "AS");
+    }
+}
@@ -0,0 +1,308 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Continent {
+    Africa,
+    Antarctica,
+    Asia,
+    Europe,
+    NorthAmerica,
+    Oceania,
+    SouthAmerica,
+}
+
+pub struct CountryMetadata {
+    pub iso2: &'static str,
+    pub iso3: &'static str,
+    /// ISO 3166-1 numeric
+    pub numeric: u16,
+    /// International calling code with `+`. NANP countries have the area code: "+1684" for AS
+    pub dial_code: &'static str,
+    /// ISO 4217 code of the official currency, `None` for Antarctica
+    pub currency: Option<&'static str>,
+    pub continent: Continent,
+    /// UN M49 sub-region, e.g. "Northern Europe"
+    pub region: &'static str,
+}
+
+const fn country(
+    iso2: &'static str,
+    iso3: &'static str,
+    numeric: u16,
+    dial_code: &'static str,
+    currency: Option<&'static str>,
+    continent: Continent,
+    region: &'static str,
+) -> CountryMetadata {
+    CountryMetadata {
+        iso2,
+        iso3,
+        numeric,
+        dial_code,
+        currency,
+        continent,
+        region,
+    }
+}
+
+use Continent::*;
+
+pub const EU_COUNTRIES: &[&str] = &[
+    "AT", "BE", "BG", "CY", "CZ", "DE", "DK", "EE", "ES", "FI", "FR", "GR", "HR", "HU", "IE", "IT",
+    "LT", "LU", "LV", "MT", "NL", "PL", "PT", "RO", "SE", "SI", "SK",
+];
+
+/// EEA members outside of the EU
+pub const EEA_NON_EU_COUNTRIES: &[&str] = &["IS", "LI", "NO"];
+
+/// Same countries and order as `get_country_pairs`
+#[rustfmt::skip]
+pub const COUNTRY_METADATA: &[CountryMetadata] = &[
+    country("AF", "AFG", 4, "+93", Some("AFN"), Asia, "Southern Asia"),
+    country("AX", "ALA", 248, "+358", Some("EUR"), Europe, "Northern Europe"),
+    country("AL", "ALB", 8, "+355", Some("ALL"), Europe, "Southern Europe"),
+    country("DZ", "DZA", 12, "+213", Some("DZD"), Africa, "Northern Africa"),
+    country("AS", "ASM", 16, "+1684", Some("USD"), Oceania, "Polynesia"),
+    country("AD", "AND", 20, "+376", Some("EUR"), Europe, "Southern Europe"),
+    country("AO", "AGO", 24, "+244", Some("AOA"), Africa, "Middle Africa"),
+    country("AI", "AIA", 660, "+1264", Some("XCD"), NorthAmerica, "Caribbean"),
+    country("AQ", "ATA", 10, "+672", None, Antarctica, "Antarctica"),
+    country("AG", "ATG", 28, "+1268", Some("XCD"), NorthAmerica, "Caribbean"),
+    country("AR", "ARG", 32, "+54", Some("ARS"), SouthAmerica, "South America"),
+    country("AM", "ARM", 51, "+374", Some("AMD"), Asia, "Western Asia"),
+    country("AW", "ABW", 533, "+297", Some("AWG"), NorthAmerica, "Caribbean"),
+    country("AU", "AUS", 36, "+61", Some("AUD"), Oceania, "Australia and New Zealand"),
+    country("AT", "AUT", 40, "+43", Some("EUR"), Europe, "Western Europe"),
+    country("AZ", "AZE", 31, "+994", Some("AZN"), Asia, "Western Asia"),
+    country("BS", "BHS", 44, "+1242", Some("BSD"), NorthAmerica, "Caribbean"),
+    country("BH", "BHR", 48, "+973", Some("BHD"), Asia, "Western Asia"),
+    country("BD", "BGD", 50, "+880", Some("BDT"), Asia, "Southern Asia"),
+    country("BB", "BRB", 52, "+1246", Some("BBD"), NorthAmerica, "Caribbean"),
+    country("BY", "BLR", 112, "+375", Some("BYN"), Europe, "Eastern Europe"),
+    country("BE", "BEL", 56, "+32", Some("EUR"), Europe, "Western Europe"),
+    country("BZ", "BLZ", 84, "+501", Some("BZD"), NorthAmerica, "Central America"),
+    country("BJ", "BEN", 204, "+229", Some("XOF"), Africa, "Western Africa"),
+    country("BM", "BMU", 60, "+1441", Some("BMD"), NorthAmerica, "Northern America"),
+    country("BT", "BTN", 64, "+975", Some("BTN"), Asia, "Southern Asia"),
+    country("BO", "BOL", 68, "+591", Some("BOB"), SouthAmerica, "South America"),
+    country("BQ", "BES", 535, "+599", Some("USD"), NorthAmerica, "Caribbean"),
+    country("BA", "BIH", 70, "+387", Some("BAM"), Europe, "Southern Europe"),
+    country("BW", "BWA", 72, "+267", Some("BWP"), Africa, "Southern Africa"),
+    country("BV", "BVT", 74, "+47", Some("NOK"), Antarctica, "Antarctica"),
+    country("BR", "BRA", 76, "+55", Some("BRL"), SouthAmerica, "South America"),
+    country("IO", "IOT", 86, "+246", Some("USD"), Africa, "Eastern Africa"),
+    country("BN", "BRN", 96, "+673", Some("BND"), Asia, "South-eastern Asia"),
+    country("BG", "BGR", 100, "+359", Some("EUR"), Europe, "Eastern Europe"),
+    country("BF", "BFA", 854, "+226", Some("XOF"), Africa, "Western Africa"),
+    country("BI", "BDI", 108, "+257", Some("BIF"), Africa, "Eastern Africa"),
+    country("KH", "KHM", 116, "+855", Some("KHR"), Asia, "South-eastern Asia"),
+    country("CM", "CMR", 120, "+237", Some("XAF"), Africa, "Middle Africa"),
+    country("CA", "CAN", 124, "+1", Some("CAD"), NorthAmerica, "Northern America"),
+    country("CV", "CPV", 132, "+238", Some("CVE"), Africa, "Western Africa"),
+    country("KY", "CYM", 136, "+1345", Some("KYD"), NorthAmerica, "Caribbean"),
+    country("CF", "CAF", 140, "+236", Some("XAF"), Africa, "Middle Africa"),
+    country("TD", "TCD", 148, "+235", Some("XAF"), Africa, "Middle Africa"),
+    country("CL", "CHL", 152, "+56", Some("CLP"), SouthAmerica, "South America"),
+    country("CN", "CHN", 156, "+86", Some("CNY"), Asia, "Eastern Asia"),
+    country("CX", "CXR", 162, "+61", Some("AUD"), Oceania, "Australia and New Zealand"),
+    country("CC", "CCK", 166, "+61", Some("AUD"), Oceania, "Australia and New Zealand"),
+    country("CO", "COL", 170, "+57", Some("COP"), SouthAmerica, "South America"),
+    country("KM", "COM", 174, "+269", Some("KMF"), Africa, "Eastern Africa"),
+    country("CG", "COG", 178, "+242", Some("XAF"), Africa, "Middle Africa"),
+    country("CD", "COD", 180, "+243", Some("CDF"), Africa, "Middle Africa"),
+    country("CK", "COK", 184, "+682", Some("NZD"), Oceania, "Polynesia"),
+    country("CR", "CRI", 188, "+506", Some("CRC"), NorthAmerica, "Central America"),
+    country("CI", "CIV", 384, "+225", Some("XOF"), Africa, "Western Africa"),
+    country("HR", "HRV", 191, "+385", Some("EUR"), Europe, "Southern Europe"),
+    country("CU", "CUB", 192, "+53", Some("CUP"), NorthAmerica, "Caribbean"),
+    country("CW", "CUW", 531, "+599", Some("ANG"), NorthAmerica, "Caribbean"),
+    country("CY", "CYP", 196, "+357", Some("EUR"), Asia, "Western Asia"),
+    country("CZ", "CZE", 203, "+420", Some("CZK"), Europe, "Eastern Europe"),
+    country("DK", "DNK", 208, "+45", Some("DKK"), Europe, "Northern Europe"),
+    country("DJ", "DJI", 262, "+253", Some("DJF"), Africa, "Eastern Africa"),
+    country("DM", "DMA", 212, "+1767", Some("XCD"), NorthAmerica, "Caribbean"),
+    country("DO", "DOM", 214, "+1809", Some("DOP"), NorthAmerica, "Caribbean"),
+    country("EC", "ECU", 218, "+593", Some("USD"), SouthAmerica, "South America"),
+    country("EG", "EGY", 818, "+20", Some("EGP"), Africa, "Northern Africa"),
+    country("SV", "SLV", 222, "+503", Some("USD"), NorthAmerica, "Central America"),
+    country("GQ", "GNQ", 226, "+240", Some("XAF"), Africa, "Middle Africa"),
+    country("ER", "ERI", 232, "+291", Some("ERN"), Africa, "Eastern Africa"),
+    country("EE", "EST", 233, "+372", Some("EUR"), Europe, "Northern Europe"),
+    country("ET", "ETH", 231, "+251", Some("ETB"), Africa, "Eastern Africa"),
+    country("FK", "FLK", 238, "+500", Some("FKP"), SouthAmerica, "South America"),
+    country("FO", "FRO", 234, "+298", Some("DKK"), Europe, "Northern Europe"),
+    country("FJ", "FJI", 242, "+679", Some("FJD"), Oceania, "Melanesia"),
+    country("FI", "FIN", 246, "+358", Some("EUR"), Europe, "Northern Europe"),
+    country("FR", "FRA", 250, "+33", Some("EUR"), Europe, "Western Europe"),
+    country("GF", "GUF", 254, "+594", Some("EUR"), SouthAmerica, "South America"),
+    country("PF", "PYF", 258, "+689", Some("XPF"), Oceania, "Polynesia"),
+    country("TF", "ATF", 260, "+262", Some("EUR"), Antarctica, "Antarctica"),
+    country("GA", "GAB", 266, "+241", Some("XAF"), Africa, "Middle Africa"),
+    country("GM", "GMB", 270, "+220", Some("GMD"), Africa, "Western Africa"),
+    country("GE", "GEO", 268, "+995", Some("GEL"), Asia, "Western Asia"),
+    country("DE", "DEU", 276, "+49", Some("EUR"), Europe, "Western Europe"),
+    country("GH", "GHA", 288, "+233", Some("GHS"), Africa, "Western Africa"),
+    country("GI", "GIB", 292, "+350", Some("GIP"), Europe, "Southern Europe"),
+    country("GR", "GRC", 300, "+30", Some("EUR"), Europe, "Southern Europe"),
+    country("GL", "GRL", 304, "+299", Some("DKK"), NorthAmerica, "Northern America"),
+    country("GD", "GRD", 308, "+1473", Some("XCD"), NorthAmerica, "Caribbean"),
+    country("GP", "GLP", 312, "+590", Some("EUR"), NorthAmerica, "Caribbean"),
+    country("GU", "GUM", 316, "+1671", Some("USD"), Oceania, "Micronesia"),
+    country("GT", "GTM", 320, "+502", Some("GTQ"), NorthAmerica, "Central America"),
+    country("GG", "GGY", 831, "+44", Some("GBP"), Europe, "Northern Europe"),
+    country("GN", "GIN", 324, "+224", Some("GNF"), Africa, "Western Africa"),
+    country("GW", "GNB", 624, "+245", Some("XOF"), Africa, "Western Africa"),
+    country("GY", "GUY", 328, "+592", Some("GYD"), SouthAmerica, "South America"),
+    country("HT", "HTI", 332, "+509", Some("HTG"), NorthAmerica, "Caribbean"),
+    country("HM", "HMD", 334, "+672", Some("AUD"), Antarctica, "Antarctica"),
+    country("VA", "VAT", 336, "+39", Some("EUR"), Europe, "Southern Europe"),
+    country("HN", "HND", 340, "+504", Some("HNL"), NorthAmerica, "Central America"),
+    country("HK", "HKG", 344, "+852", Some("HKD"), Asia, "Eastern Asia"),
+    country("HU", "HUN", 348, "+36", Some("HUF"), Europe, "Eastern Europe"),
+    country("IS", "ISL", 352, "+354", Some("ISK"), Europe, "Northern Europe"),
+    country("IN", "IND", 356, "+91", Some("INR"), Asia, "Southern Asia"),
+    country("ID", "IDN", 360, "+62", Some("IDR"), Asia, "South-eastern Asia"),
+    country("IR", "IRN", 364, "+98", Some("IRR"), Asia, "Southern Asia"),
+    country("IQ", "IRQ", 368, "+964", Some("IQD"), Asia, "Western Asia"),
+    country("IE", "IRL", 372, "+353", Some("EUR"), Europe, "Northern Europe"),
+    country("IM", "IMN", 833, "+44", Some("GBP"), Europe, "Northern Europe"),
+    country("IL", "ISR", 376, "+972", Some("ILS"), Asia, "Western Asia"),
+    country("IT", "ITA", 380, "+39", Some("EUR"), Europe, "Southern Europe"),
+    country("JM", "JAM", 388, "+1876", Some("JMD"), NorthAmerica, "Caribbean"),
+    country("JP", "JPN", 392, "+81", Some("JPY"), Asia, "Eastern Asia"),
+    country("JE", "JEY", 832, "+44", Some("GBP"), Europe, "Northern Europe"),
+    country("JO", "JOR", 400, "+962", Some("JOD"), Asia, "Western Asia"),
+    country("KZ", "KAZ", 398, "+7", Some("KZT"), Asia, "Central Asia"),
+    country("KE", "KEN", 404, "+254", Some("KES"), Africa, "Eastern Africa"),
+    country("KI", "KIR", 296, "+686", Some("AUD"), Oceania, "Micronesia"),
+    country("KP", "PRK", 408, "+850", Some("KPW"), Asia, "Eastern Asia"),
+    country("KR", "KOR", 410, "+82", Some("KRW"), Asia, "Eastern Asia"),
+    country("KW", "KWT", 414, "+965", Some("KWD"), Asia, "Western Asia"),
+    country("KG", "KGZ", 417, "+996", Some("KGS"), Asia, "Central Asia"),
+    country("LA", "LAO", 418, "+856", Some("LAK"), Asia, "South-eastern Asia"),
+    country("LV", "LVA", 428, "+371", Some("EUR"), Europe, "Northern Europe"),
+    country("LB", "LBN", 422, "+961", Some("LBP"), Asia, "Western Asia"),
+    country("LS", "LSO", 426, "+266", Some("LSL"), Africa, "Southern Africa"),
+    country("LR", "LBR", 430, "+231", Some("LRD"), Africa, "Western Africa"),
+    country("LY", "LBY", 434, "+218", Some("LYD"), Africa, "Northern Africa"),
+    country("LI", "LIE", 438, "+423", Some("CHF"), Europe, "Western Europe"),
+    country("LT", "LTU", 440, "+370", Some("EUR"), Europe, "Northern Europe"),
+    country("LU", "LUX", 442, "+352", Some("EUR"), Europe, "Western Europe"),
+    country("MO", "MAC", 446, "+853", Some("MOP"), Asia, "Eastern Asia"),
+    country("MK", "MKD", 807, "+389", Some("MKD"), Europe, "Southern Europe"),
+    country("MG", "MDG", 450, "+261", Some("MGA"), Africa, "Eastern Africa"),
+    country("MW", "MWI", 454, "+265", Some("MWK"), Africa, "Eastern Africa"),
+    country("MY", "MYS", 458, "+60", Some("MYR"), Asia, "South-eastern Asia"),
+    country("MV", "MDV", 462, "+960", Some("MVR"), Asia, "Southern Asia"),
+    country("ML", "MLI", 466, "+223", Some("XOF"), Africa, "Western Africa"),
+    country("MT", "MLT", 470, "+356", Some("EUR"), Europe, "Southern Europe"),
+    country("MH", "MHL", 584, "+692", Some("USD"), Oceania, "Micronesia"),
+    country("MQ", "MTQ", 474, "+596", Some("EUR"), NorthAmerica, "Caribbean"),
+    country("MR", "MRT", 478, "+222", Some("MRU"), Africa, "Western Africa"),
+    country("MU", "MUS", 480, "+230", Some("MUR"), Africa, "Eastern Africa"),
+    country("YT", "MYT", 175, "+262", Some("EUR"), Africa, "Eastern Africa"),
+    country("MX", "MEX", 484, "+52", Some("MXN"), NorthAmerica, "Central America"),
+    country("FM", "FSM", 583, "+691", Some("USD"), Oceania, "Micronesia"),
+    country("MD", "MDA", 498, "+373", Some("MDL"), Europe, "Eastern Europe"),
+    country("MC", "MCO", 492, "+377", Some("EUR"), Europe, "Western Europe"),
+    country("MN", "MNG", 496, "+976", Some("MNT"), Asia, "Eastern Asia"),
+    country("ME", "MNE", 499, "+382", Some("EUR"), Europe, "Southern Europe"),
+    country("MS", "MSR", 500, "+1664", Some("XCD"), NorthAmerica, "Caribbean"),
+    country("MA", "MAR", 504, "+212", Some("MAD"), Africa, "Northern Africa"),
+    country("MZ", "MOZ", 508, "+258", Some("MZN"), Africa, "Eastern Africa"),
+    country("MM", "MMR", 104, "+95", Some("MMK"), Asia, "South-eastern Asia"),
+    country("NA", "NAM", 516, "+264", Some("NAD"), Africa, "Southern Africa"),
+    country("NR", "NRU", 520, "+674", Some("AUD"), Oceania, "Micronesia"),
+    country("NP", "NPL", 524, "+977", Some("NPR"), Asia, "Southern Asia"),
+    country("NL", "NLD", 528, "+31", Some("EUR"), Europe, "Western Europe"),
+    country("NC", "NCL", 540, "+687", Some("XPF"), Oceania, "Melanesia"),
+    country("NZ", "NZL", 554, "+64", Some("NZD"), Oceania, "Australia and New Zealand"),
+    country("NI", "NIC", 558, "+505", Some("NIO"), NorthAmerica, "Central America"),
+    country("NE", "NER", 562, "+227", Some("XOF"), Africa, "Western Africa"),
+    country("NG", "NGA", 566, "+234", Some("NGN"), Africa, "Western Africa"),
+    country("NU", "NIU", 570, "+683", Some("NZD"), Oceania, "Polynesia"),
+    country("NF", "NFK", 574, "+672", Some("AUD"), Oceania, "Australia and New Zealand"),
+    country("MP", "MNP", 580, "+1670", Some("USD"), Oceania, "Micronesia"),
+    country("NO", "NOR", 578, "+47", Some("NOK"), Europe, "Northern Europe"),
+    country("OM", "OMN", 512, "+968", Some("OMR"), Asia, "Western Asia"),
+    country("PK", "PAK", 586, "+92", Some("PKR"), Asia, "Southern Asia"),
+    country("PW", "PLW", 585, "+680", Some("USD"), Oceania, "Micronesia"),
+    country("PS", "PSE", 275, "+970", Some("ILS"), Asia, "Western Asia"),
+    country("PA", "PAN", 591, "+507", Some("PAB"), NorthAmerica, "Central America"),
+    country("PG", "PNG", 598, "+675", Some("PGK"), Oceania, "Melanesia"),
+    country("PY", "PRY", 600, "+595", Some("PYG"), SouthAmerica, "South America"),
+    country("PE", "PER", 604, "+51", Some("PEN"), SouthAmerica, "South America"),
+    country("PH", "PHL", 608, "+63", Some("PHP"), Asia, "South-eastern Asia"),
+    country("PN", "PCN", 612, "+64", Some("NZD"), Oceania, "Polynesia"),
+    country("PL", "POL", 616, "+48", Some("PLN"), Europe, "Eastern Europe"),
+    country("PT", "PRT", 620, "+351", Some("EUR"), Europe, "Southern Europe"),
+    country("PR", "PRI", 630, "+1787", Some("USD"), NorthAmerica, "Caribbean"),
+    country("QA", "QAT", 634, "+974", Some("QAR"), Asia, "Western Asia"),
+    country("RE", "REU", 638, "+262", Some("EUR"), Africa, "Eastern Africa"),
+    country("RO", "ROU", 642, "+40", Some("RON"), Europe, "Eastern Europe"),
+    country("RU", "RUS", 643, "+7", Some("RUB"), Europe, "Eastern Europe"),
+    country("RW", "RWA", 646, "+250", Some("RWF"), Africa, "Eastern Africa"),
+    country("BL", "BLM", 652, "+590", Some("EUR"), NorthAmerica, "Caribbean"),
+    country("SH", "SHN", 654, "+290", Some("SHP"), Africa, "Western Africa"),
+    country("KN", "KNA", 659, "+1869", Some("XCD"), NorthAmerica, "Caribbean"),
+    country("LC", "LCA", 662, "+1758", Some("XCD"), NorthAmerica, "Caribbean"),
+    country("MF", "MAF", 663, "+590", Some("EUR"), NorthAmerica, "Caribbean"),
+    country("PM", "SPM", 666, "+508", Some("EUR"), NorthAmerica, "Northern America"),
+    country("VC", "VCT", 670, "+1784", Some("XCD"), NorthAmerica, "Caribbean"),
+    country("WS", "WSM", 882, "+685", Some("WST"), Oceania, "Polynesia"),
+    country("SM", "SMR", 674, "+378", Some("EUR"), Europe, "Southern Europe"),
+    country("ST", "STP", 678, "+239", Some("STN"), Africa, "Middle Africa"),
+    country("SA", "SAU", 682, "+966", Some("SAR"), Asia, "Western Asia"),
+    country("SN", "SEN", 686, "+221", Some("XOF"), Africa, "Western Africa"),
+    country("RS", "SRB", 688, "+381", Some("RSD"), Europe, "Southern Europe"),
+    country("SC", "SYC", 690, "+248", Some("SCR"), Africa, "Eastern Africa"),
+    country("SL", "SLE", 694, "+232", Some("SLE"), Africa, "Western Africa"),
+    country("SG", "SGP", 702, "+65", Some("SGD"), Asia, "South-eastern Asia"),
+    country("SX", "SXM", 534, "+1721", Some("ANG"), NorthAmerica, "Caribbean"),
+    country("SK", "SVK", 703, "+421", Some("EUR"), Europe, "Eastern Europe"),
+    country("SI", "SVN", 705, "+386", Some("EUR"), Europe, "Southern Europe"),
+    country("SB", "SLB", 90, "+677", Some("SBD"), Oceania, "Melanesia"),
+    country("SO", "SOM", 706, "+252", Some("SOS"), Africa, "Eastern Africa"),
+    country("ZA", "ZAF", 710, "+27", Some("ZAR"), Africa, "Southern Africa"),
+    country("GS", "SGS", 239, "+500", Some("GBP"), Antarctica, "Antarctica"),
+    country("SS", "SSD", 728, "+211", Some("SSP"), Africa, "Eastern Africa"),
+    country("ES", "ESP", 724, "+34", Some("EUR"), Europe, "Southern Europe"),
+    country("LK", "LKA", 144, "+94", Some("LKR"), Asia, "Southern Asia"),
+    country("SD", "SDN", 729, "+249", Some("SDG"), Africa, "Northern Africa"),
+    country("SR", "SUR", 740, "+597", Some("SRD"), SouthAmerica, "South America"),
+    country("SJ", "SJM", 744, "+47", Some("NOK"), Europe, "Northern Europe"),
+    country("SZ", "SWZ", 748, "+268", Some("SZL"), Africa, "Southern Africa"),
+    country("SE", "SWE", 752, "+46", Some("SEK"), Europe, "Northern Europe"),
+    country("CH", "CHE", 756, "+41", Some("CHF"), Europe, "Western Europe"),
+    country("SY", "SYR", 760, "+963", Some("SYP"), Asia, "Western Asia"),
+    country("TW", "TWN", 158, "+886", Some("TWD"), Asia, "Eastern Asia"),
+    country("TJ", "TJK", 762, "+992", Some("TJS"), Asia, "Central Asia"),
+    country("TZ", "TZA", 834, "+255", Some("TZS"), Africa, "Eastern Africa"),
+    country("TH", "THA", 764, "+66", Some("THB"), Asia, "South-eastern Asia"),
+    country("TG", "TGO", 768, "+228", Some("XOF"), Africa, "Western Africa"),
+    country("TK", "TKL", 772, "+690", Some("NZD"), Oceania, "Polynesia"),
+    country("TO", "TON", 776, "+676", Some("TOP"), Oceania, "Polynesia"),
+    country("TT", "TTO", 780, "+1868", Some("TTD"), NorthAmerica, "Caribbean"),
+    country("TN", "TUN", 788, "+216", Some("TND"), Africa, "Northern Africa"),
+    country("TR", "TUR", 792, "+90", Some("TRY"), Asia, "Western Asia"),
+    country("TM", "TKM", 795, "+993", Some("TMT"), Asia, "Central Asia"),
+    country("TC", "TCA", 796, "+1649", Some("USD"), NorthAmerica, "Caribbean"),
+    country("TV", "TUV", 798, "+688", Some("AUD"), Oceania, "Polynesia"),
+    country("UG", "UGA", 800, "+256", Some("UGX"), Africa, "Eastern Africa"),
+    country("UA", "UKR", 804, "+380", Some("UAH"), Europe, "Eastern Europe"),
+    country("AE", "ARE", 784, "+971", Some("AED"), Asia, "Western Asia"),
+    country("GB", "GBR", 826, "+44", Some("GBP"), Europe, "Northern Europe"),
+    country("US", "USA", 840, "+1", Some("USD"), NorthAmerica, "Northern America"),
+    country("UM", "UMI", 581, "+1", Some("USD"), Oceania, "Micronesia"),
+    country("UY", "URY", 858, "+598", Some("UYU"), SouthAmerica, "South America"),
+    country("UZ", "UZB", 860, "+998", Some("UZS"), Asia, "Central Asia"),
+    country("VU", "VUT", 548, "+678", Some("VUV"), Oceania, "Melanesia"),
+    country("VE", "VEN", 862, "+58", Some("VES"), SouthAmerica, "South America"),
+    country("VN", "VNM", 704, "+84", Some("VND"), Asia, "South-eastern Asia"),
+    country("VI", "VIR", 850, "+1340", Some("USD"), NorthAmerica, "Caribbean"),
+    country("WF", "WLF", 876, "+681", Some("XPF"), Oceania, "Polynesia"),
+    country("EH", "ESH", 732, "+212", Some("MAD"), Africa, "Northern Africa"),
+    country("YE", "YEM", 887, "+967", Some("YER"), Asia, "Western Asia"),
+    country("ZM", "ZMB", 894, "+260", Some("ZMW"), Africa, "Eastern Africa"),
+    country("ZW", "ZWE", 716, "+263", Some("ZWG"), Africa, "Eastern Africa"),
+];
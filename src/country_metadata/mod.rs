@@ -0,0 +1,5 @@
+mod country_metadata_table;
+pub use country_metadata_table::*;
+
+mod country_metadata_lookup;
+pub use country_metadata_lookup::*;
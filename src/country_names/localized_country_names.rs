@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use icu_collator::{Collator, CollatorOptions};
+use icu_locid::Locale;
+use lazy_static::lazy_static;
+use rust_common::country_code::CountryCode;
+use serde::Serialize;
+use service_sdk::my_http_server::HttpContext;
+
+use crate::{get_country_iso2, get_country_pairs, GetPreferredLanguage, DEFAULT_LANGUAGE};
+
+// One `<iso2> <name>` per line, same countries as `get_country_pairs`
+const BUNDLED_COUNTRY_NAMES: &[(&str, &str)] = &[
+    ("ar", include_str!("names/ar.txt")),
+    ("de", include_str!("names/de.txt")),
+    ("es", include_str!("names/es.txt")),
+    ("fr", include_str!("names/fr.txt")),
+    ("it", include_str!("names/it.txt")),
+    ("pl", include_str!("names/pl.txt")),
+    ("pt", include_str!("names/pt.txt")),
+    ("ru", include_str!("names/ru.txt")),
+];
+
+/// Languages of the bundled country names. English ones are the names of `get_country_pairs`.
+pub const COUNTRY_NAME_LANGUAGES: &[&str] = &["en", "ar", "de", "es", "fr", "it", "pl", "pt", "ru"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct LocalizedCountryName {
+    pub iso2: &'static str,
+    pub name: &'static str,
+}
+
+lazy_static! {
+    static ref COUNTRY_NAMES: HashMap<&'static str, HashMap<&'static str, &'static str>> = {
+        let mut map = HashMap::new();
+        map.insert(
+            DEFAULT_LANGUAGE,
+            get_country_pairs()
+                .into_iter()
+                .map(|(name, iso2)| (iso2, name))
+                .collect(),
+        );
+        for &(language, names) in BUNDLED_COUNTRY_NAMES {
+            map.insert(language, parse_country_names(names));
+        }
+        map
+    };
+    static ref SORTED_COUNTRY_NAMES: HashMap<&'static str, Vec<LocalizedCountryName>> = {
+        let mut map = HashMap::new();
+        for &language in COUNTRY_NAME_LANGUAGES {
+            map.insert(language, sort_country_names(language));
+        }
+        map
+    };
+}
+
+fn parse_country_names(names: &'static str) -> HashMap<&'static str, &'static str> {
+    names
+        .lines()
+        .filter_map(|line| line.split_once(' '))
+        .collect()
+}
+
+// Names are compared with the collation rules of the language: "Österreich" goes
+// right after "Oman" in German, "Łotwa" after "Luksemburg" in Polish.
+fn sort_country_names(language: &'static str) -> Vec<LocalizedCountryName> {
+    let mut result: Vec<LocalizedCountryName> = get_country_pairs()
+        .iter()
+        .map(|(_, iso2)| LocalizedCountryName {
+            iso2,
+            name: get_country_name_by_iso2(iso2, language).unwrap_or(iso2),
+        })
+        .collect();
+
+    let collator = language
+        .parse::<Locale>()
+        .ok()
+        .and_then(|locale| Collator::try_new(&(&locale).into(), CollatorOptions::new()).ok());
+
+    match collator {
+        Some(collator) => result.sort_by(|a, b| collator.compare(a.name, b.name)),
+        None => result.sort_by(|a, b| a.name.cmp(b.name)),
+    }
+
+    result
+}
+
+/// "pt-BR" -> "pt". Unsupported languages fall back to English.
+fn get_supported_language(language: &str) -> &'static str {
+    let language = language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+
+    COUNTRY_NAME_LANGUAGES
+        .iter()
+        .find(|supported| **supported == language)
+        .copied()
+        .unwrap_or(DEFAULT_LANGUAGE)
+}
+
+fn get_country_name_by_iso2(iso2: &str, language: &str) -> Option<&'static str> {
+    let language = get_supported_language(language);
+
+    COUNTRY_NAMES
+        .get(language)
+        .and_then(|names| names.get(iso2))
+        .or_else(|| COUNTRY_NAMES.get(DEFAULT_LANGUAGE)?.get(iso2))
+        .copied()
+}
+
+/// Name of the country in `language` ("de", "pt-BR"...), English if there is no translation.
+pub fn get_localized_country_name(country: &CountryCode, language: &str) -> Option<&'static str> {
+    get_country_name_by_iso2(get_country_iso2(country)?, language)
+}
+
+/// Countries for dropdowns, sorted by the localized name.
+pub fn get_sorted_country_names(language: &str) -> &'static [LocalizedCountryName] {
+    SORTED_COUNTRY_NAMES
+        .get(get_supported_language(language))
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+/// Sorted countries in the language from the `lang` cookie or `Accept-Language` header.
+pub fn get_preferred_sorted_country_names(ctx: &HttpContext) -> &'static [LocalizedCountryName] {
+    let language = ctx.get_preferred_language(COUNTRY_NAME_LANGUAGES.to_vec());
+    get_sorted_country_names(&language)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_names_cover_all_countries() {
+        let pairs = get_country_pairs();
+
+        for &(language, names) in BUNDLED_COUNTRY_NAMES {
+            assert!(COUNTRY_NAME_LANGUAGES.contains(&language));
+            assert_eq!(names.lines().count(), pairs.len(), "{}", language);
+
+            let names = &COUNTRY_NAMES[language];
+            assert_eq!(names.len(), pairs.len(), "{}", language);
+
+            for (_, iso2) in pairs.iter() {
+                let name = names.get(iso2);
+                assert!(
+                    name.is_some_and(|name| !name.trim().is_empty() && name.trim() == *name),
+                    "{} {}",
+                    language,
+                    iso2
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn localized_names_with_fallback() {
+        let code = CountryCode::parse("DE").unwrap();
+
+        for (language, expected) in [
+            ("en", "Germany"),
+            ("de", "Deutschland"),
+            ("DE-at", "Deutschland"),
+            ("pt_BR", "Alemanha"),
+            ("pl", "Niemcy"),
+            ("ar", "ألمانيا"),
+            ("ja", "Germany"),
+            ("", "Germany"),
+        ] {
+            assert_eq!(
+                get_localized_country_name(&code, language),
+                Some(expected),
+                "{}",
+                language
+            );
+        }
+    }
+
+    #[test]
+    fn sorted_by_language_collation() {
+        let position = |language: &str, iso2: &str| {
+            get_sorted_country_names(language)
+                .iter()
+                .position(|country| country.iso2 == iso2)
+                .unwrap()
+        };
+
+        // Oman, Österreich, Pakistan
+        assert_eq!(position("de", "AT"), position("de", "OM") + 1);
+        assert_eq!(position("de", "PK"), position("de", "AT") + 1);
+        // Luksemburg, Łotwa
+        assert_eq!(position("pl", "LV"), position("pl", "LU") + 1);
+        assert_eq!(position("es", "AF"), 0);
+        assert_eq!(position("fr", "AF"), 0);
+
+        for &language in COUNTRY_NAME_LANGUAGES {
+            assert_eq!(
+                get_sorted_country_names(language).len(),
+                get_country_pairs().len()
+            );
+        }
+
+        assert_eq!(
+            get_sorted_country_names("ja"),
+            get_sorted_country_names("en")
+        );
+    }
+}
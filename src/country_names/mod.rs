@@ -0,0 +1,2 @@
+mod localized_country_names;
+pub use localized_country_names::*;
@@ -0,0 +1,5 @@
+mod wallet_address;
+pub use wallet_address::*;
+
+mod wallet_address_validators;
+pub use wallet_address_validators::*;
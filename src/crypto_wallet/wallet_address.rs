@@ -0,0 +1,442 @@
+use bech32::primitives::decode::SegwitHrpstringError;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+
+use crate::{ValidationFailure, CODE_INVALID_CHECKSUM, CODE_INVALID_FORMAT};
+
+pub const CODE_WRONG_NETWORK: &str = "wrong_network";
+
+const BITCOIN_P2PKH_VERSION: u8 = 0x00;
+const BITCOIN_P2SH_VERSION: u8 = 0x05;
+const BITCOIN_TESTNET_P2PKH_VERSION: u8 = 0x6f;
+const BITCOIN_TESTNET_P2SH_VERSION: u8 = 0xc4;
+const TRON_ADDRESS_PREFIX: u8 = 0x41;
+
+/// Network the payout is sent in. Tokens use the address format of their chain:
+/// USDT TRC-20 is validated as `Tron`, USDT ERC-20 as `Ethereum`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CryptoNetwork {
+    Bitcoin,
+    BitcoinTestnet,
+    Ethereum,
+    Tron,
+    Solana,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalletAddressType {
+    /// Legacy "1..." address
+    P2pkh,
+    /// "3..." address
+    P2sh,
+    /// SegWit v0 with 20 byte program, "bc1q..."
+    P2wpkh,
+    /// SegWit v0 with 32 byte program, "bc1q..."
+    P2wsh,
+    /// SegWit v1, "bc1p..."
+    P2tr,
+    Ethereum,
+    Tron,
+    Solana,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WalletAddress {
+    pub network: CryptoNetwork,
+    pub address_type: WalletAddressType,
+    /// Bech32 addresses are lowercased, Ethereum addresses get EIP-55 checksum
+    pub value: String,
+}
+
+impl WalletAddress {
+    pub fn parse(network: CryptoNetwork, value: &str) -> Result<Self, ValidationFailure> {
+        if value.is_empty() {
+            return Err(invalid_format("Wallet address: Should not be empty"));
+        }
+
+        if value.trim() != value {
+            return Err(invalid_format(
+                "Wallet address: Should not start or end with space",
+            ));
+        }
+
+        let (address_type, value) = match network {
+            CryptoNetwork::Bitcoin => parse_bitcoin(value, false)?,
+            CryptoNetwork::BitcoinTestnet => parse_bitcoin(value, true)?,
+            CryptoNetwork::Ethereum => parse_ethereum(value)?,
+            CryptoNetwork::Tron => parse_tron(value)?,
+            CryptoNetwork::Solana => parse_solana(value)?,
+        };
+
+        Ok(Self {
+            network,
+            address_type,
+            value,
+        })
+    }
+}
+
+fn invalid_format(message: &str) -> ValidationFailure {
+    ValidationFailure::new(CODE_INVALID_FORMAT, message)
+}
+
+fn invalid_checksum(message: &str) -> ValidationFailure {
+    ValidationFailure::new(CODE_INVALID_CHECKSUM, message)
+}
+
+fn wrong_network(message: &str) -> ValidationFailure {
+    ValidationFailure::new(CODE_WRONG_NETWORK, message)
+}
+
+fn get_double_sha256(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(Sha256::digest(data)).into()
+}
+
+/// Returns the payload without the 4 byte checksum.
+fn decode_base58_check(value: &str) -> Result<Vec<u8>, ValidationFailure> {
+    let mut data = bs58::decode(value)
+        .into_vec()
+        .map_err(|_| invalid_format("Wallet address: Not a valid Base58 string"))?;
+
+    if data.len() < 5 {
+        return Err(invalid_format("Wallet address: Too short"));
+    }
+
+    let payload_len = data.len() - 4;
+
+    if get_double_sha256(&data[..payload_len])[..4] != data[payload_len..] {
+        return Err(invalid_checksum("Wallet address: Invalid checksum"));
+    }
+
+    data.truncate(payload_len);
+    Ok(data)
+}
+
+fn parse_bitcoin(
+    value: &str,
+    is_testnet: bool,
+) -> Result<(WalletAddressType, String), ValidationFailure> {
+    let lowercase = value.to_ascii_lowercase();
+
+    if lowercase.starts_with("bc1") || lowercase.starts_with("tb1") {
+        let address_type = parse_bitcoin_segwit(value, is_testnet)?;
+        return Ok((address_type, lowercase));
+    }
+
+    let payload = decode_base58_check(value)?;
+
+    if payload.len() != 21 {
+        return Err(invalid_format(
+            "Wallet address: Invalid Bitcoin address length",
+        ));
+    }
+
+    let address_type = match (payload[0], is_testnet) {
+        (BITCOIN_P2PKH_VERSION, false) | (BITCOIN_TESTNET_P2PKH_VERSION, true) => {
+            WalletAddressType::P2pkh
+        }
+        (BITCOIN_P2SH_VERSION, false) | (BITCOIN_TESTNET_P2SH_VERSION, true) => {
+            WalletAddressType::P2sh
+        }
+        (BITCOIN_TESTNET_P2PKH_VERSION | BITCOIN_TESTNET_P2SH_VERSION, false)
+        | (BITCOIN_P2PKH_VERSION | BITCOIN_P2SH_VERSION, true) => {
+            return Err(wrong_network(
+                "Wallet address: Address belongs to another Bitcoin network",
+            ));
+        }
+        _ => {
+            return Err(wrong_network("Wallet address: Not a Bitcoin address"));
+        }
+    };
+
+    Ok((address_type, value.to_string()))
+}
+
+fn parse_bitcoin_segwit(
+    value: &str,
+    is_testnet: bool,
+) -> Result<WalletAddressType, ValidationFailure> {
+    let (hrp, version, program) = bech32::segwit::decode(value).map_err(|err| match err.0 {
+        SegwitHrpstringError::Checksum(_) => invalid_checksum("Wallet address: Invalid checksum"),
+        _ => invalid_format("Wallet address: Not a valid SegWit address"),
+    })?;
+
+    let expected_hrp = if is_testnet { "tb" } else { "bc" };
+
+    if hrp.to_lowercase() != expected_hrp {
+        return Err(wrong_network(
+            "Wallet address: Address belongs to another Bitcoin network",
+        ));
+    }
+
+    match (version.to_u8(), program.len()) {
+        (0, 20) => Ok(WalletAddressType::P2wpkh),
+        (0, 32) => Ok(WalletAddressType::P2wsh),
+        (1, 32) => Ok(WalletAddressType::P2tr),
+        _ => Err(invalid_format("Wallet address: Unsupported SegWit version")),
+    }
+}
+
+fn parse_ethereum(value: &str) -> Result<(WalletAddressType, String), ValidationFailure> {
+    let Some(hex) = value.strip_prefix("0x") else {
+        return Err(invalid_format("Wallet address: Should start with 0x"));
+    };
+
+    if hex.len() != 40 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(invalid_format(
+            "Wallet address: Should contain 40 hex symbols after 0x",
+        ));
+    }
+
+    let checksummed = get_eip55_address(hex);
+
+    // Single case addresses carry no checksum
+    let is_single_case = !hex.bytes().any(|b| b.is_ascii_lowercase())
+        || !hex.bytes().any(|b| b.is_ascii_uppercase());
+
+    if !is_single_case && checksummed[2..] != *hex {
+        return Err(invalid_checksum("Wallet address: Invalid EIP-55 checksum"));
+    }
+
+    Ok((WalletAddressType::Ethereum, checksummed))
+}
+
+fn get_eip55_address(hex: &str) -> String {
+    let lowercase = hex.to_ascii_lowercase();
+    let hash = Keccak256::digest(lowercase.as_bytes());
+
+    let mut result = String::with_capacity(42);
+    result.push_str("0x");
+
+    for (index, c) in lowercase.chars().enumerate() {
+        let nibble = (hash[index / 2] >> (if index % 2 == 0 { 4 } else { 0 })) & 0x0f;
+
+        if c.is_ascii_alphabetic() && nibble >= 8 {
+            result.push(c.to_ascii_uppercase());
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+fn parse_tron(value: &str) -> Result<(WalletAddressType, String), ValidationFailure> {
+    if value.starts_with("0x") {
+        return Err(wrong_network(
+            "Wallet address: Ethereum address is not valid in TRON network",
+        ));
+    }
+
+    let payload = decode_base58_check(value)?;
+
+    if payload.len() != 21 || payload[0] != TRON_ADDRESS_PREFIX {
+        return Err(wrong_network("Wallet address: Not a TRON address"));
+    }
+
+    Ok((WalletAddressType::Tron, value.to_string()))
+}
+
+fn parse_solana(value: &str) -> Result<(WalletAddressType, String), ValidationFailure> {
+    let data = bs58::decode(value)
+        .into_vec()
+        .map_err(|_| invalid_format("Wallet address: Not a valid Base58 string"))?;
+
+    // Ed25519 public key
+    if data.len() != 32 {
+        return Err(invalid_format(
+            "Wallet address: Solana address should contain 32 bytes",
+        ));
+    }
+
+    Ok((WalletAddressType::Solana, value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(network: CryptoNetwork, value: &str) -> Result<WalletAddress, ValidationFailure> {
+        WalletAddress::parse(network, value)
+    }
+
+    fn error_code(network: CryptoNetwork, value: &str) -> &'static str {
+        parse(network, value).unwrap_err().code
+    }
+
+    #[test]
+    fn bitcoin_addresses() {
+        let cases = [
+            (
+                "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2",
+                WalletAddressType::P2pkh,
+            ),
+            (
+                "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy",
+                WalletAddressType::P2sh,
+            ),
+            (
+                "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+                WalletAddressType::P2wpkh,
+            ),
+            (
+                "bc1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3qccfmv3",
+                WalletAddressType::P2wsh,
+            ),
+            (
+                "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0",
+                WalletAddressType::P2tr,
+            ),
+        ];
+
+        for (value, address_type) in cases {
+            let address = parse(CryptoNetwork::Bitcoin, value).unwrap();
+            assert_eq!(address.address_type, address_type, "{}", value);
+        }
+
+        let address = parse(
+            CryptoNetwork::Bitcoin,
+            "BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4",
+        )
+        .unwrap();
+        assert_eq!(address.value, "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4");
+    }
+
+    #[test]
+    fn bitcoin_errors() {
+        assert_eq!(
+            error_code(CryptoNetwork::Bitcoin, "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3"),
+            CODE_INVALID_CHECKSUM
+        );
+        assert_eq!(
+            error_code(
+                CryptoNetwork::Bitcoin,
+                "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5"
+            ),
+            CODE_INVALID_CHECKSUM
+        );
+        // v1 program with bech32 instead of bech32m checksum (BIP-350 test vector)
+        assert!(parse(
+            CryptoNetwork::Bitcoin,
+            "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqh2y7hd"
+        )
+        .is_err());
+        assert_eq!(
+            error_code(
+                CryptoNetwork::Bitcoin,
+                "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx"
+            ),
+            CODE_WRONG_NETWORK
+        );
+        assert_eq!(
+            error_code(CryptoNetwork::Bitcoin, "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn"),
+            CODE_WRONG_NETWORK
+        );
+        assert_eq!(
+            error_code(CryptoNetwork::Bitcoin, "0OIl"),
+            CODE_INVALID_FORMAT
+        );
+
+        assert!(parse(
+            CryptoNetwork::BitcoinTestnet,
+            "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx"
+        )
+        .is_ok());
+        assert!(parse(
+            CryptoNetwork::BitcoinTestnet,
+            "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn"
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn ethereum_addresses() {
+        let checksummed = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
+        assert_eq!(
+            parse(CryptoNetwork::Ethereum, checksummed).unwrap().value,
+            checksummed
+        );
+        assert_eq!(
+            parse(
+                CryptoNetwork::Ethereum,
+                &checksummed.to_lowercase().replace("0X", "0x")
+            )
+            .unwrap()
+            .value,
+            checksummed
+        );
+        assert_eq!(
+            error_code(
+                CryptoNetwork::Ethereum,
+                "0x5AAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+            ),
+            CODE_INVALID_CHECKSUM
+        );
+        assert_eq!(
+            error_code(
+                CryptoNetwork::Ethereum,
+                "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+            ),
+            CODE_INVALID_FORMAT
+        );
+        assert_eq!(
+            error_code(
+                CryptoNetwork::Ethereum,
+                "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAe"
+            ),
+            CODE_INVALID_FORMAT
+        );
+    }
+
+    #[test]
+    fn tron_addresses() {
+        assert!(parse(CryptoNetwork::Tron, "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t").is_ok());
+        assert_eq!(
+            error_code(CryptoNetwork::Tron, "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6u"),
+            CODE_INVALID_CHECKSUM
+        );
+        assert_eq!(
+            error_code(CryptoNetwork::Tron, "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2"),
+            CODE_WRONG_NETWORK
+        );
+        assert_eq!(
+            error_code(
+                CryptoNetwork::Tron,
+                "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+            ),
+            CODE_WRONG_NETWORK
+        );
+    }
+
+    #[test]
+    fn solana_addresses() {
+        assert!(parse(
+            CryptoNetwork::Solana,
+            "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
+        )
+        .is_ok());
+        assert!(parse(CryptoNetwork::Solana, "11111111111111111111111111111111").is_ok());
+        assert_eq!(
+            error_code(
+                CryptoNetwork::Solana,
+                "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt"
+            ),
+            CODE_INVALID_FORMAT
+        );
+        assert_eq!(
+            error_code(
+                CryptoNetwork::Solana,
+                "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+            ),
+            CODE_INVALID_FORMAT
+        );
+    }
+
+    #[test]
+    fn rejects_spaces_and_empty_values() {
+        assert!(parse(CryptoNetwork::Ethereum, "").is_err());
+        assert!(parse(CryptoNetwork::Tron, " TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t").is_err());
+    }
+}
@@ -0,0 +1,33 @@
+use service_sdk::my_http_server::{HttpContext, HttpFailResult};
+
+use crate::{into_http_fail, ValidationFailure};
+
+use super::{CryptoNetwork, WalletAddress};
+
+pub fn check_wallet_address(
+    network: CryptoNetwork,
+    value: &str,
+) -> Result<WalletAddress, ValidationFailure> {
+    WalletAddress::parse(network, value)
+}
+
+/// Validates a payout address and returns it normalized, see [`WalletAddress::value`].
+pub fn validate_wallet_address(
+    ctx: &HttpContext,
+    network: CryptoNetwork,
+    value: &str,
+) -> Result<WalletAddress, HttpFailResult> {
+    into_http_fail(ctx, check_wallet_address(network, value))
+}
+
+pub fn validate_wallet_address_optional(
+    ctx: &HttpContext,
+    network: CryptoNetwork,
+    value: &Option<String>,
+) -> Result<Option<WalletAddress>, HttpFailResult> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+
+    validate_wallet_address(ctx, network, value).map(Some)
+}
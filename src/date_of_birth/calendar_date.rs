@@ -0,0 +1,188 @@
+use service_sdk::rust_extensions::date_time::DateTimeAsMicroseconds;
+
+const MICROSECONDS_IN_DAY: i64 = 24 * 60 * 60 * 1_000_000;
+
+/// Date without time and timezone. Ages and birthdays are compared on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl CalendarDate {
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > get_days_in_month(year, month) {
+            return None;
+        }
+
+        Some(Self { year, month, day })
+    }
+
+    /// Current date in UTC
+    pub fn today() -> Self {
+        Self::from_date_time(DateTimeAsMicroseconds::now())
+    }
+
+    pub fn from_date_time(value: DateTimeAsMicroseconds) -> Self {
+        let days = value.unix_microseconds.div_euclid(MICROSECONDS_IN_DAY);
+        get_date_from_days(days)
+    }
+
+    /// Accepts plain `YYYY-MM-DD` and timestamps. Timestamps are taken in UTC.
+    pub fn parse(value: &str) -> Option<Self> {
+        if let Some(result) = Self::parse_ymd(value) {
+            return Some(result);
+        }
+
+        let date_time = DateTimeAsMicroseconds::from_str(value)?;
+        Some(Self::from_date_time(date_time))
+    }
+
+    fn parse_ymd(value: &str) -> Option<Self> {
+        let bytes = value.as_bytes();
+
+        if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+            return None;
+        }
+
+        let is_digits = |range: std::ops::Range<usize>| bytes[range].iter().all(u8::is_ascii_digit);
+
+        if !is_digits(0..4) || !is_digits(5..7) || !is_digits(8..10) {
+            return None;
+        }
+
+        Self::new(
+            value[0..4].parse().ok()?,
+            value[5..7].parse().ok()?,
+            value[8..10].parse().ok()?,
+        )
+    }
+
+    /// Full years passed since `self` till `date`. People born on February 29
+    /// get one year older on March 1 in non-leap years.
+    pub fn get_full_years_till(&self, date: &Self) -> i32 {
+        let mut result = date.year - self.year;
+
+        if (date.month, date.day) < (self.month, self.day) {
+            result -= 1;
+        }
+
+        result
+    }
+}
+
+impl std::fmt::Display for CalendarDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn get_days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Days since 1970-01-01 to the proleptic Gregorian date (H. Hinnant's civil_from_days)
+fn get_date_from_days(days: i64) -> CalendarDate {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    CalendarDate {
+        year: year as i32,
+        month,
+        day,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_plain_dates() {
+        assert_eq!(
+            CalendarDate::parse("2000-02-29"),
+            CalendarDate::new(2000, 2, 29)
+        );
+
+        for value in [
+            "1999-02-29",
+            "2000-13-01",
+            "2000-00-10",
+            "2000-1-01",
+            "20000101",
+            "abcd-ef-gh",
+        ] {
+            assert!(CalendarDate::parse(value).is_none(), "{}", value);
+        }
+    }
+
+    #[test]
+    fn converts_timestamps_in_utc() {
+        assert_eq!(
+            get_date_from_days(0),
+            CalendarDate::new(1970, 1, 1).unwrap()
+        );
+        assert_eq!(
+            get_date_from_days(-1),
+            CalendarDate::new(1969, 12, 31).unwrap()
+        );
+        assert_eq!(
+            get_date_from_days(11_016),
+            CalendarDate::new(2000, 2, 29).unwrap()
+        );
+
+        let date_time = DateTimeAsMicroseconds {
+            unix_microseconds: 951_825_600_000_000,
+        };
+        assert_eq!(
+            CalendarDate::from_date_time(date_time).to_string(),
+            "2000-02-29"
+        );
+    }
+
+    #[test]
+    fn full_years_respect_birthday() {
+        let birth = CalendarDate::new(2000, 6, 15).unwrap();
+
+        assert_eq!(
+            birth.get_full_years_till(&CalendarDate::new(2018, 6, 14).unwrap()),
+            17
+        );
+        assert_eq!(
+            birth.get_full_years_till(&CalendarDate::new(2018, 6, 15).unwrap()),
+            18
+        );
+
+        let leap_birth = CalendarDate::new(2004, 2, 29).unwrap();
+        assert_eq!(
+            leap_birth.get_full_years_till(&CalendarDate::new(2022, 2, 28).unwrap()),
+            17
+        );
+        assert_eq!(
+            leap_birth.get_full_years_till(&CalendarDate::new(2022, 3, 1).unwrap()),
+            18
+        );
+        assert_eq!(
+            leap_birth.get_full_years_till(&CalendarDate::new(2024, 2, 29).unwrap()),
+            20
+        );
+    }
+}
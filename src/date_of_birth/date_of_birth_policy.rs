@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+
+use rust_common::country_code::CountryCode;
+use serde::{Deserialize, Serialize};
+
+use super::CalendarDate;
+use crate::{
+    get_country_iso2, BrandPolicies, ValidationFailure, CODE_INVALID_FORMAT, CODE_TOO_YOUNG,
+};
+
+pub const CODE_IMPLAUSIBLE_DATE: &str = "implausible_date";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DateOfBirthPolicy {
+    #[serde(rename = "minAge")]
+    pub min_age: u32,
+    /// Older dates are treated as typos
+    #[serde(rename = "maxAge")]
+    pub max_age: u32,
+    /// Min age by ISO2 country code, overrides `min_age`
+    #[serde(rename = "countryMinAges")]
+    pub country_min_ages: HashMap<String, u32>,
+}
+
+impl Default for DateOfBirthPolicy {
+    fn default() -> Self {
+        Self {
+            min_age: 18,
+            max_age: 120,
+            country_min_ages: HashMap::new(),
+        }
+    }
+}
+
+impl DateOfBirthPolicy {
+    pub fn get_min_age(&self, country: Option<&CountryCode>) -> u32 {
+        country
+            .and_then(get_country_iso2)
+            .and_then(|iso2| self.country_min_ages.get(iso2))
+            .copied()
+            .unwrap_or(self.min_age)
+    }
+
+    /// `today` is a parameter to keep the check reproducible, pass [`CalendarDate::today`] in handlers.
+    pub fn check(
+        &self,
+        value: &str,
+        country: Option<&CountryCode>,
+        today: CalendarDate,
+    ) -> Result<CalendarDate, ValidationFailure> {
+        let Some(date_of_birth) = CalendarDate::parse(value) else {
+            return Err(ValidationFailure::new(
+                CODE_INVALID_FORMAT,
+                "DateOfBirth: Not a valid date!",
+            ));
+        };
+
+        if date_of_birth > today {
+            return Err(ValidationFailure::new(
+                CODE_IMPLAUSIBLE_DATE,
+                "DateOfBirth: Should not be in the future",
+            ));
+        }
+
+        let age = date_of_birth.get_full_years_till(&today);
+
+        if age > self.max_age as i32 {
+            return Err(ValidationFailure::new(
+                CODE_IMPLAUSIBLE_DATE,
+                format!("DateOfBirth: Should not be older than {}", self.max_age),
+            ));
+        }
+
+        let min_age = self.get_min_age(country);
+
+        if age < min_age as i32 {
+            return Err(ValidationFailure::new(
+                CODE_TOO_YOUNG,
+                format!("DateOfBirth: Should be older than {}", min_age),
+            ));
+        }
+
+        Ok(date_of_birth)
+    }
+}
+
+pub type DateOfBirthPolicies = BrandPolicies<DateOfBirthPolicy>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> CalendarDate {
+        CalendarDate::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn min_age_is_reached_on_birthday() {
+        let policy = DateOfBirthPolicy::default();
+        let today = date(2024, 6, 15);
+
+        assert!(policy.check("2006-06-15", None, today).is_ok());
+        assert_eq!(
+            policy.check("2006-06-16", None, today).unwrap_err().code,
+            CODE_TOO_YOUNG
+        );
+    }
+
+    #[test]
+    fn leap_day_birthday() {
+        let policy = DateOfBirthPolicy::default();
+
+        assert!(policy.check("2004-02-29", None, date(2022, 2, 28)).is_err());
+        assert!(policy.check("2004-02-29", None, date(2022, 3, 1)).is_ok());
+    }
+
+    #[test]
+    fn rejects_implausible_dates() {
+        let policy = DateOfBirthPolicy::default();
+        let today = date(2024, 6, 15);
+
+        for value in ["2024-06-16", "1903-06-15"] {
+            assert_eq!(
+                policy.check(value, None, today).unwrap_err().code,
+                CODE_IMPLAUSIBLE_DATE,
+                "{}",
+                value
+            );
+        }
+
+        assert!(policy.check("1904-06-15", None, today).is_ok());
+        assert_eq!(
+            policy.check("2000-02-30", None, today).unwrap_err().code,
+            CODE_INVALID_FORMAT
+        );
+    }
+
+    #[test]
+    fn accepts_timestamps() {
+        let policy = DateOfBirthPolicy::default();
+
+        assert_eq!(
+            policy
+                .check("2000-02-29T12:00:00", None, date(2024, 6, 15))
+                .unwrap(),
+            date(2000, 2, 29)
+        );
+    }
+
+    #[test]
+    fn min_age_by_country_and_brand() {
+        let policies: DateOfBirthPolicies = serde_json::from_str(
+            r#"{"brands":{"brand-us":{"minAge":18,"countryMinAges":{"US":21}}}}"#,
+        )
+        .unwrap();
+
+        let policy = policies.get("brand-us");
+        let today = date(2024, 6, 15);
+
+        assert!(policy.check("2004-06-15", None, today).is_ok());
+        assert!(policy
+            .check("2004-06-15", Some(&CountryCode::GBR), today)
+            .is_ok());
+
+        let err = policy
+            .check("2004-06-15", Some(&CountryCode::USA), today)
+            .unwrap_err();
+        assert_eq!(err.code, CODE_TOO_YOUNG);
+        assert_eq!(err.message, "DateOfBirth: Should be older than 21");
+
+        assert_eq!(policies.get("other").max_age, 120);
+    }
+}
@@ -0,0 +1,5 @@
+mod calendar_date;
+pub use calendar_date::*;
+
+mod date_of_birth_policy;
+pub use date_of_birth_policy::*;
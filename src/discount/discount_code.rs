@@ -0,0 +1,469 @@
+use serde::Serialize;
+use service_sdk::rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use crate::{normalize_discount_code, Amount, ApiResultStatus, Currency};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiscountValue {
+    /// Whole percent of the price, 1..=100
+    Percent(u32),
+    /// Fixed amount off the price. Only applies to prices in the same currency.
+    Fixed { amount: Amount, currency: String },
+}
+
+/// Discount code as services store it. `code` is stored normalized, see [`normalize_discount_code`].
+#[derive(Debug, Clone)]
+pub struct DiscountCode {
+    pub code: String,
+    pub brand_id: String,
+    pub value: DiscountValue,
+    pub valid_from: Option<DateTimeAsMicroseconds>,
+    /// Exclusive: the code is expired from this moment
+    pub valid_till: Option<DateTimeAsMicroseconds>,
+    /// Total number of usages by all clients
+    pub max_usages: Option<u32>,
+    pub max_usages_per_client: Option<u32>,
+    /// Empty means the code applies to any package
+    pub package_ids: Vec<String>,
+    pub usages: u32,
+}
+
+/// Purchase the discount code is entered for.
+#[derive(Debug, Clone)]
+pub struct DiscountRequest<'s> {
+    pub brand_id: &'s str,
+    pub package_id: &'s str,
+    pub price: Amount,
+    pub currency: &'s Currency,
+    /// How many times the client has already used the code
+    pub client_usages: u32,
+    pub now: DateTimeAsMicroseconds,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscountedPrice {
+    pub original_price: Amount,
+    pub discount: Amount,
+    pub price: Amount,
+}
+
+impl DiscountCode {
+    /// Rules are checked in order: brand and validity start ([`ApiResultStatus::InvalidDiscountCode`]),
+    /// expiration ([`ApiResultStatus::DiscountCodeExpired`]), usage limits
+    /// ([`ApiResultStatus::DiscountCodeUsageExceeded`]) and package and currency
+    /// ([`ApiResultStatus::DiscountCodeForAnotherPackage`]).
+    ///
+    /// Percent discounts are rounded down to the minor unit of the currency, so the client never gets
+    /// more than the configured discount. The price never goes below zero.
+    pub fn evaluate(&self, request: &DiscountRequest) -> Result<DiscountedPrice, ApiResultStatus> {
+        if self.brand_id != request.brand_id {
+            return Err(ApiResultStatus::InvalidDiscountCode);
+        }
+
+        let now = request.now.unix_microseconds;
+
+        if let Some(valid_from) = &self.valid_from {
+            if now < valid_from.unix_microseconds {
+                return Err(ApiResultStatus::InvalidDiscountCode);
+            }
+        }
+
+        if let Some(valid_till) = &self.valid_till {
+            if now >= valid_till.unix_microseconds {
+                return Err(ApiResultStatus::DiscountCodeExpired);
+            }
+        }
+
+        if let Some(max_usages) = self.max_usages {
+            if self.usages >= max_usages {
+                return Err(ApiResultStatus::DiscountCodeUsageExceeded);
+            }
+        }
+
+        if let Some(max_usages_per_client) = self.max_usages_per_client {
+            if request.client_usages >= max_usages_per_client {
+                return Err(ApiResultStatus::DiscountCodeUsageExceeded);
+            }
+        }
+
+        if !self.package_ids.is_empty()
+            && !self.package_ids.iter().any(|id| id == request.package_id)
+        {
+            return Err(ApiResultStatus::DiscountCodeForAnotherPackage);
+        }
+
+        if let DiscountValue::Fixed { currency, .. } = &self.value {
+            if currency != request.currency.code {
+                return Err(ApiResultStatus::DiscountCodeForAnotherPackage);
+            }
+        }
+
+        self.apply(request.price, request.currency)
+            .ok_or(ApiResultStatus::InvalidDiscountCode)
+    }
+
+    /// `None` if the code or the price is misconfigured
+    fn apply(&self, price: Amount, currency: &Currency) -> Option<DiscountedPrice> {
+        let minor_units = currency.minor_units;
+        let price_minor = price.to_minor_units(minor_units)?;
+
+        if price_minor < 0 {
+            return None;
+        }
+
+        let discount_minor = match &self.value {
+            DiscountValue::Percent(percent) => {
+                if !(1..=100).contains(percent) {
+                    return None;
+                }
+
+                (price_minor as i128 * *percent as i128 / 100) as i64
+            }
+            DiscountValue::Fixed { amount, .. } => {
+                let amount_minor = amount.to_minor_units(minor_units)?;
+
+                if amount_minor <= 0 {
+                    return None;
+                }
+
+                amount_minor.min(price_minor)
+            }
+        };
+
+        Some(DiscountedPrice {
+            original_price: price,
+            discount: Amount::from_minor_units(discount_minor, minor_units).ok()?,
+            price: Amount::from_minor_units(price_minor - discount_minor, minor_units).ok()?,
+        })
+    }
+}
+
+/// Evaluates the code entered by the client against the code found by it.
+/// Malformed, unknown and not matching codes are [`ApiResultStatus::InvalidDiscountCode`].
+pub fn evaluate_discount_code(
+    entered_code: &str,
+    discount_code: Option<&DiscountCode>,
+    request: &DiscountRequest,
+) -> Result<DiscountedPrice, ApiResultStatus> {
+    let entered_code =
+        normalize_discount_code(entered_code).map_err(|_| ApiResultStatus::InvalidDiscountCode)?;
+
+    match discount_code {
+        Some(discount_code) if discount_code.code == entered_code => {
+            discount_code.evaluate(request)
+        }
+        _ => Err(ApiResultStatus::InvalidDiscountCode),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 24 * 60 * 60 * 1_000_000;
+    const NOW: i64 = 1_718_409_600_000_000;
+
+    fn time(unix_microseconds: i64) -> DateTimeAsMicroseconds {
+        DateTimeAsMicroseconds { unix_microseconds }
+    }
+
+    fn amount(value: &str) -> Amount {
+        Amount::parse(value).unwrap()
+    }
+
+    fn usd() -> &'static Currency {
+        Currency::parse("USD").unwrap()
+    }
+
+    fn discount_code() -> DiscountCode {
+        DiscountCode {
+            code: "SUMMER-24".to_string(),
+            brand_id: "brand".to_string(),
+            value: DiscountValue::Percent(10),
+            valid_from: Some(time(NOW - DAY)),
+            valid_till: Some(time(NOW + DAY)),
+            max_usages: Some(100),
+            max_usages_per_client: Some(1),
+            package_ids: vec!["starter".to_string(), "pro".to_string()],
+            usages: 0,
+        }
+    }
+
+    fn request() -> DiscountRequest<'static> {
+        DiscountRequest {
+            brand_id: "brand",
+            package_id: "pro",
+            price: amount("99.99"),
+            currency: usd(),
+            client_usages: 0,
+            now: time(NOW),
+        }
+    }
+
+    fn assert_status(result: Result<DiscountedPrice, ApiResultStatus>, expected: ApiResultStatus) {
+        let status = result.unwrap_err();
+        assert_eq!(status as i16, expected as i16, "{:?}", status);
+    }
+
+    #[test]
+    fn applies_percent_discount_rounded_down() {
+        let result = discount_code().evaluate(&request()).unwrap();
+
+        assert_eq!(
+            result,
+            DiscountedPrice {
+                original_price: amount("99.99"),
+                discount: amount("9.99"),
+                price: amount("90"),
+            }
+        );
+    }
+
+    #[test]
+    fn full_percent_discount_makes_price_zero() {
+        let mut code = discount_code();
+        code.value = DiscountValue::Percent(100);
+
+        let result = code.evaluate(&request()).unwrap();
+        assert_eq!(result.discount, amount("99.99"));
+        assert_eq!(result.price, Amount::ZERO);
+    }
+
+    #[test]
+    fn percent_discount_respects_currency_precision() {
+        let mut request = request();
+        request.currency = Currency::parse("JPY").unwrap();
+        request.price = amount("1999");
+
+        let result = discount_code().evaluate(&request).unwrap();
+        assert_eq!(result.discount, amount("199"));
+        assert_eq!(result.price, amount("1800"));
+
+        request.currency = Currency::parse("KWD").unwrap();
+        request.price = amount("10.005");
+
+        let result = discount_code().evaluate(&request).unwrap();
+        assert_eq!(result.discount, amount("1"));
+        assert_eq!(result.price, amount("9.005"));
+    }
+
+    #[test]
+    fn applies_fixed_discount_not_below_zero() {
+        let mut code = discount_code();
+        code.value = DiscountValue::Fixed {
+            amount: amount("25"),
+            currency: "USD".to_string(),
+        };
+
+        let result = code.evaluate(&request()).unwrap();
+        assert_eq!(result.discount, amount("25"));
+        assert_eq!(result.price, amount("74.99"));
+
+        let mut request = request();
+        request.price = amount("19.5");
+
+        let result = code.evaluate(&request).unwrap();
+        assert_eq!(result.discount, amount("19.5"));
+        assert_eq!(result.price, Amount::ZERO);
+    }
+
+    #[test]
+    fn fixed_discount_in_another_currency_is_for_another_package() {
+        let mut code = discount_code();
+        code.value = DiscountValue::Fixed {
+            amount: amount("25"),
+            currency: "EUR".to_string(),
+        };
+
+        assert_status(
+            code.evaluate(&request()),
+            ApiResultStatus::DiscountCodeForAnotherPackage,
+        );
+    }
+
+    #[test]
+    fn code_of_another_brand_is_invalid() {
+        let mut request = request();
+        request.brand_id = "another-brand";
+
+        assert_status(
+            discount_code().evaluate(&request),
+            ApiResultStatus::InvalidDiscountCode,
+        );
+    }
+
+    #[test]
+    fn validity_window() {
+        let code = discount_code();
+        let mut request = request();
+
+        request.now = time(NOW - DAY - 1);
+        assert_status(
+            code.evaluate(&request),
+            ApiResultStatus::InvalidDiscountCode,
+        );
+
+        request.now = time(NOW - DAY);
+        assert!(code.evaluate(&request).is_ok());
+
+        request.now = time(NOW + DAY - 1);
+        assert!(code.evaluate(&request).is_ok());
+
+        request.now = time(NOW + DAY);
+        assert_status(
+            code.evaluate(&request),
+            ApiResultStatus::DiscountCodeExpired,
+        );
+    }
+
+    #[test]
+    fn code_without_validity_window_never_expires() {
+        let mut code = discount_code();
+        code.valid_from = None;
+        code.valid_till = None;
+
+        let mut request = request();
+
+        for now in [0, NOW + 3650 * DAY] {
+            request.now = time(now);
+            assert!(code.evaluate(&request).is_ok());
+        }
+    }
+
+    #[test]
+    fn total_usage_limit() {
+        let mut code = discount_code();
+
+        code.usages = 99;
+        assert!(code.evaluate(&request()).is_ok());
+
+        code.usages = 100;
+        assert_status(
+            code.evaluate(&request()),
+            ApiResultStatus::DiscountCodeUsageExceeded,
+        );
+
+        code.max_usages = None;
+        code.usages = 1_000_000;
+        assert!(code.evaluate(&request()).is_ok());
+    }
+
+    #[test]
+    fn per_client_usage_limit() {
+        let mut code = discount_code();
+        let mut request = request();
+
+        request.client_usages = 1;
+        assert_status(
+            code.evaluate(&request),
+            ApiResultStatus::DiscountCodeUsageExceeded,
+        );
+
+        code.max_usages_per_client = Some(3);
+        assert!(code.evaluate(&request).is_ok());
+
+        code.max_usages_per_client = None;
+        request.client_usages = 1_000;
+        assert!(code.evaluate(&request).is_ok());
+    }
+
+    #[test]
+    fn package_restrictions() {
+        let mut code = discount_code();
+        let mut request = request();
+
+        request.package_id = "starter";
+        assert!(code.evaluate(&request).is_ok());
+
+        request.package_id = "premium";
+        assert_status(
+            code.evaluate(&request),
+            ApiResultStatus::DiscountCodeForAnotherPackage,
+        );
+
+        code.package_ids.clear();
+        assert!(code.evaluate(&request).is_ok());
+    }
+
+    #[test]
+    fn rules_are_checked_in_order() {
+        let mut code = discount_code();
+        code.usages = 100;
+
+        let mut request = request();
+        request.package_id = "premium";
+        request.client_usages = 1;
+
+        assert_status(
+            code.evaluate(&request),
+            ApiResultStatus::DiscountCodeUsageExceeded,
+        );
+
+        request.now = time(NOW + DAY);
+        assert_status(
+            code.evaluate(&request),
+            ApiResultStatus::DiscountCodeExpired,
+        );
+
+        request.brand_id = "another-brand";
+        assert_status(
+            code.evaluate(&request),
+            ApiResultStatus::InvalidDiscountCode,
+        );
+    }
+
+    #[test]
+    fn misconfigured_codes_are_invalid() {
+        for value in [
+            DiscountValue::Percent(0),
+            DiscountValue::Percent(101),
+            DiscountValue::Fixed {
+                amount: amount("0"),
+                currency: "USD".to_string(),
+            },
+            DiscountValue::Fixed {
+                amount: amount("-5"),
+                currency: "USD".to_string(),
+            },
+            DiscountValue::Fixed {
+                amount: amount("0.001"),
+                currency: "USD".to_string(),
+            },
+        ] {
+            let mut code = discount_code();
+            code.value = value.clone();
+
+            assert_status(
+                code.evaluate(&request()),
+                ApiResultStatus::InvalidDiscountCode,
+            );
+        }
+
+        let mut request = request();
+        request.price = amount("10.001");
+        assert_status(
+            discount_code().evaluate(&request),
+            ApiResultStatus::InvalidDiscountCode,
+        );
+    }
+
+    #[test]
+    fn evaluates_entered_code() {
+        let code = discount_code();
+
+        assert!(evaluate_discount_code(" summer-24", Some(&code), &request()).is_ok());
+        assert_status(
+            evaluate_discount_code("WINTER-24", Some(&code), &request()),
+            ApiResultStatus::InvalidDiscountCode,
+        );
+        assert_status(
+            evaluate_discount_code("SUMMER-24", None, &request()),
+            ApiResultStatus::InvalidDiscountCode,
+        );
+        assert_status(
+            evaluate_discount_code("SUMMER 24!", Some(&code), &request()),
+            ApiResultStatus::InvalidDiscountCode,
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use service_sdk::my_http_server::{HttpContext, HttpFailResult};
+
+use crate::{
+    into_http_fail, ValidationFailure, CODE_EMPTY, CODE_INVALID_FORMAT, CODE_MAX_LENGTH,
+    CODE_MIN_LENGTH,
+};
+
+pub const DISCOUNT_CODE_MIN_LEN: usize = 4;
+pub const DISCOUNT_CODE_MAX_LEN: usize = 32;
+
+/// Discount codes are case-insensitive: "summer-24 " is normalized to "SUMMER-24".
+///
+/// Latin letters, digits and single hyphens between them are allowed.
+pub fn normalize_discount_code(value: &str) -> Result<String, ValidationFailure> {
+    let value = value.trim();
+
+    if value.is_empty() {
+        return Err(ValidationFailure::new(
+            CODE_EMPTY,
+            "DiscountCode: Should not be empty",
+        ));
+    }
+
+    if value.len() < DISCOUNT_CODE_MIN_LEN {
+        return Err(ValidationFailure::new(
+            CODE_MIN_LENGTH,
+            format!(
+                "DiscountCode: Min length is {} symbols",
+                DISCOUNT_CODE_MIN_LEN
+            ),
+        ));
+    }
+
+    if value.len() > DISCOUNT_CODE_MAX_LEN {
+        return Err(ValidationFailure::new(
+            CODE_MAX_LENGTH,
+            format!(
+                "DiscountCode: Max length is {} symbols",
+                DISCOUNT_CODE_MAX_LEN
+            ),
+        ));
+    }
+
+    let is_valid = value
+        .split('-')
+        .all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_alphanumeric()));
+
+    if !is_valid {
+        return Err(ValidationFailure::new(
+            CODE_INVALID_FORMAT,
+            "DiscountCode: Only latin letters, digits and single hyphens between them are allowed",
+        ));
+    }
+
+    Ok(value.to_ascii_uppercase())
+}
+
+pub fn validate_discount_code(ctx: &HttpContext, value: &str) -> Result<String, HttpFailResult> {
+    into_http_fail(ctx, normalize_discount_code(value))
+}
+
+pub fn validate_discount_code_optional(
+    ctx: &HttpContext,
+    value: &Option<String>,
+) -> Result<Option<String>, HttpFailResult> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+
+    validate_discount_code(ctx, value).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_discount_code() {
+        assert_eq!(normalize_discount_code(" summer-24 ").unwrap(), "SUMMER-24");
+        assert_eq!(normalize_discount_code("WELCOME").unwrap(), "WELCOME");
+        assert_eq!(normalize_discount_code("a1b2").unwrap(), "A1B2");
+    }
+
+    #[test]
+    fn rejects_invalid_discount_codes() {
+        for (value, code) in [
+            ("", CODE_EMPTY),
+            ("   ", CODE_EMPTY),
+            ("ABC", CODE_MIN_LENGTH),
+            (&"A".repeat(33) as &str, CODE_MAX_LENGTH),
+            ("-SUMMER", CODE_INVALID_FORMAT),
+            ("SUMMER-", CODE_INVALID_FORMAT),
+            ("SUM--MER", CODE_INVALID_FORMAT),
+            ("SUM MER", CODE_INVALID_FORMAT),
+            ("SUMMER_24", CODE_INVALID_FORMAT),
+            ("ЛЕТО2024", CODE_INVALID_FORMAT),
+        ] {
+            assert_eq!(
+                normalize_discount_code(value).unwrap_err().code,
+                code,
+                "{:?}",
+                value
+            );
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod discount_code_format;
+pub use discount_code_format::*;
+
+mod discount_code;
+pub use discount_code::*;
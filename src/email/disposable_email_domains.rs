@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+
+use lazy_static::lazy_static;
+
+const BUNDLED_DISPOSABLE_DOMAINS: &str = include_str!("disposable_email_domains.txt");
+
+lazy_static! {
+    static ref DISPOSABLE_EMAIL_DOMAINS: DisposableEmailDomains = DisposableEmailDomains::bundled();
+}
+
+/// Disposable/temporary email domains. The bundled list can be extended
+/// with domains from settings without a new release.
+#[derive(Debug, Clone, Default)]
+pub struct DisposableEmailDomains {
+    domains: HashSet<String>,
+}
+
+impl DisposableEmailDomains {
+    pub fn bundled() -> Self {
+        let mut result = Self::default();
+        result.add_list(BUNDLED_DISPOSABLE_DOMAINS);
+        result
+    }
+
+    /// Adds domains in the bundled list format: one per line, `#` starts a comment.
+    pub fn add_list(&mut self, list: &str) {
+        for line in list.lines() {
+            let domain = line.split('#').next().unwrap_or_default().trim();
+
+            if !domain.is_empty() {
+                self.add(domain);
+            }
+        }
+    }
+
+    pub fn add(&mut self, domain: &str) {
+        self.domains.insert(domain.to_lowercase());
+    }
+
+    /// `domain` is expected in ASCII form, as in [`crate::EmailAddress::domain`].
+    /// Subdomains of a listed domain are disposable as well.
+    pub fn is_disposable(&self, domain: &str) -> bool {
+        let domain = domain.to_lowercase();
+        let mut candidate = domain.as_str();
+
+        loop {
+            if self.domains.contains(candidate) {
+                return true;
+            }
+
+            match candidate.split_once('.') {
+                Some((_, parent)) if parent.contains('.') => candidate = parent,
+                _ => return false,
+            }
+        }
+    }
+}
+
+/// The bundled list, loaded once.
+pub fn get_bundled_disposable_email_domains() -> &'static DisposableEmailDomains {
+    &DISPOSABLE_EMAIL_DOMAINS
+}
+
+pub fn is_disposable_email_domain(domain: &str) -> bool {
+    DISPOSABLE_EMAIL_DOMAINS.is_disposable(domain)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_domains_are_disposable() {
+        assert!(is_disposable_email_domain("mailinator.com"));
+        assert!(is_disposable_email_domain("YOPMAIL.com"));
+        assert!(is_disposable_email_domain("eu.mailinator.com"));
+        assert!(!is_disposable_email_domain("gmail.com"));
+        assert!(!is_disposable_email_domain("com"));
+    }
+
+    #[test]
+    fn list_can_be_extended() {
+        let mut domains = DisposableEmailDomains::bundled();
+        assert!(!domains.is_disposable("fresh-temp.io"));
+
+        domains.add_list("# from settings\nfresh-temp.io\n\n");
+        assert!(domains.is_disposable("fresh-temp.io"));
+    }
+}
@@ -0,0 +1,305 @@
+use std::borrow::Cow;
+
+use crate::{ValidationFailure, CODE_INVALID_FORMAT, CODE_MAX_LENGTH};
+
+// RFC 5321 4.5.3.1
+const MAX_EMAIL_LEN: usize = 254;
+const MAX_LOCAL_PART_LEN: usize = 64;
+const MAX_DOMAIN_LEN: usize = 253;
+const MAX_DOMAIN_LABEL_LEN: usize = 63;
+
+/// Email split into parts. Domain is kept in ASCII (punycode) form and lowercased.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailAddress {
+    pub local_part: String,
+    pub domain: String,
+}
+
+impl EmailAddress {
+    /// Practical subset of RFC 5321/5322: dot-atom local part (quoted strings and
+    /// comments are rejected) and a DNS domain which may be an IDN.
+    pub fn parse(value: &str) -> Result<Self, ValidationFailure> {
+        let (local_part, domain) = split_email(value)?;
+
+        let mut domain = domain.into_owned();
+        domain.make_ascii_lowercase();
+
+        Ok(Self {
+            local_part: local_part.to_string(),
+            domain,
+        })
+    }
+
+    /// Same rules as [`EmailAddress::parse`] without building the result.
+    /// Plain ASCII emails are checked without allocations.
+    pub fn is_valid(value: &str) -> bool {
+        split_email(value).is_ok()
+    }
+
+    pub fn get_unicode_domain(&self) -> String {
+        idna::domain_to_unicode(&self.domain).0
+    }
+
+    /// Form used for uniqueness checks. Two emails with the same canonical form
+    /// end up in the same mailbox.
+    pub fn get_canonical(&self) -> String {
+        let mut local_part = self.local_part.to_lowercase();
+        let mut domain = self.domain.as_str();
+
+        if let Some(rules) = get_provider_rules(domain) {
+            if let Some(index) = local_part.find(rules.tag_separator) {
+                local_part.truncate(index);
+            }
+
+            if rules.ignore_dots {
+                local_part.retain(|c| c != '.');
+            }
+
+            domain = rules.canonical_domain.unwrap_or(domain);
+        }
+
+        format!("{}@{}", local_part, domain)
+    }
+}
+
+impl std::fmt::Display for EmailAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}@{}", self.local_part, self.domain)
+    }
+}
+
+struct ProviderRules {
+    domains: &'static [&'static str],
+    tag_separator: char,
+    ignore_dots: bool,
+    canonical_domain: Option<&'static str>,
+}
+
+const PROVIDER_RULES: &[ProviderRules] = &[
+    ProviderRules {
+        domains: &["gmail.com", "googlemail.com"],
+        tag_separator: '+',
+        ignore_dots: true,
+        canonical_domain: Some("gmail.com"),
+    },
+    ProviderRules {
+        domains: &["outlook.com", "hotmail.com", "live.com", "msn.com"],
+        tag_separator: '+',
+        ignore_dots: false,
+        canonical_domain: None,
+    },
+    ProviderRules {
+        domains: &["icloud.com", "me.com", "mac.com"],
+        tag_separator: '+',
+        ignore_dots: false,
+        canonical_domain: Some("icloud.com"),
+    },
+    ProviderRules {
+        domains: &["proton.me", "protonmail.com", "protonmail.ch", "pm.me"],
+        tag_separator: '+',
+        ignore_dots: false,
+        canonical_domain: Some("proton.me"),
+    },
+    ProviderRules {
+        domains: &["fastmail.com", "fastmail.fm"],
+        tag_separator: '+',
+        ignore_dots: false,
+        canonical_domain: None,
+    },
+];
+
+fn get_provider_rules(domain: &str) -> Option<&'static ProviderRules> {
+    PROVIDER_RULES
+        .iter()
+        .find(|rules| rules.domains.contains(&domain))
+}
+
+fn split_email(value: &str) -> Result<(&str, Cow<'_, str>), ValidationFailure> {
+    if value.len() > MAX_EMAIL_LEN {
+        return Err(ValidationFailure::new(
+            CODE_MAX_LENGTH,
+            format!("Email: Max length is {} symbols", MAX_EMAIL_LEN),
+        ));
+    }
+
+    let Some((local_part, domain)) = value.rsplit_once('@') else {
+        return Err(invalid_format("Email: Should contain @"));
+    };
+
+    check_local_part(local_part)?;
+    let domain = get_ascii_domain(domain)?;
+
+    if local_part.len() + 1 + domain.len() > MAX_EMAIL_LEN {
+        return Err(ValidationFailure::new(
+            CODE_MAX_LENGTH,
+            format!("Email: Max length is {} symbols", MAX_EMAIL_LEN),
+        ));
+    }
+
+    Ok((local_part, domain))
+}
+
+fn invalid_format(message: &str) -> ValidationFailure {
+    ValidationFailure::new(CODE_INVALID_FORMAT, message)
+}
+
+fn is_atext(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b"!#$%&'*+/=?^_`{|}~-".contains(&b)
+}
+
+fn check_local_part(local_part: &str) -> Result<(), ValidationFailure> {
+    if local_part.is_empty() {
+        return Err(invalid_format("Email: Local part should not be empty"));
+    }
+
+    if local_part.len() > MAX_LOCAL_PART_LEN {
+        return Err(ValidationFailure::new(
+            CODE_MAX_LENGTH,
+            format!(
+                "Email: Local part max length is {} symbols",
+                MAX_LOCAL_PART_LEN
+            ),
+        ));
+    }
+
+    if local_part.starts_with('.') || local_part.ends_with('.') || local_part.contains("..") {
+        return Err(invalid_format(
+            "Email: Dots are not allowed at the start, end or in a row",
+        ));
+    }
+
+    if !local_part.bytes().all(|b| b == b'.' || is_atext(b)) {
+        return Err(invalid_format(
+            "Email: Local part contains not allowed symbols",
+        ));
+    }
+
+    Ok(())
+}
+
+fn is_plain_ascii_domain(domain: &str) -> bool {
+    domain.is_ascii()
+        && !domain
+            .as_bytes()
+            .windows(4)
+            .any(|window| window.eq_ignore_ascii_case(b"xn--"))
+}
+
+/// Returns the domain in ASCII form. Case is kept for plain ASCII domains.
+fn get_ascii_domain(domain: &str) -> Result<Cow<'_, str>, ValidationFailure> {
+    if domain.is_empty() {
+        return Err(invalid_format("Email: Domain should not be empty"));
+    }
+
+    // Plain ASCII domains need no IDNA mapping, LDH check below is enough for them.
+    // domain_to_ascii is lenient to a lot of input which is not a hostname, so LDH is checked afterwards
+    let domain = if is_plain_ascii_domain(domain) {
+        Cow::Borrowed(domain)
+    } else {
+        let domain = idna::domain_to_ascii(domain)
+            .map_err(|_| invalid_format("Email: Domain is not valid"))?;
+        Cow::Owned(domain)
+    };
+
+    if domain.len() > MAX_DOMAIN_LEN {
+        return Err(invalid_format("Email: Domain is too long"));
+    }
+
+    if !domain.contains('.') {
+        return Err(invalid_format("Email: Domain should contain a dot"));
+    }
+
+    for label in domain.split('.') {
+        let is_valid = !label.is_empty()
+            && label.len() <= MAX_DOMAIN_LABEL_LEN
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-');
+
+        if !is_valid {
+            return Err(invalid_format("Email: Domain is not valid"));
+        }
+    }
+
+    let tld = domain.rsplit('.').next().unwrap_or_default();
+    if tld.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid_format("Email: Domain is not valid"));
+    }
+
+    Ok(domain)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_regular_emails() {
+        let email = EmailAddress::parse("John.Doe+promo@Gmail.com").unwrap();
+
+        assert_eq!(email.local_part, "John.Doe+promo");
+        assert_eq!(email.domain, "gmail.com");
+
+        assert!(EmailAddress::parse("o'brien@test.co.uk").is_ok());
+        assert!(EmailAddress::parse("user_name-1@sub.domain.io").is_ok());
+    }
+
+    #[test]
+    fn rejects_invalid_emails() {
+        for value in [
+            "",
+            "test.tt",
+            "@test.tt",
+            "test@",
+            "test@tt",
+            " test@test.tt",
+            "test@test.tt ",
+            ".test@test.tt",
+            "te..st@test.tt",
+            "test.@test.tt",
+            "\"quoted\"@test.tt",
+            "te st@test.tt",
+            "test@-test.tt",
+            "test@test-.tt",
+            "test@test..tt",
+            "test@127.0.0.1",
+            "test@[127.0.0.1]",
+            "test@te_st.tt",
+        ] {
+            assert!(EmailAddress::parse(value).is_err(), "{}", value);
+        }
+
+        assert!(EmailAddress::parse(&format!("{}@test.tt", "a".repeat(65))).is_err());
+        assert!(!EmailAddress::is_valid("test@xn--.tt"));
+    }
+
+    #[test]
+    fn converts_idn_domain_to_punycode() {
+        let email = EmailAddress::parse("info@Bücher.de").unwrap();
+
+        assert_eq!(email.domain, "xn--bcher-kva.de");
+        assert_eq!(email.get_unicode_domain(), "bücher.de");
+        assert_eq!(email.to_string(), "info@xn--bcher-kva.de");
+    }
+
+    #[test]
+    fn canonical_form_follows_provider_rules() {
+        let canonical = |value: &str| EmailAddress::parse(value).unwrap().get_canonical();
+
+        assert_eq!(canonical("John.Doe+promo@Gmail.com"), "johndoe@gmail.com");
+        assert_eq!(canonical("johndoe@googlemail.com"), "johndoe@gmail.com");
+        assert_eq!(canonical("John.Doe+x@outlook.com"), "john.doe@outlook.com");
+        // Yahoo disposable addresses are separate accounts, not tags
+        assert_eq!(
+            canonical("john-shopping@yahoo.com"),
+            "john-shopping@yahoo.com"
+        );
+        assert_eq!(canonical("John@me.com"), "john@icloud.com");
+        assert_eq!(
+            canonical("John.Doe+promo@company.com"),
+            "john.doe+promo@company.com"
+        );
+    }
+}
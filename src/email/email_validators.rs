@@ -0,0 +1,107 @@
+use service_sdk::my_http_server::{HttpContext, HttpFailResult};
+
+use crate::{into_http_fail, EmailAddress, ValidationFailure};
+
+use super::{get_bundled_disposable_email_domains, DisposableEmailDomains};
+
+pub const CODE_DISPOSABLE_EMAIL: &str = "disposable_email";
+
+pub fn check_email_not_disposable(value: &str) -> Result<(), ValidationFailure> {
+    check_email_not_disposable_with_list(value, get_bundled_disposable_email_domains())
+}
+
+/// Same as [`check_email_not_disposable`] with a list extended from settings.
+pub fn check_email_not_disposable_with_list(
+    value: &str,
+    domains: &DisposableEmailDomains,
+) -> Result<(), ValidationFailure> {
+    let email = EmailAddress::parse(value)?;
+
+    if domains.is_disposable(&email.domain) {
+        return Err(ValidationFailure::new(
+            CODE_DISPOSABLE_EMAIL,
+            "Email: Disposable email addresses are not allowed",
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_email_not_disposable(ctx: &HttpContext, value: &str) -> Result<(), HttpFailResult> {
+    validate_email_not_disposable_with_list(ctx, value, get_bundled_disposable_email_domains())
+}
+
+pub fn validate_email_not_disposable_optional(
+    ctx: &HttpContext,
+    value: &Option<String>,
+) -> Result<(), HttpFailResult> {
+    validate_email_not_disposable_with_list_optional(
+        ctx,
+        value,
+        get_bundled_disposable_email_domains(),
+    )
+}
+
+pub fn validate_email_not_disposable_with_list(
+    ctx: &HttpContext,
+    value: &str,
+    domains: &DisposableEmailDomains,
+) -> Result<(), HttpFailResult> {
+    into_http_fail(ctx, check_email_not_disposable_with_list(value, domains))
+}
+
+pub fn validate_email_not_disposable_with_list_optional(
+    ctx: &HttpContext,
+    value: &Option<String>,
+    domains: &DisposableEmailDomains,
+) -> Result<(), HttpFailResult> {
+    let Some(value) = value else {
+        return Ok(());
+    };
+
+    validate_email_not_disposable_with_list(ctx, value, domains)
+}
+
+/// Canonical form for uniqueness checks, see [`EmailAddress::get_canonical`].
+pub fn get_canonical_email(value: &str) -> Result<String, ValidationFailure> {
+    Ok(EmailAddress::parse(value)?.get_canonical())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disposable_email_is_rejected() {
+        assert_eq!(
+            check_email_not_disposable("trader@mailinator.com")
+                .unwrap_err()
+                .code,
+            CODE_DISPOSABLE_EMAIL
+        );
+        assert!(check_email_not_disposable("trader@gmail.com").is_ok());
+    }
+
+    #[test]
+    fn extended_list_is_used() {
+        let mut domains = DisposableEmailDomains::bundled();
+        domains.add("fresh-temp.io");
+
+        assert!(check_email_not_disposable("trader@fresh-temp.io").is_ok());
+        assert_eq!(
+            check_email_not_disposable_with_list("trader@fresh-temp.io", &domains)
+                .unwrap_err()
+                .code,
+            CODE_DISPOSABLE_EMAIL
+        );
+        assert!(check_email_not_disposable_with_list("trader@mailinator.com", &domains).is_err());
+    }
+
+    #[test]
+    fn same_mailbox_has_same_canonical_email() {
+        assert_eq!(
+            get_canonical_email("John.Doe+promo@Gmail.com").unwrap(),
+            get_canonical_email("johndoe@gmail.com").unwrap()
+        );
+    }
+}
@@ -0,0 +1,7 @@
+mod disposable_email_domains;
+mod email_address;
+mod email_validators;
+
+pub use disposable_email_domains::*;
+pub use email_address::*;
+pub use email_validators::*;
@@ -0,0 +1,707 @@
+use std::io::Read;
+
+use super::image_dimensions::{read_u16_be, read_u32_be};
+use super::FileType;
+
+// Markup browsers would execute if the file is served or opened as HTML
+const SCRIPT_MARKERS: &[&[u8]] = &[
+    b"<script",
+    b"<?php",
+    b"<html",
+    b"<!doctype",
+    b"<svg",
+    b"<iframe",
+    b"<body",
+];
+
+// PDF actions which run code or open other files, compared with decoded names
+const PDF_ACTION_NAMES: &[&[u8]] = &[
+    b"javascript",
+    b"js",
+    b"launch",
+    b"embeddedfile",
+    b"embeddedfiles",
+    b"richmedia",
+    b"xfa",
+];
+
+// Only keys and values an object stream dictionary is expected to have. Other filters,
+// decode parameters and encryption make the stream not inspectable.
+const PDF_OBJECT_STREAM_NAMES: &[&[u8]] = &[
+    b"Type",
+    b"ObjStm",
+    b"N",
+    b"First",
+    b"Extends",
+    b"Length",
+    b"Filter",
+    b"FlateDecode",
+    b"Fl",
+];
+
+// Decoded size of all object streams of one file
+const MAX_PDF_OBJECT_STREAMS_SIZE: u64 = 16 * 1024 * 1024;
+
+const PDF_WHITESPACE: &[u8] = b"\0\t\n\x0c\r ";
+const PDF_DELIMITERS: &[u8] = b"()<>[]{}/%";
+
+// Compressed pixels of PNG and bitstreams of WebP
+const PNG_IMAGE_CHUNKS: &[&[u8]] = &[b"IDAT", b"fdAT"];
+const WEBP_IMAGE_CHUNKS: &[&[u8]] = &[b"VP8 ", b"VP8L", b"ALPH", b"ANMF"];
+
+/// Polyglot files: valid documents or images which are also HTML, scripts or archives.
+///
+/// Only the parts readers parse as text are searched: PDF objects, including inflated object
+/// streams, and image metadata. Stream data and pixels are skipped, short markers appear in
+/// random bytes by chance. Files which can not be walked to their end are rejected, so are
+/// data appended after the end of an image.
+///
+/// JPEG trailers are rejected unless `allow_camera_trailers` is set, then Samsung SEF blocks
+/// and Motion Photo videos are accepted without inspection.
+pub(super) fn has_embedded_content(
+    file_type: FileType,
+    content: &[u8],
+    allow_camera_trailers: bool,
+) -> bool {
+    match file_type {
+        FileType::Pdf => has_pdf_embedded_content(content),
+        FileType::Jpeg => has_jpeg_embedded_content(content, allow_camera_trailers),
+        FileType::Png => has_png_embedded_content(content),
+        FileType::Webp => has_webp_embedded_content(content),
+        FileType::Heic => has_heic_embedded_content(content),
+    }
+}
+
+fn has_script_marker_at(content: &[u8], offset: usize) -> bool {
+    SCRIPT_MARKERS.iter().any(|marker| {
+        content[offset..]
+            .get(..marker.len())
+            .is_some_and(|candidate| candidate.eq_ignore_ascii_case(marker))
+    })
+}
+
+fn has_script_markers(content: &[u8]) -> bool {
+    content
+        .iter()
+        .enumerate()
+        .any(|(offset, b)| *b == b'<' && has_script_marker_at(content, offset))
+}
+
+fn find(content: &[u8], needle: &[u8]) -> Option<usize> {
+    content
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn has_pdf_embedded_content(content: &[u8]) -> bool {
+    let mut object_streams_size = 0;
+
+    has_pdf_embedded_tokens(content, false, &mut object_streams_size)
+        || has_pdf_trailing_data(content)
+}
+
+// Walks the tokens outside of streams. Strings and comments are skipped, names are decoded,
+// object streams are inflated and walked the same way.
+fn has_pdf_embedded_tokens(
+    content: &[u8],
+    is_object_stream: bool,
+    object_streams_size: &mut u64,
+) -> bool {
+    // Names since the last `obj` keyword, the dictionary of a stream is among them
+    let mut object_names: Vec<Vec<u8>> = Vec::new();
+    let mut offset = 0;
+
+    while offset < content.len() {
+        match content[offset] {
+            b'%' => {
+                offset += content[offset..]
+                    .iter()
+                    .position(|b| *b == b'\r' || *b == b'\n')
+                    .unwrap_or(content.len() - offset);
+            }
+            b'(' => {
+                let Some(end) = skip_pdf_string(content, offset) else {
+                    return true;
+                };
+                offset = end;
+            }
+            b'<' if content.get(offset + 1) == Some(&b'<') => offset += 2,
+            b'<' => {
+                if has_script_marker_at(content, offset) {
+                    return true;
+                }
+
+                let Some(len) = content[offset..].iter().position(|b| *b == b'>') else {
+                    return true;
+                };
+                offset += len + 1;
+            }
+            b'/' => {
+                let end = offset + 1 + get_pdf_token_len(&content[offset + 1..]);
+                let name = decode_pdf_name(&content[offset + 1..end]);
+
+                if PDF_ACTION_NAMES
+                    .iter()
+                    .any(|action| name.eq_ignore_ascii_case(action))
+                {
+                    return true;
+                }
+
+                object_names.push(name);
+                offset = end;
+            }
+            b if !PDF_WHITESPACE.contains(&b) && !PDF_DELIMITERS.contains(&b) => {
+                let end = offset + get_pdf_token_len(&content[offset..]);
+
+                match &content[offset..end] {
+                    b"obj" => object_names.clear(),
+                    b"stream" => {
+                        // Streams are not allowed inside object streams
+                        if is_object_stream {
+                            return true;
+                        }
+
+                        let data_start = if content[end..].starts_with(b"\r\n") {
+                            end + 2
+                        } else if content[end..].starts_with(b"\n") {
+                            end + 1
+                        } else {
+                            end
+                        };
+
+                        let Some(data_len) = find(&content[data_start..], b"endstream") else {
+                            return true;
+                        };
+
+                        let data = &content[data_start..data_start + data_len];
+
+                        if object_names.iter().any(|name| name == b"ObjStm")
+                            && has_object_stream_embedded_content(
+                                &object_names,
+                                data,
+                                object_streams_size,
+                            )
+                        {
+                            return true;
+                        }
+
+                        offset = data_start + data_len + b"endstream".len();
+                        continue;
+                    }
+                    _ => {}
+                }
+
+                offset = end;
+            }
+            _ => offset += 1,
+        }
+    }
+
+    false
+}
+
+fn has_object_stream_embedded_content(
+    object_names: &[Vec<u8>],
+    data: &[u8],
+    object_streams_size: &mut u64,
+) -> bool {
+    if object_names
+        .iter()
+        .any(|name| !PDF_OBJECT_STREAM_NAMES.contains(&name.as_slice()))
+    {
+        return true;
+    }
+
+    let is_compressed = object_names.iter().any(|name| name == b"Filter");
+    let limit = MAX_PDF_OBJECT_STREAMS_SIZE - *object_streams_size;
+
+    let mut decoded = Vec::new();
+
+    let read = if is_compressed {
+        flate2::read::ZlibDecoder::new(data)
+            .take(limit + 1)
+            .read_to_end(&mut decoded)
+    } else {
+        data.take(limit + 1).read_to_end(&mut decoded)
+    };
+
+    if read.is_err() || decoded.len() as u64 > limit {
+        return true;
+    }
+
+    *object_streams_size += decoded.len() as u64;
+
+    has_pdf_embedded_tokens(&decoded, true, object_streams_size)
+}
+
+fn get_pdf_token_len(content: &[u8]) -> usize {
+    content
+        .iter()
+        .position(|b| PDF_WHITESPACE.contains(b) || PDF_DELIMITERS.contains(b))
+        .unwrap_or(content.len())
+}
+
+// Offset after the closing parenthesis. Parentheses may be nested or escaped.
+fn skip_pdf_string(content: &[u8], start: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut offset = start;
+
+    while offset < content.len() {
+        match content[offset] {
+            b'\\' => offset += 1,
+            b'(' => depth += 1,
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(offset + 1);
+                }
+            }
+            _ => {}
+        }
+
+        offset += 1;
+    }
+
+    None
+}
+
+// "/J#61vaScript" is "/JavaScript" for PDF readers
+fn decode_pdf_name(src: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(src.len());
+    let mut offset = 0;
+
+    while offset < src.len() {
+        let hex = src.get(offset + 1..offset + 3);
+
+        match hex {
+            Some(hex) if src[offset] == b'#' && hex.iter().all(u8::is_ascii_hexdigit) => {
+                let hex = std::str::from_utf8(hex).unwrap();
+                result.push(u8::from_str_radix(hex, 16).unwrap());
+                offset += 3;
+            }
+            _ => {
+                result.push(src[offset]);
+                offset += 1;
+            }
+        }
+    }
+
+    result
+}
+
+// Incremental updates append to PDFs, so only the end of the last one is checked
+fn has_pdf_trailing_data(content: &[u8]) -> bool {
+    let tail = &content[content.len().saturating_sub(1024)..];
+    let Some(eof) = tail.windows(5).rposition(|window| window == b"%%EOF") else {
+        return true;
+    };
+
+    tail[eof + 5..].iter().any(|b| !b.is_ascii_whitespace())
+}
+
+// Walks the segments till the end of image marker. Segments are searched,
+// entropy-coded data after the start of scan is skipped.
+fn has_jpeg_embedded_content(content: &[u8], allow_camera_trailers: bool) -> bool {
+    let mut offset = 2;
+
+    let image_end = loop {
+        if content.get(offset) != Some(&0xFF) {
+            return true;
+        }
+
+        let Some(&marker) = content.get(offset + 1) else {
+            return true;
+        };
+
+        match marker {
+            // Fill bytes
+            0xFF => offset += 1,
+            // Segments without length
+            0x01 | 0xD0..=0xD7 => offset += 2,
+            0xD9 => break offset + 2,
+            _ => {
+                let Some(len) = read_u16_be(content, offset + 2) else {
+                    return true;
+                };
+
+                let Some(segment) = content.get(offset + 4..offset + 2 + len as usize) else {
+                    return true;
+                };
+
+                if has_script_markers(segment) {
+                    return true;
+                }
+
+                offset += 2 + len as usize;
+
+                if marker == 0xDA {
+                    offset = skip_entropy_coded_data(content, offset);
+                }
+            }
+        }
+    };
+
+    // Some cameras pad files with zeros after the end of image marker
+    let trailer = &content[image_end..];
+    let trailer = &trailer[..trailer.len() - trailer.iter().rev().take_while(|b| **b == 0).count()];
+
+    if trailer.is_empty() {
+        return false;
+    }
+
+    !(allow_camera_trailers && is_camera_trailer(trailer))
+}
+
+// Scan data ends at the first marker, 0xFF bytes of the data are followed by 0x00
+// and restart markers are a part of it
+fn skip_entropy_coded_data(content: &[u8], start: usize) -> usize {
+    let mut offset = start;
+
+    while offset + 1 < content.len() {
+        if content[offset] == 0xFF && !matches!(content[offset + 1], 0x00 | 0xD0..=0xD7) {
+            return offset;
+        }
+
+        offset += 1;
+    }
+
+    content.len()
+}
+
+// Samsung SEF blocks end with "SEFT", Motion Photo videos are MP4 files starting with "ftyp"
+fn is_camera_trailer(trailer: &[u8]) -> bool {
+    trailer.ends_with(b"SEFT") || trailer.get(4..8) == Some(b"ftyp")
+}
+
+// Chunks: length, type, data and CRC till the IEND one, which should end the file
+fn has_png_embedded_content(content: &[u8]) -> bool {
+    let mut offset = 8;
+
+    loop {
+        let Some(len) = read_u32_be(content, offset) else {
+            return true;
+        };
+
+        let data_start = offset + 8;
+        let data_end = data_start + len as usize;
+
+        let (Some(chunk_type), Some(data)) = (
+            content.get(offset + 4..data_start),
+            content.get(data_start..data_end),
+        ) else {
+            return true;
+        };
+
+        if chunk_type == b"IEND" {
+            return content.len() != data_end + 4;
+        }
+
+        if !PNG_IMAGE_CHUNKS.contains(&chunk_type) && has_script_markers(data) {
+            return true;
+        }
+
+        offset = data_end + 4;
+    }
+}
+
+// RIFF chunks: fourcc, little endian size and data padded to even size
+fn has_webp_embedded_content(content: &[u8]) -> bool {
+    let riff_size = u32::from_le_bytes([content[4], content[5], content[6], content[7]]);
+    let riff_end = riff_size as usize + 8;
+
+    if content.len() < riff_end || content.len() > riff_end + 1 {
+        return true;
+    }
+
+    let riff = &content[..riff_end];
+    let mut offset = 12;
+
+    while offset + 8 <= riff.len() {
+        let fourcc = &riff[offset..offset + 4];
+        let size = u32::from_le_bytes([
+            riff[offset + 4],
+            riff[offset + 5],
+            riff[offset + 6],
+            riff[offset + 7],
+        ]) as usize;
+
+        let Some(data) = riff.get(offset + 8..offset + 8 + size) else {
+            return true;
+        };
+
+        if !WEBP_IMAGE_CHUNKS.contains(&fourcc) && has_script_markers(data) {
+            return true;
+        }
+
+        offset += 8 + size + size % 2;
+    }
+
+    false
+}
+
+// Boxes: size, type and data, size 1 is followed by a 64-bit one, size 0 is till the end.
+// Media data ("mdat") is skipped, other boxes should cover the file to its end.
+fn has_heic_embedded_content(content: &[u8]) -> bool {
+    let mut offset = 0;
+
+    while offset < content.len() {
+        let (Some(size), Some(box_type)) = (
+            read_u32_be(content, offset),
+            content.get(offset + 4..offset + 8),
+        ) else {
+            return true;
+        };
+
+        let (header_len, size) = match size {
+            0 => (8, content.len() - offset),
+            1 => {
+                let Some(large_size) = content.get(offset + 8..offset + 16).and_then(|bytes| {
+                    usize::try_from(u64::from_be_bytes(bytes.try_into().ok()?)).ok()
+                }) else {
+                    return true;
+                };
+                (16, large_size)
+            }
+            size => (8, size as usize),
+        };
+
+        let Some(data) = offset
+            .checked_add(size)
+            .filter(|_| size >= header_len)
+            .and_then(|end| content.get(offset + header_len..end))
+        else {
+            return true;
+        };
+
+        if box_type != b"mdat" && has_script_markers(data) {
+            return true;
+        }
+
+        offset += size;
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::super::image_dimensions::tests::{get_heic, get_jpeg, get_png, get_webp};
+    use super::*;
+
+    const PAYLOAD_SIZE: usize = 4 * 1024 * 1024;
+
+    // Markers placed on purpose into data which should not be searched
+    const MARKERS: &[u8] = b"<script>/JS /JavaScript<svg";
+
+    // xorshift, the same bytes on every run
+    fn get_random_bytes(len: usize, seed: u64) -> Vec<u8> {
+        let mut state = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+        let mut result = Vec::with_capacity(len + MARKERS.len());
+
+        while result.len() < len {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            result.extend_from_slice(&state.to_le_bytes());
+        }
+
+        result.truncate(len);
+        result.extend_from_slice(MARKERS);
+        result
+    }
+
+    fn get_pdf(objects: &[&[u8]]) -> Vec<u8> {
+        let mut result = b"%PDF-1.7\n%\xe2\xe3\xcf\xd3\n".to_vec();
+
+        for (index, object) in objects.iter().enumerate() {
+            result.extend_from_slice(format!("{} 0 obj\n", index + 1).as_bytes());
+            result.extend_from_slice(object);
+            result.extend_from_slice(b"\nendobj\n");
+        }
+
+        result.extend_from_slice(b"trailer\n<< /Root 1 0 R >>\n%%EOF\n");
+        result
+    }
+
+    fn get_stream(dictionary: &str, data: &[u8]) -> Vec<u8> {
+        let mut result =
+            format!("<< {} /Length {} >>\nstream\n", dictionary, data.len()).into_bytes();
+        result.extend_from_slice(data);
+        result.extend_from_slice(b"\nendstream");
+        result
+    }
+
+    fn get_object_stream(objects: &[u8]) -> Vec<u8> {
+        let mut encoder =
+            flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(objects).unwrap();
+
+        get_stream(
+            "/Type /ObjStm /N 1 /First 4 /Filter /FlateDecode",
+            &encoder.finish().unwrap(),
+        )
+    }
+
+    #[test]
+    fn random_data_is_not_searched() {
+        for seed in 1..=2 {
+            let payload = get_random_bytes(PAYLOAD_SIZE, seed);
+
+            let image = get_stream(
+                "/Type /XObject /Subtype /Image /Filter /DCTDecode",
+                &payload,
+            );
+            let pdf = get_pdf(&[b"<< /Type /Catalog >>", &image]);
+            assert!(!has_embedded_content(FileType::Pdf, &pdf, false));
+
+            // 0xFF in scan data is followed by 0x00
+            let mut jpeg = get_jpeg(1200, 1600);
+            let mut scan = vec![
+                0xFF, 0xDA, 0x00, 0x0C, 3, 1, 0, 2, 0x11, 3, 0x11, 0, 0x3F, 0,
+            ];
+            for b in &payload {
+                scan.push(*b);
+                if *b == 0xFF {
+                    scan.push(0x00);
+                }
+            }
+            jpeg.splice(jpeg.len() - 2..jpeg.len() - 2, scan);
+            assert!(!has_embedded_content(FileType::Jpeg, &jpeg, false));
+
+            let mut png = get_png(800, 600);
+            let mut idat = (payload.len() as u32).to_be_bytes().to_vec();
+            idat.extend_from_slice(b"IDAT");
+            idat.extend_from_slice(&payload);
+            idat.extend_from_slice(&[0; 4]);
+            png.splice(png.len() - 12..png.len() - 12, idat);
+            assert!(!has_embedded_content(FileType::Png, &png, false));
+
+            let mut webp = get_webp(800, 600);
+            webp.extend_from_slice(b"VP8 ");
+            webp.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+            webp.extend_from_slice(&payload);
+            if payload.len() % 2 == 1 {
+                webp.push(0);
+            }
+            let riff_size = (webp.len() - 8) as u32;
+            webp[4..8].copy_from_slice(&riff_size.to_le_bytes());
+            assert!(!has_embedded_content(FileType::Webp, &webp, false));
+
+            let mut heic = get_heic(4032, 3024);
+            heic.extend_from_slice(&(payload.len() as u32 + 8).to_be_bytes());
+            heic.extend_from_slice(b"mdat");
+            heic.extend_from_slice(&payload);
+            assert!(!has_embedded_content(FileType::Heic, &heic, false));
+        }
+    }
+
+    #[test]
+    fn pdf_names_are_decoded() {
+        for action in [
+            "/J#61vaScript (app.alert(1))",
+            "/#4A#53 (app.alert(1))",
+            "/#4c#61unch << /F (cmd.exe) >>",
+        ] {
+            let object = format!("<< /Type /Catalog /OpenAction << /S {} >> >>", action);
+            let pdf = get_pdf(&[object.as_bytes()]);
+
+            assert!(
+                has_embedded_content(FileType::Pdf, &pdf, false),
+                "{}",
+                action
+            );
+        }
+
+        for object in [
+            "<< /Type /Catalog /JSomething 1 >>",
+            "<< /Title (/JavaScript in a string) >>",
+            "<< /Author <2F4A53> >>",
+        ] {
+            let pdf = get_pdf(&[object.as_bytes()]);
+            assert!(
+                !has_embedded_content(FileType::Pdf, &pdf, false),
+                "{}",
+                object
+            );
+        }
+    }
+
+    #[test]
+    fn pdf_object_streams_are_inflated() {
+        let catalog: &[u8] = b"<< /Type /Catalog >>";
+
+        let clean = get_object_stream(b"2 0 << /Type /Pages /Kids [] /Count 0 >>");
+        assert!(!has_embedded_content(
+            FileType::Pdf,
+            &get_pdf(&[catalog, &clean]),
+            false
+        ));
+
+        let hidden = get_object_stream(b"2 0 << /S /JavaScript /JS (app.alert(1)) >>");
+        assert!(has_embedded_content(
+            FileType::Pdf,
+            &get_pdf(&[catalog, &hidden]),
+            false
+        ));
+
+        let not_inspectable = [
+            get_stream("/Type /ObjStm /N 1 /First 4 /Filter /LZWDecode", b"data"),
+            get_stream(
+                "/Type /ObjStm /N 1 /First 4 /Filter /FlateDecode /DecodeParms << /Predictor 12 >>",
+                b"data",
+            ),
+            get_stream(
+                "/Type /ObjStm /N 1 /First 4 /Filter /FlateDecode",
+                b"not zlib",
+            ),
+        ];
+
+        for stream in not_inspectable {
+            assert!(has_embedded_content(
+                FileType::Pdf,
+                &get_pdf(&[catalog, &stream]),
+                false
+            ));
+        }
+    }
+
+    #[test]
+    fn images_should_be_walked_to_the_end() {
+        let mut png = get_png(800, 600);
+        png.truncate(png.len() - 20);
+        assert!(has_embedded_content(FileType::Png, &png, false));
+
+        let mut jpeg = get_jpeg(1200, 1600);
+        jpeg.truncate(jpeg.len() - 2);
+        assert!(has_embedded_content(FileType::Jpeg, &jpeg, false));
+
+        let mut heic = get_heic(4032, 3024);
+        heic.extend_from_slice(b"<html>");
+        assert!(has_embedded_content(FileType::Heic, &heic, false));
+
+        let mut heic = get_heic(4032, 3024);
+        heic.extend_from_slice(b"\x00\x00\x00\x18free<script>alert(1)");
+        assert!(has_embedded_content(FileType::Heic, &heic, false));
+    }
+
+    #[test]
+    fn camera_trailers() {
+        let mut seft = get_jpeg(1200, 1600);
+        seft.extend_from_slice(b"\x00\x00\x2a\x00SEFHdata\x10\x00\x00\x00SEFT");
+
+        let mut motion_photo = get_jpeg(1200, 1600);
+        motion_photo.extend_from_slice(b"\x00\x00\x00\x18ftypmp42\x00\x00\x00\x00isommp42");
+
+        let mut zip = get_jpeg(1200, 1600);
+        zip.extend_from_slice(b"PK\x03\x04payload");
+
+        for jpeg in [&seft, &motion_photo] {
+            assert!(has_embedded_content(FileType::Jpeg, jpeg, false));
+            assert!(!has_embedded_content(FileType::Jpeg, jpeg, true));
+        }
+
+        assert!(has_embedded_content(FileType::Jpeg, &zip, true));
+    }
+}
@@ -0,0 +1,206 @@
+use serde::{Deserialize, Serialize};
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+// Brands of HEIF files with HEVC images. AVIF shares the container but uses other brands.
+const HEIC_BRANDS: &[&[u8]] = &[b"heic", b"heix", b"heim", b"heis", b"hevc", b"hevx"];
+const HEIF_GENERIC_BRANDS: &[&[u8]] = &[b"mif1", b"msf1"];
+
+/// Types of documents and photos clients upload, e.g. for KYC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileType {
+    Pdf,
+    Jpeg,
+    Png,
+    Heic,
+    Webp,
+}
+
+impl FileType {
+    pub const ALL: [FileType; 5] = [
+        FileType::Pdf,
+        FileType::Jpeg,
+        FileType::Png,
+        FileType::Heic,
+        FileType::Webp,
+    ];
+
+    /// First extension is the canonical one
+    pub fn get_extensions(&self) -> &'static [&'static str] {
+        match self {
+            FileType::Pdf => &["pdf"],
+            FileType::Jpeg => &["jpg", "jpeg"],
+            FileType::Png => &["png"],
+            FileType::Heic => &["heic", "heif"],
+            FileType::Webp => &["webp"],
+        }
+    }
+
+    pub fn get_content_types(&self) -> &'static [&'static str] {
+        match self {
+            FileType::Pdf => &["application/pdf"],
+            FileType::Jpeg => &["image/jpeg"],
+            FileType::Png => &["image/png"],
+            FileType::Heic => &["image/heic", "image/heif"],
+            FileType::Webp => &["image/webp"],
+        }
+    }
+
+    pub fn is_image(&self) -> bool {
+        !matches!(self, FileType::Pdf)
+    }
+
+    /// Extension of the file name, case-insensitive
+    pub fn from_file_name(file_name: &str) -> Option<Self> {
+        let (_, extension) = file_name.rsplit_once('.')?;
+        let extension = extension.to_ascii_lowercase();
+
+        Self::ALL
+            .into_iter()
+            .find(|file_type| file_type.get_extensions().contains(&extension.as_str()))
+    }
+
+    /// Parameters like `; charset=binary` are ignored
+    pub fn is_matching_content_type(&self, content_type: &str) -> bool {
+        let media_type = content_type.split(';').next().unwrap_or_default().trim();
+
+        self.get_content_types()
+            .iter()
+            .any(|expected| expected.eq_ignore_ascii_case(media_type))
+    }
+
+    /// Detects the type by the magic bytes at the start of the content.
+    pub fn sniff(content: &[u8]) -> Option<Self> {
+        if content.starts_with(b"%PDF-") {
+            return Some(FileType::Pdf);
+        }
+
+        if content.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            return Some(FileType::Jpeg);
+        }
+
+        if content.starts_with(PNG_SIGNATURE) {
+            return Some(FileType::Png);
+        }
+
+        if content.len() >= 12 && &content[0..4] == b"RIFF" && &content[8..12] == b"WEBP" {
+            return Some(FileType::Webp);
+        }
+
+        if is_heic(content) {
+            return Some(FileType::Heic);
+        }
+
+        None
+    }
+}
+
+impl std::fmt::Display for FileType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.get_extensions()[0])
+    }
+}
+
+// ISO BMFF `ftyp` box: size, "ftyp", major brand, minor version, compatible brands
+fn is_heic(content: &[u8]) -> bool {
+    if content.len() < 16 || &content[4..8] != b"ftyp" {
+        return false;
+    }
+
+    let box_size = u32::from_be_bytes([content[0], content[1], content[2], content[3]]) as usize;
+    let ftyp = &content[..box_size.clamp(16, content.len())];
+    let major_brand = &ftyp[8..12];
+
+    if HEIC_BRANDS.contains(&major_brand) {
+        return true;
+    }
+
+    HEIF_GENERIC_BRANDS.contains(&major_brand)
+        && ftyp[16..]
+            .chunks_exact(4)
+            .any(|brand| HEIC_BRANDS.contains(&brand))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_ftyp(major_brand: &[u8], compatible_brands: &[&[u8]]) -> Vec<u8> {
+        let size = 16 + compatible_brands.len() * 4;
+
+        let mut result = (size as u32).to_be_bytes().to_vec();
+        result.extend_from_slice(b"ftyp");
+        result.extend_from_slice(major_brand);
+        result.extend_from_slice(&[0, 0, 0, 0]);
+
+        for brand in compatible_brands {
+            result.extend_from_slice(brand);
+        }
+
+        result
+    }
+
+    #[test]
+    fn sniffs_magic_bytes() {
+        assert_eq!(FileType::sniff(b"%PDF-1.7\n"), Some(FileType::Pdf));
+        assert_eq!(
+            FileType::sniff(&[0xFF, 0xD8, 0xFF, 0xE0]),
+            Some(FileType::Jpeg)
+        );
+        assert_eq!(FileType::sniff(b"\x89PNG\r\n\x1a\n"), Some(FileType::Png));
+        assert_eq!(
+            FileType::sniff(b"RIFF\x24\x00\x00\x00WEBPVP8X"),
+            Some(FileType::Webp)
+        );
+        assert_eq!(
+            FileType::sniff(&get_ftyp(b"heic", &[b"mif1", b"heic"])),
+            Some(FileType::Heic)
+        );
+        assert_eq!(
+            FileType::sniff(&get_ftyp(b"mif1", &[b"mif1", b"heic"])),
+            Some(FileType::Heic)
+        );
+
+        assert_eq!(
+            FileType::sniff(&get_ftyp(b"avif", &[b"mif1", b"avif"])),
+            None
+        );
+        assert_eq!(FileType::sniff(&get_ftyp(b"mif1", &[b"avif"])), None);
+        assert_eq!(FileType::sniff(b"RIFF\x24\x00\x00\x00WAVEfmt "), None);
+        assert_eq!(FileType::sniff(b"GIF89a"), None);
+        assert_eq!(FileType::sniff(b"<html>%PDF-1.7"), None);
+        assert_eq!(FileType::sniff(b""), None);
+    }
+
+    #[test]
+    fn detects_type_by_file_name() {
+        assert_eq!(
+            FileType::from_file_name("passport.JPG"),
+            Some(FileType::Jpeg)
+        );
+        assert_eq!(
+            FileType::from_file_name("scan.final.pdf"),
+            Some(FileType::Pdf)
+        );
+        assert_eq!(FileType::from_file_name("photo.heif"), Some(FileType::Heic));
+        assert_eq!(FileType::from_file_name("photo.pdf.exe"), None);
+        assert_eq!(FileType::from_file_name("pdf"), None);
+    }
+
+    #[test]
+    fn matches_content_type() {
+        assert!(FileType::Pdf.is_matching_content_type("application/pdf"));
+        assert!(FileType::Jpeg.is_matching_content_type("IMAGE/JPEG; charset=binary"));
+        assert!(FileType::Heic.is_matching_content_type("image/heif"));
+        assert!(!FileType::Png.is_matching_content_type("image/jpeg"));
+    }
+
+    #[test]
+    fn serialized_in_lowercase() {
+        assert_eq!(
+            serde_json::to_string(&FileType::ALL).unwrap(),
+            r#"["pdf","jpeg","png","heic","webp"]"#
+        );
+    }
+}
@@ -0,0 +1,507 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use service_sdk::my_http_server::{HttpContext, HttpFailResult};
+
+use super::embedded_content::has_embedded_content;
+use super::{FileType, ImageDimensions};
+use crate::{ApiError, ApiResultStatus, WithTraceId};
+
+pub const FILE_REASON_EMPTY: &str = "empty";
+pub const FILE_REASON_TOO_LARGE: &str = "too_large";
+pub const FILE_REASON_UNKNOWN_CONTENT: &str = "unknown_content";
+pub const FILE_REASON_EMBEDDED_CONTENT: &str = "embedded_content";
+pub const FILE_REASON_IMAGE_TOO_LARGE: &str = "image_too_large";
+pub const FILE_REASON_IMAGE_TOO_SMALL: &str = "image_too_small";
+
+/// Payload of [`ApiResultStatus::WrongFileExtension`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WrongFileExtensionData {
+    pub allowed_extensions: Vec<&'static str>,
+}
+
+/// Payload of [`ApiResultStatus::InvalidFileContent`]. `reason` is one of the `FILE_REASON_*` codes,
+/// limits are set for the size and dimension reasons.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvalidFileContentData {
+    pub reason: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_size_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions_limit: Option<ImageDimensions>,
+}
+
+impl InvalidFileContentData {
+    fn new(reason: &'static str) -> Self {
+        Self {
+            reason,
+            max_size_bytes: None,
+            dimensions_limit: None,
+        }
+    }
+}
+
+/// Upload rules of one purpose, e.g. KYC documents or a selfie.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FileUploadPolicy {
+    pub allowed_types: Vec<FileType>,
+    pub max_size_bytes: u64,
+    pub max_image_width: u32,
+    pub max_image_height: u32,
+    pub min_image_width: u32,
+    pub min_image_height: u32,
+    /// Accept Samsung SEF blocks and Motion Photo videos after the end of JPEG images.
+    /// They are not inspected and may carry any payload, so it is off by default.
+    pub allow_camera_trailers: bool,
+}
+
+impl Default for FileUploadPolicy {
+    fn default() -> Self {
+        Self {
+            allowed_types: FileType::ALL.to_vec(),
+            max_size_bytes: 10 * 1024 * 1024,
+            max_image_width: 10_000,
+            max_image_height: 10_000,
+            min_image_width: 0,
+            min_image_height: 0,
+            allow_camera_trailers: false,
+        }
+    }
+}
+
+impl FileUploadPolicy {
+    /// Returns the type detected by the content.
+    ///
+    /// Extension, content type and content mismatches are [`ApiResultStatus::WrongFileExtension`],
+    /// other failures are [`ApiResultStatus::InvalidFileContent`] with [`InvalidFileContentData`].
+    /// `content_type` is optional, as `application/octet-stream` is treated as not set.
+    pub fn check(
+        &self,
+        file_name: &str,
+        content_type: Option<&str>,
+        content: &[u8],
+    ) -> Result<FileType, ApiError> {
+        if content.is_empty() {
+            return Err(invalid_content(InvalidFileContentData::new(
+                FILE_REASON_EMPTY,
+            )));
+        }
+
+        if content.len() as u64 > self.max_size_bytes {
+            return Err(invalid_content(InvalidFileContentData {
+                max_size_bytes: Some(self.max_size_bytes),
+                ..InvalidFileContentData::new(FILE_REASON_TOO_LARGE)
+            }));
+        }
+
+        let declared_type = FileType::from_file_name(file_name)
+            .filter(|file_type| self.allowed_types.contains(file_type))
+            .ok_or_else(|| self.wrong_extension())?;
+
+        let Some(file_type) = FileType::sniff(content) else {
+            return Err(invalid_content(InvalidFileContentData::new(
+                FILE_REASON_UNKNOWN_CONTENT,
+            )));
+        };
+
+        if file_type != declared_type {
+            return Err(self.wrong_extension());
+        }
+
+        if let Some(content_type) = content_type {
+            let media_type = content_type.split(';').next().unwrap_or_default().trim();
+            let is_set = !media_type.is_empty()
+                && !media_type.eq_ignore_ascii_case("application/octet-stream");
+
+            if is_set && !file_type.is_matching_content_type(content_type) {
+                return Err(self.wrong_extension());
+            }
+        }
+
+        if has_embedded_content(file_type, content, self.allow_camera_trailers) {
+            return Err(invalid_content(InvalidFileContentData::new(
+                FILE_REASON_EMBEDDED_CONTENT,
+            )));
+        }
+
+        if file_type.is_image() {
+            self.check_dimensions(file_type, content)?;
+        }
+
+        Ok(file_type)
+    }
+
+    fn check_dimensions(&self, file_type: FileType, content: &[u8]) -> Result<(), ApiError> {
+        let Some(dimensions) = ImageDimensions::read(file_type, content) else {
+            return Err(invalid_content(InvalidFileContentData::new(
+                FILE_REASON_UNKNOWN_CONTENT,
+            )));
+        };
+
+        if dimensions.width > self.max_image_width || dimensions.height > self.max_image_height {
+            return Err(invalid_content(InvalidFileContentData {
+                dimensions_limit: Some(ImageDimensions::new(
+                    self.max_image_width,
+                    self.max_image_height,
+                )),
+                ..InvalidFileContentData::new(FILE_REASON_IMAGE_TOO_LARGE)
+            }));
+        }
+
+        if dimensions.width < self.min_image_width || dimensions.height < self.min_image_height {
+            return Err(invalid_content(InvalidFileContentData {
+                dimensions_limit: Some(ImageDimensions::new(
+                    self.min_image_width,
+                    self.min_image_height,
+                )),
+                ..InvalidFileContentData::new(FILE_REASON_IMAGE_TOO_SMALL)
+            }));
+        }
+
+        Ok(())
+    }
+
+    fn wrong_extension(&self) -> ApiError {
+        let allowed_extensions = self
+            .allowed_types
+            .iter()
+            .flat_map(|file_type| file_type.get_extensions().iter().copied())
+            .collect();
+
+        ApiError::new(ApiResultStatus::WrongFileExtension)
+            .with_data(WrongFileExtensionData { allowed_extensions })
+    }
+}
+
+/// Upload policies by purpose. Purposes without own policy use `default`.
+/// Per brand they are configured as `BrandPolicies<FileUploadPolicies>`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FileUploadPolicies {
+    #[serde(default)]
+    pub default: FileUploadPolicy,
+    #[serde(default)]
+    pub purposes: HashMap<String, FileUploadPolicy>,
+}
+
+impl FileUploadPolicies {
+    pub fn get(&self, purpose: &str) -> &FileUploadPolicy {
+        self.purposes.get(purpose).unwrap_or(&self.default)
+    }
+}
+
+pub fn validate_uploaded_file(
+    ctx: &HttpContext,
+    policy: &FileUploadPolicy,
+    file_name: &str,
+    content_type: Option<&str>,
+    content: &[u8],
+) -> Result<FileType, HttpFailResult> {
+    policy
+        .check(file_name, content_type, content)
+        .map_err(|err| HttpFailResult::from(err).with_trace_id(ctx))
+}
+
+fn invalid_content(data: InvalidFileContentData) -> ApiError {
+    ApiError::new(ApiResultStatus::InvalidFileContent).with_data(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::image_dimensions::tests::{get_heic, get_jpeg, get_png, get_webp};
+    use super::*;
+
+    const PDF: &[u8] = b"%PDF-1.7\n1 0 obj\n<< /Type /Catalog /JSomething 1 >>\nendobj\ntrailer\n<< /Root 1 0 R >>\n%%EOF\n";
+
+    fn get_status(result: Result<FileType, ApiError>) -> (i16, serde_json::Value) {
+        let err = result.unwrap_err();
+        (err.status as i16, err.data.unwrap_or_default())
+    }
+
+    fn assert_invalid_content(result: Result<FileType, ApiError>, reason: &str) {
+        let (status, data) = get_status(result);
+
+        assert_eq!(status, ApiResultStatus::InvalidFileContent as i16);
+        assert_eq!(data["reason"], reason);
+    }
+
+    fn assert_wrong_extension(result: Result<FileType, ApiError>) {
+        let (status, _) = get_status(result);
+        assert_eq!(status, ApiResultStatus::WrongFileExtension as i16);
+    }
+
+    #[test]
+    fn accepts_valid_files() {
+        let policy = FileUploadPolicy::default();
+
+        for (file_name, content_type, content, expected) in [
+            (
+                "passport.pdf",
+                "application/pdf",
+                PDF.to_vec(),
+                FileType::Pdf,
+            ),
+            (
+                "selfie.JPEG",
+                "image/jpeg",
+                get_jpeg(1200, 1600),
+                FileType::Jpeg,
+            ),
+            ("bill.png", "image/png", get_png(800, 600), FileType::Png),
+            (
+                "id.heic",
+                "image/heic",
+                get_heic(4032, 3024),
+                FileType::Heic,
+            ),
+            ("id.webp", "image/webp", get_webp(800, 600), FileType::Webp),
+        ] {
+            assert_eq!(
+                policy
+                    .check(file_name, Some(content_type), &content)
+                    .unwrap(),
+                expected
+            );
+            assert_eq!(policy.check(file_name, None, &content).unwrap(), expected);
+        }
+
+        let mut padded_jpeg = get_jpeg(1200, 1600);
+        padded_jpeg.extend_from_slice(&[0, 0, 0, 0]);
+        assert!(policy.check("selfie.jpg", None, &padded_jpeg).is_ok());
+
+        let mut motion_photo = get_jpeg(1200, 1600);
+        motion_photo.extend_from_slice(b"\x00\x00\x00\x18ftypmp42\x00\x00\x00\x00isommp42");
+        assert_invalid_content(
+            policy.check("selfie.jpg", None, &motion_photo),
+            FILE_REASON_EMBEDDED_CONTENT,
+        );
+
+        let camera_policy = FileUploadPolicy {
+            allow_camera_trailers: true,
+            ..Default::default()
+        };
+        assert!(camera_policy
+            .check("selfie.jpg", None, &motion_photo)
+            .is_ok());
+    }
+
+    #[test]
+    fn extension_should_be_allowed_and_match_content() {
+        let policy = FileUploadPolicy {
+            allowed_types: vec![FileType::Pdf, FileType::Jpeg],
+            ..Default::default()
+        };
+
+        let (status, data) = get_status(policy.check("bill.png", None, &get_png(800, 600)));
+        assert_eq!(status, ApiResultStatus::WrongFileExtension as i16);
+        assert_eq!(
+            data,
+            serde_json::json!({ "allowedExtensions": ["pdf", "jpg", "jpeg"] })
+        );
+
+        assert_wrong_extension(policy.check("passport", None, PDF));
+        assert_wrong_extension(policy.check("passport.exe", None, PDF));
+        assert_wrong_extension(policy.check("passport.jpg", None, PDF));
+        assert_wrong_extension(policy.check("passport.pdf", None, &get_jpeg(1200, 1600)));
+    }
+
+    #[test]
+    fn content_type_should_match_content() {
+        let policy = FileUploadPolicy::default();
+        let png = get_png(800, 600);
+
+        assert_wrong_extension(policy.check("bill.png", Some("image/jpeg"), &png));
+        assert_wrong_extension(policy.check("bill.png", Some("text/html"), &png));
+        assert!(policy
+            .check("bill.png", Some("application/octet-stream"), &png)
+            .is_ok());
+        assert!(policy
+            .check(
+                "bill.png",
+                Some("Application/Octet-Stream; charset=binary"),
+                &png
+            )
+            .is_ok());
+        assert!(policy.check("bill.png", Some(""), &png).is_ok());
+        assert!(policy.check("bill.png", Some("IMAGE/PNG"), &png).is_ok());
+    }
+
+    #[test]
+    fn size_limit() {
+        let policy = FileUploadPolicy {
+            max_size_bytes: 100,
+            ..Default::default()
+        };
+
+        assert_invalid_content(policy.check("passport.pdf", None, b""), FILE_REASON_EMPTY);
+
+        let mut pdf = PDF.to_vec();
+        pdf.resize(101, b' ');
+
+        let (status, data) = get_status(policy.check("passport.pdf", None, &pdf));
+        assert_eq!(status, ApiResultStatus::InvalidFileContent as i16);
+        assert_eq!(
+            data,
+            serde_json::json!({ "reason": "too_large", "maxSizeBytes": 100 })
+        );
+
+        pdf.truncate(100);
+        assert!(policy.check("passport.pdf", None, &pdf).is_ok());
+    }
+
+    #[test]
+    fn unknown_content() {
+        let policy = FileUploadPolicy::default();
+
+        assert_invalid_content(
+            policy.check("passport.pdf", None, b"GIF89a"),
+            FILE_REASON_UNKNOWN_CONTENT,
+        );
+        assert_invalid_content(
+            policy.check("selfie.jpg", None, &[0xFF, 0xD8, 0xFF, 0xD9]),
+            FILE_REASON_UNKNOWN_CONTENT,
+        );
+    }
+
+    #[test]
+    fn image_dimension_limits() {
+        let policy = FileUploadPolicy {
+            max_image_width: 4000,
+            max_image_height: 4000,
+            min_image_width: 300,
+            min_image_height: 300,
+            ..Default::default()
+        };
+
+        assert!(policy
+            .check("selfie.png", None, &get_png(4000, 300))
+            .is_ok());
+
+        let (status, data) = get_status(policy.check("selfie.png", None, &get_png(4001, 3000)));
+        assert_eq!(status, ApiResultStatus::InvalidFileContent as i16);
+        assert_eq!(
+            data,
+            serde_json::json!({
+                "reason": "image_too_large",
+                "dimensionsLimit": { "width": 4000, "height": 4000 }
+            })
+        );
+
+        assert_invalid_content(
+            policy.check("selfie.webp", None, &get_webp(800, 299)),
+            FILE_REASON_IMAGE_TOO_SMALL,
+        );
+        assert_invalid_content(
+            policy.check("selfie.heic", None, &get_heic(8000, 6000)),
+            FILE_REASON_IMAGE_TOO_LARGE,
+        );
+    }
+
+    #[test]
+    fn rejects_polyglot_files() {
+        let policy = FileUploadPolicy::default();
+
+        let mut png_with_html = get_png(800, 600);
+        png_with_html.splice(
+            33..33,
+            b"\x00\x00\x00\x18tEXtComment\x00<SCRIPT>alert(1)\x00\x00\x00\x00"
+                .iter()
+                .copied(),
+        );
+        assert_invalid_content(
+            policy.check("bill.png", None, &png_with_html),
+            FILE_REASON_EMBEDDED_CONTENT,
+        );
+
+        let mut png_with_zip = get_png(800, 600);
+        png_with_zip.extend_from_slice(b"PK\x03\x04payload");
+        assert_invalid_content(
+            policy.check("bill.png", None, &png_with_zip),
+            FILE_REASON_EMBEDDED_CONTENT,
+        );
+
+        let mut jpeg_with_php = get_jpeg(1200, 1600);
+        jpeg_with_php.extend_from_slice(b"<?php system($_GET['c']); ?>");
+        assert_invalid_content(
+            policy.check("selfie.jpg", None, &jpeg_with_php),
+            FILE_REASON_EMBEDDED_CONTENT,
+        );
+
+        let mut webp_with_data = get_webp(800, 600);
+        webp_with_data.extend_from_slice(b"appended");
+        assert_invalid_content(
+            policy.check("id.webp", None, &webp_with_data),
+            FILE_REASON_EMBEDDED_CONTENT,
+        );
+    }
+
+    #[test]
+    fn rejects_pdf_with_actions() {
+        let policy = FileUploadPolicy::default();
+
+        for action in [
+            "/JavaScript (app.alert(1))",
+            "/JS (app.alert(1))",
+            "/Launch << /F (cmd.exe) >>",
+            "/EmbeddedFiles 2 0 R",
+            "/OpenAction << /S /javascript >>",
+        ] {
+            let pdf = format!(
+                "%PDF-1.7\n1 0 obj\n<< /Type /Catalog {} >>\nendobj\n%%EOF\n",
+                action
+            );
+
+            assert_invalid_content(
+                policy.check("passport.pdf", None, pdf.as_bytes()),
+                FILE_REASON_EMBEDDED_CONTENT,
+            );
+        }
+
+        let mut pdf_with_html = PDF.to_vec();
+        pdf_with_html.extend_from_slice(b"<html><body>phishing</body></html>");
+        assert_invalid_content(
+            policy.check("passport.pdf", None, &pdf_with_html),
+            FILE_REASON_EMBEDDED_CONTENT,
+        );
+
+        assert_invalid_content(
+            policy.check("passport.pdf", None, b"%PDF-1.7\n1 0 obj\nendobj\n"),
+            FILE_REASON_EMBEDDED_CONTENT,
+        );
+    }
+
+    #[test]
+    fn policies_by_purpose() {
+        let policies: FileUploadPolicies = serde_json::from_str(
+            r#"{"purposes":{"selfie":{"allowedTypes":["jpeg","png"],"minImageWidth":480,"minImageHeight":640}}}"#,
+        )
+        .unwrap();
+
+        let selfie = policies.get("selfie");
+        assert_eq!(selfie.allowed_types, vec![FileType::Jpeg, FileType::Png]);
+        assert_eq!(selfie.min_image_width, 480);
+        assert_eq!(selfie.max_size_bytes, 10 * 1024 * 1024);
+
+        assert_eq!(policies.get("kyc-document"), &FileUploadPolicy::default());
+    }
+
+    #[test]
+    fn policies_by_brand_and_purpose() {
+        let policies: crate::BrandPolicies<FileUploadPolicies> = serde_json::from_str(
+            r#"{"brands":{"brand-1":{"purposes":{"selfie":{"maxSizeBytes":1024}}}}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(policies.get("brand-1").get("selfie").max_size_bytes, 1024);
+        assert_eq!(
+            policies.get("brand-2").get("selfie"),
+            &FileUploadPolicy::default()
+        );
+
+        let json = serde_json::to_string(policies.get("brand-1")).unwrap();
+        let restored: FileUploadPolicies = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.get("selfie").max_size_bytes, 1024);
+    }
+}
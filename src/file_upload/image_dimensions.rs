@@ -0,0 +1,261 @@
+use serde::{Deserialize, Serialize};
+
+use super::FileType;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageDimensions {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl ImageDimensions {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self { width, height }
+    }
+
+    /// Reads the dimensions from the image header without decoding the image.
+    /// `None` for PDF and for images with damaged headers.
+    pub fn read(file_type: FileType, content: &[u8]) -> Option<Self> {
+        let result = match file_type {
+            FileType::Pdf => return None,
+            FileType::Jpeg => read_jpeg(content),
+            FileType::Png => read_png(content),
+            FileType::Webp => read_webp(content),
+            FileType::Heic => read_heic(content),
+        }?;
+
+        if result.width == 0 || result.height == 0 {
+            return None;
+        }
+
+        Some(result)
+    }
+}
+
+pub(super) fn read_u16_be(content: &[u8], offset: usize) -> Option<u32> {
+    let bytes = content.get(offset..offset + 2)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]) as u32)
+}
+
+pub(super) fn read_u32_be(content: &[u8], offset: usize) -> Option<u32> {
+    let bytes = content.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn read_u24_le(content: &[u8], offset: usize) -> Option<u32> {
+    let bytes = content.get(offset..offset + 3)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0]))
+}
+
+// Signature, then the IHDR chunk: length, "IHDR", width, height
+fn read_png(content: &[u8]) -> Option<ImageDimensions> {
+    if content.get(12..16)? != b"IHDR" {
+        return None;
+    }
+
+    Some(ImageDimensions::new(
+        read_u32_be(content, 16)?,
+        read_u32_be(content, 20)?,
+    ))
+}
+
+// Walks the segments till the SOFn (start of frame) one
+fn read_jpeg(content: &[u8]) -> Option<ImageDimensions> {
+    let mut offset = 2;
+
+    loop {
+        if *content.get(offset)? != 0xFF {
+            return None;
+        }
+
+        let marker = *content.get(offset + 1)?;
+
+        match marker {
+            // Fill bytes
+            0xFF => {
+                offset += 1;
+                continue;
+            }
+            // Segments without length
+            0x01 | 0xD0..=0xD7 => {
+                offset += 2;
+                continue;
+            }
+            // End of image or start of scan before any frame header
+            0xD9 | 0xDA => return None,
+            // SOF0..SOF15 except DHT, JPG and DAC
+            0xC0..=0xCF if !matches!(marker, 0xC4 | 0xC8 | 0xCC) => {
+                return Some(ImageDimensions::new(
+                    read_u16_be(content, offset + 7)?,
+                    read_u16_be(content, offset + 5)?,
+                ));
+            }
+            _ => {
+                offset += 2 + read_u16_be(content, offset + 2)? as usize;
+            }
+        }
+    }
+}
+
+// RIFF header, then the first chunk is VP8 (lossy), VP8L (lossless) or VP8X (extended)
+fn read_webp(content: &[u8]) -> Option<ImageDimensions> {
+    match content.get(12..16)? {
+        b"VP8 " => {
+            if content.get(23..26)? != [0x9D, 0x01, 0x2A] {
+                return None;
+            }
+
+            Some(ImageDimensions::new(
+                u16::from_le_bytes([*content.get(26)?, *content.get(27)?]) as u32 & 0x3FFF,
+                u16::from_le_bytes([*content.get(28)?, *content.get(29)?]) as u32 & 0x3FFF,
+            ))
+        }
+        b"VP8L" => {
+            if *content.get(20)? != 0x2F {
+                return None;
+            }
+
+            let bits = u32::from_le_bytes(content.get(21..25)?.try_into().ok()?);
+
+            Some(ImageDimensions::new(
+                (bits & 0x3FFF) + 1,
+                ((bits >> 14) & 0x3FFF) + 1,
+            ))
+        }
+        b"VP8X" => Some(ImageDimensions::new(
+            read_u24_le(content, 24)? + 1,
+            read_u24_le(content, 27)? + 1,
+        )),
+        _ => None,
+    }
+}
+
+// Image spatial extents ("ispe") properties: version and flags, width, height.
+// Thumbnails and grid tiles have their own ones, the largest one is the full image.
+fn read_heic(content: &[u8]) -> Option<ImageDimensions> {
+    content
+        .windows(4)
+        .enumerate()
+        .filter(|(_, window)| *window == b"ispe")
+        .filter_map(|(offset, _)| {
+            Some(ImageDimensions::new(
+                read_u32_be(content, offset + 8)?,
+                read_u32_be(content, offset + 12)?,
+            ))
+        })
+        .max_by_key(|dimensions| dimensions.width as u64 * dimensions.height as u64)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub fn get_png(width: u32, height: u32) -> Vec<u8> {
+        let mut result = b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR".to_vec();
+        result.extend_from_slice(&width.to_be_bytes());
+        result.extend_from_slice(&height.to_be_bytes());
+        result.extend_from_slice(&[8, 6, 0, 0, 0, 0, 0, 0, 0]);
+        result.extend_from_slice(b"\x00\x00\x00\x00IEND\xae\x42\x60\x82");
+        result
+    }
+
+    pub fn get_jpeg(width: u16, height: u16) -> Vec<u8> {
+        let mut result = vec![0xFF, 0xD8];
+        // APP0 (JFIF)
+        result.extend_from_slice(&[0xFF, 0xE0, 0x00, 0x10]);
+        result.extend_from_slice(b"JFIF\x00\x01\x01\x00\x00\x01\x00\x01\x00\x00");
+        // SOF0
+        result.extend_from_slice(&[0xFF, 0xC0, 0x00, 0x11, 0x08]);
+        result.extend_from_slice(&height.to_be_bytes());
+        result.extend_from_slice(&width.to_be_bytes());
+        result.extend_from_slice(&[3, 1, 0x22, 0, 2, 0x11, 1, 3, 0x11, 1]);
+        result.extend_from_slice(&[0xFF, 0xD9]);
+        result
+    }
+
+    pub fn get_webp(width: u32, height: u32) -> Vec<u8> {
+        let mut result = b"RIFF\x16\x00\x00\x00WEBPVP8X\x0a\x00\x00\x00\x00\x00\x00\x00".to_vec();
+        result.extend_from_slice(&(width - 1).to_le_bytes()[..3]);
+        result.extend_from_slice(&(height - 1).to_le_bytes()[..3]);
+        result
+    }
+
+    pub fn get_heic(width: u32, height: u32) -> Vec<u8> {
+        let mut result = b"\x00\x00\x00\x18ftypheic\x00\x00\x00\x00mif1heic".to_vec();
+        // Thumbnail and primary image properties
+        for (width, height) in [(320, 240), (width, height)] {
+            result.extend_from_slice(b"\x00\x00\x00\x14ispe\x00\x00\x00\x00");
+            result.extend_from_slice(&u32::to_be_bytes(width));
+            result.extend_from_slice(&u32::to_be_bytes(height));
+        }
+        result
+    }
+
+    #[test]
+    fn reads_dimensions() {
+        assert_eq!(
+            ImageDimensions::read(FileType::Png, &get_png(1920, 1080)),
+            Some(ImageDimensions::new(1920, 1080))
+        );
+        assert_eq!(
+            ImageDimensions::read(FileType::Jpeg, &get_jpeg(4032, 3024)),
+            Some(ImageDimensions::new(4032, 3024))
+        );
+        assert_eq!(
+            ImageDimensions::read(FileType::Webp, &get_webp(800, 600)),
+            Some(ImageDimensions::new(800, 600))
+        );
+        assert_eq!(
+            ImageDimensions::read(FileType::Heic, &get_heic(4032, 3024)),
+            Some(ImageDimensions::new(4032, 3024))
+        );
+        assert_eq!(ImageDimensions::read(FileType::Pdf, b"%PDF-1.7"), None);
+    }
+
+    #[test]
+    fn reads_lossy_and_lossless_webp() {
+        let mut lossy =
+            b"RIFF\x00\x00\x00\x00WEBPVP8 \x00\x00\x00\x00\x00\x00\x00\x9d\x01\x2a".to_vec();
+        lossy.extend_from_slice(&640u16.to_le_bytes());
+        lossy.extend_from_slice(&480u16.to_le_bytes());
+
+        assert_eq!(
+            ImageDimensions::read(FileType::Webp, &lossy),
+            Some(ImageDimensions::new(640, 480))
+        );
+
+        let bits: u32 = (640 - 1) | ((480 - 1) << 14);
+        let mut lossless = b"RIFF\x00\x00\x00\x00WEBPVP8L\x00\x00\x00\x00\x2f".to_vec();
+        lossless.extend_from_slice(&bits.to_le_bytes());
+
+        assert_eq!(
+            ImageDimensions::read(FileType::Webp, &lossless),
+            Some(ImageDimensions::new(640, 480))
+        );
+    }
+
+    #[test]
+    fn damaged_headers() {
+        let png = get_png(1920, 1080);
+        let jpeg = get_jpeg(4032, 3024);
+
+        assert_eq!(ImageDimensions::read(FileType::Png, &png[..20]), None);
+        assert_eq!(
+            ImageDimensions::read(FileType::Png, &get_png(0, 1080)),
+            None
+        );
+        assert_eq!(ImageDimensions::read(FileType::Jpeg, &jpeg[..24]), None);
+        assert_eq!(
+            ImageDimensions::read(FileType::Jpeg, &[0xFF, 0xD8, 0xFF, 0xD9]),
+            None
+        );
+        assert_eq!(
+            ImageDimensions::read(FileType::Webp, b"RIFF\x00\x00\x00\x00WEBPVP8 "),
+            None
+        );
+        assert_eq!(
+            ImageDimensions::read(FileType::Heic, b"\x00\x00\x00\x10ftypheic"),
+            None
+        );
+    }
+}
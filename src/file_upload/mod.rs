@@ -0,0 +1,10 @@
+mod file_type;
+pub use file_type::*;
+
+mod image_dimensions;
+pub use image_dimensions::*;
+
+mod embedded_content;
+
+mod file_upload_policy;
+pub use file_upload_policy::*;
@@ -1,7 +1,11 @@
+extern crate self as rest_api_wl_shared;
+
 mod api_result_status;
 mod get_client_id;
 pub mod middlewares;
 pub use api_result_status::*;
+mod api_error;
+pub use api_error::*;
 pub use get_client_id::*;
 
 mod countries;
@@ -10,6 +14,58 @@ pub use countries::*;
 mod validators;
 pub use validators::*;
 
+mod validation;
+pub use validation::*;
+pub use rest_api_wl_shared_macros::Validate;
+
+mod brand_policies;
+pub use brand_policies::*;
+
+mod password;
+pub use password::*;
+
+mod email;
+pub use email::*;
+
+mod phone;
+pub use phone::*;
+
+mod name;
+pub use name::*;
+
+mod zip_code;
+pub use zip_code::*;
+
+mod date_of_birth;
+pub use date_of_birth::*;
+
+mod banking;
+pub use banking::*;
+
+mod crypto_wallet;
+pub use crypto_wallet::*;
+
+mod money;
+pub use money::*;
+
+mod discount;
+pub use discount::*;
+
+mod otp;
+pub use otp::*;
+
+mod file_upload;
+pub use file_upload::*;
+
+mod url_validation;
+pub use url_validation::*;
+
+mod country_metadata;
+pub use country_metadata::*;
+
+mod country_names;
+pub use country_names::*;
+
 mod get_language;
 pub use get_language::*;
 
@@ -19,6 +75,17 @@ pub use cookie::*;
 mod header;
 pub use header::*;
 
+mod pagination;
+pub use pagination::*;
+
+mod request_id;
+pub use request_id::*;
+
+pub mod token;
+
+mod contract_export;
+pub use contract_export::*;
+
 #[cfg(feature = "auth-middleware")]
 mod configure_rest_api_server;
 #[cfg(feature = "auth-middleware")]
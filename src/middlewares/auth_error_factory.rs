@@ -1,59 +1,331 @@
 service_sdk::macros::use_my_http_server!();
 
-use my_http_server::controllers::{documentation::DataTypeProvider, AuthErrorFactory};
-use my_http_server::WebContentType;
+use lazy_static::lazy_static;
+use my_http_server::controllers::documentation::{
+    data_types::HttpDataType, out_results::HttpResult, DataTypeProvider,
+};
+use my_http_server::controllers::AuthErrorFactory;
+use my_http_server::{HttpFailResult, WebContentType};
 use serde::Serialize;
 use service_sdk::my_http_server::macros::MyHttpObjectStructure;
 
 use crate::{ApiHttpResult, ApiResultStatus};
 
-pub struct AuthErrorFactoryWl;
+use super::{AuthenticationFailedApiResponse, AuthorizationFailedApiResponse};
+
 #[derive(Serialize, MyHttpObjectStructure)]
 pub struct AccessClaimRequired {
     pub result: ApiResultStatus,
     pub data: String,
 }
 
-impl AuthErrorFactory for AuthErrorFactoryWl {
-    fn get_not_authenticated(&self) -> my_http_server::HttpFailResult {
-        ApiResultStatus::AccessTokenInvalid.into()
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthErrorBodyLayout {
+    /// `{"result":-17}` and `{"result":-18,"data":"<claim>"}`
+    ResultWithData,
+    /// `{"result":-17,"description":"..."}` and `{"result":-18,"description":"...","claim":"<claim>"}`
+    ResultWithDescription,
+}
+
+/// Descriptions of the auth failures. They are fixed per factory: [`AuthErrorFactory`]
+/// is called without the request, so they can not follow the request language.
+#[derive(Debug, Clone)]
+pub struct AuthErrorTexts {
+    pub not_authenticated: String,
+    pub not_authorized: String,
+}
+
+impl Default for AuthErrorTexts {
+    fn default() -> Self {
+        Self {
+            not_authenticated: AuthenticationFailedApiResponse::default_desc(),
+            not_authorized: AuthorizationFailedApiResponse::default_desc(),
+        }
     }
+}
 
-    fn get_not_authorized(&self, claim_name: String) -> my_http_server::HttpFailResult {
-        let content = AccessClaimRequired {
-            result: ApiResultStatus::AccessClaimRequired,
-            data: claim_name,
-        };
+/// Single place which decides how auth failures look on the wire.
+///
+/// `legacy_compatibility` reproduces what the old factory of the layout returned:
+/// - `ResultWithData` (`AuthErrorFactoryWl`): same bodies and codes, documented with the old descriptions;
+/// - `ResultWithDescription` (`AuthFailResponseFactory`): not-authorized is 401 without the claim name.
+#[derive(Debug, Clone)]
+pub struct WlAuthErrorFactory {
+    pub layout: AuthErrorBodyLayout,
+    pub texts: AuthErrorTexts,
+    pub legacy_compatibility: bool,
+}
 
-        let content = serde_json::to_string(&content).unwrap();
+impl WlAuthErrorFactory {
+    pub fn new(layout: AuthErrorBodyLayout) -> Self {
+        Self {
+            layout,
+            texts: AuthErrorTexts::default(),
+            legacy_compatibility: false,
+        }
+    }
+
+    pub fn with_texts(mut self, texts: AuthErrorTexts) -> Self {
+        self.texts = texts;
+        self
+    }
+
+    pub fn with_legacy_compatibility(mut self) -> Self {
+        self.legacy_compatibility = true;
+        self
+    }
 
-        my_http_server::HttpFailResult::new(
-            WebContentType::Json,
-            403,
-            content.into_bytes(),
-            false,
-            false,
-        )
+    fn get_not_authorized_status_code(&self) -> u16 {
+        if self.legacy_compatibility && self.layout == AuthErrorBodyLayout::ResultWithDescription {
+            401
+        } else {
+            403
+        }
     }
 
-    fn get_global_http_fail_result_types(
-        &self,
-    ) -> Option<Vec<my_http_server::controllers::documentation::out_results::HttpResult>> {
-        use my_http_server::controllers::documentation::out_results::HttpResult;
-        vec![
+    fn get_documented_texts(&self) -> AuthErrorTexts {
+        if self.legacy_compatibility && self.layout == AuthErrorBodyLayout::ResultWithData {
+            return AuthErrorTexts {
+                not_authenticated: "Unauthenticated access".to_string(),
+                not_authorized: "Unauthorized access".to_string(),
+            };
+        }
+
+        self.texts.clone()
+    }
+}
+
+impl AuthErrorFactory for WlAuthErrorFactory {
+    fn get_not_authenticated(&self) -> HttpFailResult {
+        match self.layout {
+            AuthErrorBodyLayout::ResultWithData => ApiResultStatus::AccessTokenInvalid.into(),
+            AuthErrorBodyLayout::ResultWithDescription => AuthenticationFailedApiResponse::new(
+                ApiResultStatus::AccessTokenInvalid,
+                self.texts.not_authenticated.clone(),
+            ),
+        }
+    }
+
+    fn get_not_authorized(&self, claim_name: String) -> HttpFailResult {
+        let status_code = self.get_not_authorized_status_code();
+
+        match self.layout {
+            AuthErrorBodyLayout::ResultWithData => {
+                let content = AccessClaimRequired {
+                    result: ApiResultStatus::AccessClaimRequired,
+                    data: claim_name,
+                };
+
+                HttpFailResult::new(
+                    WebContentType::Json,
+                    status_code,
+                    serde_json::to_vec(&content).unwrap(),
+                    false,
+                    false,
+                )
+            }
+            AuthErrorBodyLayout::ResultWithDescription => {
+                if self.legacy_compatibility {
+                    return AuthorizationFailedApiResponse::new(
+                        ApiResultStatus::AccessClaimRequired,
+                        self.texts.not_authorized.clone(),
+                    );
+                }
+
+                AuthorizationFailedApiResponse::new_forbidden(
+                    ApiResultStatus::AccessClaimRequired,
+                    self.texts.not_authorized.clone(),
+                    Some(claim_name),
+                )
+            }
+        }
+    }
+
+    fn get_global_http_fail_result_types(&self) -> Option<Vec<HttpResult>> {
+        let (not_authenticated_type, not_authorized_type) = match self.layout {
+            AuthErrorBodyLayout::ResultWithData => (
+                ApiHttpResult::get_data_type(),
+                AccessClaimRequired::get_data_type(),
+            ),
+            AuthErrorBodyLayout::ResultWithDescription => (
+                HttpDataType::Object(AuthenticationFailedApiResponse::get_http_data_structure()),
+                HttpDataType::Object(AuthorizationFailedApiResponse::get_http_data_structure()),
+            ),
+        };
+
+        let texts = self.get_documented_texts();
+
+        Some(vec![
             HttpResult {
                 http_code: 401,
                 nullable: false,
-                description: "Unauthenticated access".to_string(),
-                data_type: ApiHttpResult::get_data_type(),
+                description: texts.not_authenticated,
+                data_type: not_authenticated_type,
             },
             HttpResult {
-                http_code: 403,
+                http_code: self.get_not_authorized_status_code(),
                 nullable: false,
-                description: "Unauthorized access".to_string(),
-                data_type: AccessClaimRequired::get_data_type(),
+                description: texts.not_authorized,
+                data_type: not_authorized_type,
             },
-        ]
-        .into()
+        ])
+    }
+}
+
+lazy_static! {
+    static ref AUTH_ERROR_FACTORY_WL: WlAuthErrorFactory =
+        WlAuthErrorFactory::new(AuthErrorBodyLayout::ResultWithData).with_legacy_compatibility();
+    static ref AUTH_FAIL_RESPONSE_FACTORY: WlAuthErrorFactory =
+        WlAuthErrorFactory::new(AuthErrorBodyLayout::ResultWithDescription)
+            .with_legacy_compatibility();
+}
+
+/// Preset of [`WlAuthErrorFactory`] with [`AuthErrorBodyLayout::ResultWithData`] in legacy compatibility.
+pub struct AuthErrorFactoryWl;
+
+impl AuthErrorFactory for AuthErrorFactoryWl {
+    fn get_not_authenticated(&self) -> HttpFailResult {
+        AUTH_ERROR_FACTORY_WL.get_not_authenticated()
+    }
+
+    fn get_not_authorized(&self, claim_name: String) -> HttpFailResult {
+        AUTH_ERROR_FACTORY_WL.get_not_authorized(claim_name)
+    }
+
+    fn get_global_http_fail_result_types(&self) -> Option<Vec<HttpResult>> {
+        AUTH_ERROR_FACTORY_WL.get_global_http_fail_result_types()
+    }
+}
+
+/// Preset of [`WlAuthErrorFactory`] with [`AuthErrorBodyLayout::ResultWithDescription`] in legacy compatibility:
+/// not-authorized stays 401 without the claim name. Use [`WlAuthErrorFactory::new`] for 403 with the claim.
+pub struct AuthFailResponseFactory;
+
+impl AuthErrorFactory for AuthFailResponseFactory {
+    fn get_not_authenticated(&self) -> HttpFailResult {
+        AUTH_FAIL_RESPONSE_FACTORY.get_not_authenticated()
+    }
+
+    fn get_not_authorized(&self, claim_name: String) -> HttpFailResult {
+        AUTH_FAIL_RESPONSE_FACTORY.get_not_authorized(claim_name)
+    }
+
+    fn get_global_http_fail_result_types(&self) -> Option<Vec<HttpResult>> {
+        AUTH_FAIL_RESPONSE_FACTORY.get_global_http_fail_result_types()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn body(result: &HttpFailResult) -> String {
+        String::from_utf8(result.content.clone()).unwrap()
+    }
+
+    #[test]
+    fn result_with_data_layout() {
+        let factory = WlAuthErrorFactory::new(AuthErrorBodyLayout::ResultWithData);
+
+        let result = factory.get_not_authenticated();
+        assert_eq!(result.status_code, 401);
+        assert_eq!(body(&result), r#"{"result":-17}"#);
+
+        let result = factory.get_not_authorized("EmailConfirmed".to_string());
+        assert_eq!(result.status_code, 403);
+        assert_eq!(body(&result), r#"{"result":-18,"data":"EmailConfirmed"}"#);
+    }
+
+    #[test]
+    fn result_with_description_layout() {
+        let factory = WlAuthErrorFactory::new(AuthErrorBodyLayout::ResultWithDescription);
+
+        let result = factory.get_not_authenticated();
+        assert_eq!(result.status_code, 401);
+        assert_eq!(
+            body(&result),
+            r#"{"result":-17,"description":"Authentication required"}"#
+        );
+
+        let result = factory.get_not_authorized("EmailConfirmed".to_string());
+        assert_eq!(result.status_code, 403);
+        assert_eq!(
+            body(&result),
+            r#"{"result":-18,"description":"Authorization required","claim":"EmailConfirmed"}"#
+        );
+    }
+
+    #[test]
+    fn legacy_compatibility_layout() {
+        let factory = WlAuthErrorFactory::new(AuthErrorBodyLayout::ResultWithDescription)
+            .with_legacy_compatibility();
+
+        let result = factory.get_not_authorized("EmailConfirmed".to_string());
+        assert_eq!(result.status_code, 401);
+        assert_eq!(
+            body(&result),
+            r#"{"result":-18,"description":"Authorization required"}"#
+        );
+
+        let factory = WlAuthErrorFactory::new(AuthErrorBodyLayout::ResultWithData)
+            .with_legacy_compatibility();
+
+        let result = factory.get_not_authorized("EmailConfirmed".to_string());
+        assert_eq!(result.status_code, 403);
+        assert_eq!(body(&result), r#"{"result":-18,"data":"EmailConfirmed"}"#);
+
+        let types = factory.get_global_http_fail_result_types().unwrap();
+        assert_eq!(types[0].description, "Unauthenticated access");
+        assert_eq!(types[1].description, "Unauthorized access");
+    }
+
+    #[test]
+    fn direct_responses_keep_status_codes() {
+        let result = AuthorizationFailedApiResponse::new(
+            ApiResultStatus::AccessClaimRequired,
+            AuthorizationFailedApiResponse::default_desc(),
+        );
+        assert_eq!(result.status_code, 401);
+
+        let result = AuthorizationFailedApiResponse::new_forbidden(
+            ApiResultStatus::AccessClaimRequired,
+            AuthorizationFailedApiResponse::default_desc(),
+            Some("A".to_string()),
+        );
+        assert_eq!(result.status_code, 403);
+    }
+
+    #[test]
+    fn custom_texts() {
+        let factory = WlAuthErrorFactory::new(AuthErrorBodyLayout::ResultWithDescription)
+            .with_texts(AuthErrorTexts {
+                not_authenticated: "Anmeldung erforderlich".to_string(),
+                not_authorized: "Berechtigung erforderlich".to_string(),
+            });
+
+        assert_eq!(
+            body(&factory.get_not_authenticated()),
+            r#"{"result":-17,"description":"Anmeldung erforderlich"}"#
+        );
+    }
+
+    #[test]
+    fn presets_match_layouts() {
+        assert_eq!(
+            body(&AuthErrorFactoryWl.get_not_authorized("A".to_string())),
+            r#"{"result":-18,"data":"A"}"#
+        );
+
+        let result = AuthFailResponseFactory.get_not_authorized("A".to_string());
+        assert_eq!(result.status_code, 401);
+        assert_eq!(
+            body(&result),
+            r#"{"result":-18,"description":"Authorization required"}"#
+        );
+
+        let types = AuthErrorFactoryWl
+            .get_global_http_fail_result_types()
+            .unwrap();
+        assert_eq!(types[1].description, "Unauthorized access");
     }
 }
@@ -3,6 +3,7 @@ service_sdk::macros::use_my_http_server!();
 use crate::ApiResultStatus;
 use my_http_server::HttpFailResult;
 use serde::Serialize;
+use service_sdk::my_http_server::macros::MyHttpObjectStructure;
 
 #[derive(Serialize, Debug, MyHttpObjectStructure)]
 pub struct AuthenticationFailedApiResponse {
@@ -18,18 +19,41 @@ pub struct AuthorizationFailedApiResponse {
     pub result: ApiResultStatus,
     #[serde(rename = "description")]
     pub description: String,
+    #[serde(rename = "claim")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claim: Option<String>,
 }
 
 impl AuthorizationFailedApiResponse {
+    /// 401 without the claim name, as returned before [`crate::WlAuthErrorFactory`]
     pub fn new(result: ApiResultStatus, description: String) -> HttpFailResult {
+        Self::new_with_claim(result, description, None, 401)
+    }
+
+    /// 403 with the name of the missing claim
+    pub fn new_forbidden(
+        result: ApiResultStatus,
+        description: String,
+        claim: Option<String>,
+    ) -> HttpFailResult {
+        Self::new_with_claim(result, description, claim, 403)
+    }
+
+    pub fn new_with_claim(
+        result: ApiResultStatus,
+        description: String,
+        claim: Option<String>,
+        status_code: u16,
+    ) -> HttpFailResult {
         let result = AuthorizationFailedApiResponse {
             result,
             description,
+            claim,
         };
 
         let content = serde_json::to_vec(&result).unwrap();
         HttpFailResult::new(my_http_server::WebContentType::Json,
-            401,
+            status_code,
             content,
             false,
             false)
@@ -59,47 +83,3 @@ impl AuthenticationFailedApiResponse {
         "Authentication required".to_string()
     }
 }
-
-use my_http_server::controllers::documentation::{
-    data_types::HttpDataType, out_results::HttpResult,
-};
-use service_sdk::my_http_server::macros::MyHttpObjectStructure;
-
-pub struct AuthFailResponseFactory;
-
-impl my_http_server::controllers::AuthErrorFactory for AuthFailResponseFactory {
-    fn get_not_authenticated(&self) -> my_http_server::HttpFailResult {
-        return AuthenticationFailedApiResponse::new(
-            ApiResultStatus::AccessTokenInvalid,
-            AuthenticationFailedApiResponse::default_desc(),
-        );
-    }
-
-    fn get_not_authorized(&self, _claim_name: String) -> my_http_server::HttpFailResult {
-        return AuthorizationFailedApiResponse::new(
-            ApiResultStatus::AccessClaimRequired,
-            AuthorizationFailedApiResponse::default_desc(),
-        );
-    }
-    fn get_global_http_fail_result_types(&self) -> Option<Vec<HttpResult>> {
-        let authentication_http_structure =
-            AuthenticationFailedApiResponse::get_http_data_structure();
-        let authorization_http_structure =
-            AuthorizationFailedApiResponse::get_http_data_structure();
-
-        Some(vec![
-            HttpResult {
-                http_code: 401,
-                nullable: false,
-                description: AuthenticationFailedApiResponse::default_desc(),
-                data_type: HttpDataType::Object(authentication_http_structure),
-            },
-            HttpResult {
-                http_code: 403,
-                nullable: false,
-                description: AuthorizationFailedApiResponse::default_desc(),
-                data_type: HttpDataType::Object(authorization_http_structure),
-            },
-        ])
-    }
-}
@@ -0,0 +1,316 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{ValidationFailure, CODE_INVALID_FORMAT};
+
+// Keeps any two amounts comparable in i128 after aligning their scales
+const MAX_INTEGER_DIGITS: usize = 18;
+const MAX_FRACTION_DIGITS: u32 = 18;
+
+/// Decimal amount of money. Parsed from the decimal text as is, so "0.1" stays exactly 0.1.
+///
+/// Stored as `mantissa / 10^scale` without trailing fraction zeros, so
+/// "10.50" and "10.5" are the same amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Amount {
+    mantissa: i128,
+    scale: u32,
+}
+
+impl Amount {
+    pub const ZERO: Amount = Amount {
+        mantissa: 0,
+        scale: 0,
+    };
+
+    /// `Amount::new(1050, 2)` is 10.50. Limits are the same as of [`Amount::parse`].
+    pub fn new(mantissa: i128, scale: u32) -> Result<Self, ValidationFailure> {
+        let result = Self { mantissa, scale }.normalize();
+
+        if result.scale > MAX_FRACTION_DIGITS
+            || result.mantissa.unsigned_abs() / 10_u128.pow(result.scale)
+                >= 10_u128.pow(MAX_INTEGER_DIGITS as u32)
+        {
+            return Err(get_too_many_digits_failure());
+        }
+
+        Ok(result)
+    }
+
+    pub fn parse(value: &str) -> Result<Self, ValidationFailure> {
+        let invalid = || ValidationFailure::new(CODE_INVALID_FORMAT, "Amount: Not a valid number");
+
+        let (is_negative, digits) = match value.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, value),
+        };
+
+        let (integer, fraction) = match digits.split_once('.') {
+            Some((integer, fraction)) => (integer, fraction),
+            None => (digits, ""),
+        };
+
+        if integer.is_empty()
+            || (digits.contains('.') && fraction.is_empty())
+            || !integer.bytes().all(|b| b.is_ascii_digit())
+            || !fraction.bytes().all(|b| b.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let integer = integer.trim_start_matches('0');
+        let fraction = fraction.trim_end_matches('0');
+
+        if integer.len() > MAX_INTEGER_DIGITS || fraction.len() > MAX_FRACTION_DIGITS as usize {
+            return Err(get_too_many_digits_failure());
+        }
+
+        let mut mantissa: i128 = 0;
+
+        for b in integer.bytes().chain(fraction.bytes()) {
+            mantissa = mantissa * 10 + (b - b'0') as i128;
+        }
+
+        if is_negative {
+            mantissa = -mantissa;
+        }
+
+        Ok(Self {
+            mantissa,
+            scale: fraction.len() as u32,
+        })
+    }
+
+    /// Amount in minor units of a currency: `Amount::from_minor_units(1050, 2)` is 10.50
+    pub fn from_minor_units(
+        minor_units: i64,
+        decimal_places: u32,
+    ) -> Result<Self, ValidationFailure> {
+        Self::new(minor_units as i128, decimal_places)
+    }
+
+    /// `None` if the amount has more decimal places than `decimal_places`
+    pub fn to_minor_units(&self, decimal_places: u32) -> Option<i64> {
+        if self.scale > decimal_places {
+            return None;
+        }
+
+        let value = self
+            .mantissa
+            .checked_mul(10_i128.checked_pow(decimal_places - self.scale)?)?;
+
+        i64::try_from(value).ok()
+    }
+
+    /// Number of digits after the decimal point, without trailing zeros
+    pub fn get_decimal_places(&self) -> u32 {
+        self.scale
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.mantissa == 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.mantissa < 0
+    }
+
+    pub fn is_positive(&self) -> bool {
+        self.mantissa > 0
+    }
+
+    fn normalize(mut self) -> Self {
+        if self.mantissa == 0 {
+            return Self::ZERO;
+        }
+
+        while self.scale > 0 && self.mantissa % 10 == 0 {
+            self.mantissa /= 10;
+            self.scale -= 1;
+        }
+
+        self
+    }
+
+    fn get_aligned_mantissa(&self, scale: u32) -> i128 {
+        self.mantissa * 10_i128.pow(scale - self.scale)
+    }
+}
+
+fn get_too_many_digits_failure() -> ValidationFailure {
+    ValidationFailure::new(
+        CODE_INVALID_FORMAT,
+        format!(
+            "Amount: Max {} integer and {} fraction digits are allowed",
+            MAX_INTEGER_DIGITS, MAX_FRACTION_DIGITS
+        ),
+    )
+}
+
+impl Ord for Amount {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let scale = self.scale.max(other.scale);
+        self.get_aligned_mantissa(scale)
+            .cmp(&other.get_aligned_mantissa(scale))
+    }
+}
+
+impl PartialOrd for Amount {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::fmt::Display for Amount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        let digits = self.mantissa.unsigned_abs().to_string();
+        let scale = self.scale as usize;
+
+        if scale == 0 {
+            return write!(f, "{}{}", sign, digits);
+        }
+
+        let digits = format!("{:0>width$}", digits, width = scale + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+
+        write!(f, "{}{}.{}", sign, integer, fraction)
+    }
+}
+
+impl FromStr for Amount {
+    type Err = ValidationFailure;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+/// Serialized as a string to keep the precision on the client side.
+impl Serialize for Amount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Accepts strings only. JSON numbers are read as floats by most parsers,
+/// so `0.1` could not be taken exactly.
+impl<'de> Deserialize<'de> for Amount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AmountVisitor;
+
+        impl de::Visitor<'_> for AmountVisitor {
+            type Value = Amount;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("decimal amount as a string")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Amount, E> {
+                Amount::parse(value).map_err(|failure| E::custom(failure.message))
+            }
+        }
+
+        deserializer.deserialize_str(AmountVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_decimal_text() {
+        assert_eq!(
+            Amount::parse("10.50").unwrap(),
+            Amount::new(105, 1).unwrap()
+        );
+        assert_eq!(Amount::parse("0010").unwrap(), Amount::new(10, 0).unwrap());
+        assert_eq!(Amount::parse("-0.01").unwrap(), Amount::new(-1, 2).unwrap());
+        assert_eq!(Amount::parse("0.000").unwrap(), Amount::ZERO);
+        assert_eq!(Amount::parse("10.50").unwrap().get_decimal_places(), 1);
+
+        for value in [
+            "", "-", ".5", "5.", "1,5", "1e3", "+1", " 1", "1.2.3", "NaN",
+        ] {
+            assert!(Amount::parse(value).is_err(), "{:?}", value);
+        }
+
+        assert!(Amount::parse(&"9".repeat(19)).is_err());
+        assert!(Amount::parse(&format!("0.{}", "1".repeat(19))).is_err());
+    }
+
+    #[test]
+    fn formats_without_trailing_zeros() {
+        for (value, expected) in [
+            ("10.50", "10.5"),
+            ("0.05", "0.05"),
+            ("-0.05", "-0.05"),
+            ("100", "100"),
+            ("100.00", "100"),
+            ("-12.345", "-12.345"),
+        ] {
+            assert_eq!(Amount::parse(value).unwrap().to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn compares_amounts_with_different_scales() {
+        let amount = |value: &str| Amount::parse(value).unwrap();
+
+        assert!(amount("10.5") > amount("10.49"));
+        assert!(amount("-1") < amount("0.001"));
+        assert_eq!(amount("10.50").cmp(&amount("10.5")), Ordering::Equal);
+        assert!(amount(&"9".repeat(18)) > amount(&format!("0.{}", "9".repeat(18))));
+    }
+
+    #[test]
+    fn converts_minor_units() {
+        let amount = Amount::parse("10.5").unwrap();
+
+        assert_eq!(amount.to_minor_units(2), Some(1050));
+        assert_eq!(amount.to_minor_units(0), None);
+        assert_eq!(Amount::from_minor_units(1050, 2).unwrap(), amount);
+        assert_eq!(
+            Amount::from_minor_units(i64::MAX, 2).unwrap().to_string(),
+            "92233720368547758.07"
+        );
+        assert!(Amount::from_minor_units(i64::MAX, 0).is_err());
+    }
+
+    #[test]
+    fn constructors_keep_parse_limits() {
+        assert_eq!(Amount::new(0, u32::MAX).unwrap(), Amount::ZERO);
+        assert_eq!(Amount::new(1050, 2).unwrap().get_decimal_places(), 1);
+        assert!(Amount::new(1, 19).is_err());
+        assert!(Amount::new(1, u32::MAX).is_err());
+        assert!(Amount::new(i128::MAX, 0).is_err());
+        assert!(Amount::new(i128::MIN, 18).is_err());
+
+        // Largest and smallest digits are still comparable
+        let largest = Amount::new(10_i128.pow(36) - 1, 18).unwrap();
+        let negative = Amount::new(-(10_i128.pow(18) - 1), 0).unwrap();
+        let smallest = Amount::new(1, 18).unwrap();
+        assert!(largest > smallest);
+        assert!(negative < smallest);
+    }
+
+    #[test]
+    fn json_strings_only() {
+        let parse = |json: &str| serde_json::from_str::<Amount>(json);
+
+        assert_eq!(parse("\"0.1\"").unwrap(), Amount::new(1, 1).unwrap());
+        assert_eq!(parse("\"-5\"").unwrap(), Amount::new(-5, 0).unwrap());
+
+        for json in ["0.1", "100", "1e400", "\"abc\"", "true"] {
+            assert!(parse(json).is_err(), "{}", json);
+        }
+
+        assert_eq!(
+            serde_json::to_string(&Amount::new(1050, 2).unwrap()).unwrap(),
+            "\"10.5\""
+        );
+    }
+}
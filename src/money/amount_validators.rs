@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+use service_sdk::my_http_server::{HttpContext, HttpFailResult};
+
+use super::{Amount, Currency};
+use crate::{into_http_fail, ApiError, ApiResultStatus, ValidationFailure, WithTraceId};
+
+pub const CODE_INVALID_PRECISION: &str = "invalid_precision";
+pub const CODE_NOT_POSITIVE: &str = "not_positive";
+
+/// Payload of [`ApiResultStatus::AmountLessThanMin`] and [`ApiResultStatus::AmountExceedsMax`].
+#[derive(Debug, Clone, Serialize)]
+pub struct AmountLimitData {
+    pub limit: Amount,
+    pub currency: &'static str,
+}
+
+/// Bounds are inclusive.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AmountLimits {
+    #[serde(default)]
+    pub min: Option<Amount>,
+    #[serde(default)]
+    pub max: Option<Amount>,
+}
+
+impl AmountLimits {
+    pub fn check(&self, amount: &Amount, currency: &Currency) -> Result<(), ApiError> {
+        if let Some(min) = self.min {
+            if *amount < min {
+                return Err(ApiError::new(ApiResultStatus::AmountLessThanMin).with_data(
+                    AmountLimitData {
+                        limit: min,
+                        currency: currency.code,
+                    },
+                ));
+            }
+        }
+
+        if let Some(max) = self.max {
+            if *amount > max {
+                return Err(ApiError::new(ApiResultStatus::AmountExceedsMax).with_data(
+                    AmountLimitData {
+                        limit: max,
+                        currency: currency.code,
+                    },
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Amount should not have more decimal places than the currency: 10.001 USD or 10.5 JPY are rejected.
+pub fn check_amount_precision(
+    amount: &Amount,
+    currency: &Currency,
+) -> Result<(), ValidationFailure> {
+    if amount.get_decimal_places() > currency.minor_units {
+        return Err(ValidationFailure::new(
+            CODE_INVALID_PRECISION,
+            format!(
+                "Amount: Max {} decimal places are allowed for {}",
+                currency.minor_units, currency.code
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn check_amount(
+    value: &str,
+    currency: &str,
+) -> Result<(Amount, &'static Currency), ValidationFailure> {
+    let currency = Currency::parse(currency)?;
+    let amount = Amount::parse(value)?;
+
+    if !amount.is_positive() {
+        return Err(ValidationFailure::new(
+            CODE_NOT_POSITIVE,
+            "Amount: Should be greater than zero",
+        ));
+    }
+
+    check_amount_precision(&amount, currency)?;
+
+    Ok((amount, currency))
+}
+
+/// Format and precision errors are returned as [`ApiResultStatus::RequestIsNoValid`],
+/// limit violations as [`ApiResultStatus::AmountLessThanMin`]/[`ApiResultStatus::AmountExceedsMax`]
+/// with [`AmountLimitData`].
+pub fn validate_amount(
+    ctx: &HttpContext,
+    value: &str,
+    currency: &str,
+    limits: &AmountLimits,
+) -> Result<Amount, HttpFailResult> {
+    let (amount, currency) = into_http_fail(ctx, check_amount(value, currency))?;
+
+    limits
+        .check(&amount, currency)
+        .map_err(|err| HttpFailResult::from(err).with_trace_id(ctx))?;
+
+    Ok(amount)
+}
+
+pub fn validate_amount_optional(
+    ctx: &HttpContext,
+    value: &Option<String>,
+    currency: &str,
+    limits: &AmountLimits,
+) -> Result<Option<Amount>, HttpFailResult> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+
+    validate_amount(ctx, value, currency, limits).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn amount(value: &str) -> Amount {
+        Amount::parse(value).unwrap()
+    }
+
+    #[test]
+    fn precision_depends_on_currency() {
+        let usd = Currency::parse("USD").unwrap();
+        let jpy = Currency::parse("JPY").unwrap();
+        let kwd = Currency::parse("KWD").unwrap();
+
+        assert!(check_amount_precision(&amount("10.50"), usd).is_ok());
+        assert!(check_amount_precision(&amount("10.500"), usd).is_ok());
+        assert_eq!(
+            check_amount_precision(&amount("10.001"), usd)
+                .unwrap_err()
+                .code,
+            CODE_INVALID_PRECISION
+        );
+        assert!(check_amount_precision(&amount("1000"), jpy).is_ok());
+        assert!(check_amount_precision(&amount("1000.5"), jpy).is_err());
+        assert!(check_amount_precision(&amount("1.005"), kwd).is_ok());
+    }
+
+    #[test]
+    fn check_amount_requires_positive_value_and_known_currency() {
+        assert!(check_amount("10.5", "USD").is_ok());
+        assert_eq!(
+            check_amount("0", "USD").unwrap_err().code,
+            CODE_NOT_POSITIVE
+        );
+        assert_eq!(
+            check_amount("-1", "USD").unwrap_err().code,
+            CODE_NOT_POSITIVE
+        );
+        assert!(check_amount("10", "usd").is_err());
+        assert!(check_amount("1e3", "USD").is_err());
+    }
+
+    #[test]
+    fn limits_return_statuses_with_limit_data() {
+        let usd = Currency::parse("USD").unwrap();
+        let limits: AmountLimits = serde_json::from_str(r#"{"min":"10","max":"1000.5"}"#).unwrap();
+
+        assert!(limits.check(&amount("10"), usd).is_ok());
+        assert!(limits.check(&amount("1000.50"), usd).is_ok());
+
+        let err = limits.check(&amount("9.99"), usd).unwrap_err();
+        assert!(matches!(err.status, ApiResultStatus::AmountLessThanMin));
+        assert_eq!(
+            err.data.unwrap(),
+            serde_json::json!({"limit": "10", "currency": "USD"})
+        );
+
+        let err = limits.check(&amount("1000.51"), usd).unwrap_err();
+        assert!(matches!(err.status, ApiResultStatus::AmountExceedsMax));
+        assert_eq!(
+            err.data.unwrap(),
+            serde_json::json!({"limit": "1000.5", "currency": "USD"})
+        );
+
+        assert!(AmountLimits::default()
+            .check(&amount("1000000"), usd)
+            .is_ok());
+    }
+}
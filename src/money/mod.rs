@@ -0,0 +1,6 @@
+mod amount;
+pub use amount::*;
+mod currency;
+pub use currency::*;
+mod amount_validators;
+pub use amount_validators::*;
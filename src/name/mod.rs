@@ -0,0 +1,2 @@
+mod name_policy;
+pub use name_policy::*;
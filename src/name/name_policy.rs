@@ -0,0 +1,404 @@
+use serde::{Deserialize, Serialize};
+use service_sdk::my_http_server::{HttpContext, HttpFailResult};
+use unicode_normalization::UnicodeNormalization;
+use unicode_script::{Script, UnicodeScript};
+
+use crate::{
+    check_name_with_max_len, into_http_fail, BrandPolicies, ValidationFailure, CODE_EMPTY,
+    CODE_INVALID_FORMAT, CODE_MAX_LENGTH, CODE_TRIM_SPACES,
+};
+
+pub const CODE_NOT_ALLOWED_SCRIPT: &str = "not_allowed_script";
+pub const CODE_MIXED_SCRIPTS: &str = "mixed_scripts";
+
+const APOSTROPHES: [char; 3] = ['\'', '\u{2019}', '\u{02BC}'];
+const HYPHENS: [char; 2] = ['-', '\u{2010}'];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NameScript {
+    Latin,
+    Cyrillic,
+    Greek,
+    Armenian,
+    Georgian,
+    Hebrew,
+    Arabic,
+    Devanagari,
+    Thai,
+    Hangul,
+    Han,
+    Hiragana,
+    Katakana,
+}
+
+impl NameScript {
+    fn from_script(script: Script) -> Option<Self> {
+        let result = match script {
+            Script::Latin => NameScript::Latin,
+            Script::Cyrillic => NameScript::Cyrillic,
+            Script::Greek => NameScript::Greek,
+            Script::Armenian => NameScript::Armenian,
+            Script::Georgian => NameScript::Georgian,
+            Script::Hebrew => NameScript::Hebrew,
+            Script::Arabic => NameScript::Arabic,
+            Script::Devanagari => NameScript::Devanagari,
+            Script::Thai => NameScript::Thai,
+            Script::Hangul => NameScript::Hangul,
+            Script::Han => NameScript::Han,
+            Script::Hiragana => NameScript::Hiragana,
+            Script::Katakana => NameScript::Katakana,
+            _ => return None,
+        };
+
+        Some(result)
+    }
+
+    // Japanese names are written with Han, Hiragana and Katakana together
+    fn is_japanese(&self) -> bool {
+        matches!(
+            self,
+            NameScript::Han | NameScript::Hiragana | NameScript::Katakana
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NamePolicy {
+    /// Keeps the old behaviour: latin letters, hyphens and spaces only
+    #[serde(rename = "asciiOnly")]
+    pub ascii_only: bool,
+    #[serde(rename = "allowedScripts")]
+    pub allowed_scripts: Vec<NameScript>,
+    #[serde(rename = "maxLength")]
+    pub max_length: usize,
+    /// Allows e.g. "Łukasz Иванов", different scripts inside one word are never allowed
+    #[serde(rename = "allowMixedScripts")]
+    pub allow_mixed_scripts: bool,
+}
+
+impl Default for NamePolicy {
+    fn default() -> Self {
+        Self {
+            ascii_only: false,
+            allowed_scripts: vec![NameScript::Latin],
+            max_length: 32,
+            allow_mixed_scripts: false,
+        }
+    }
+}
+
+impl NamePolicy {
+    pub fn ascii_only() -> Self {
+        Self {
+            ascii_only: true,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the name in NFC form.
+    pub fn check(&self, value: &str) -> Result<String, ValidationFailure> {
+        if self.ascii_only {
+            check_name_with_max_len(value, self.max_length)?;
+            return Ok(value.to_string());
+        }
+
+        let value: String = value.nfc().collect();
+
+        if value.is_empty() {
+            return Err(ValidationFailure::new(
+                CODE_EMPTY,
+                "Name: Should not be empty",
+            ));
+        }
+
+        if value.chars().count() > self.max_length {
+            return Err(ValidationFailure::new(
+                CODE_MAX_LENGTH,
+                format!("Name: Max length is {} symbols", self.max_length),
+            ));
+        }
+
+        if value.trim() != value {
+            return Err(ValidationFailure::new(
+                CODE_TRIM_SPACES,
+                "Should not start or end with space",
+            ));
+        }
+
+        check_name_symbols(&value)?;
+        self.check_scripts(&value)?;
+
+        Ok(value)
+    }
+
+    fn check_scripts(&self, value: &str) -> Result<(), ValidationFailure> {
+        let mut name_scripts = Vec::new();
+
+        for word in value.split(|c: char| is_name_separator(c)) {
+            let word_scripts = get_scripts(word)?;
+
+            for script in &word_scripts {
+                if !self.allowed_scripts.contains(script) {
+                    return Err(ValidationFailure::new(
+                        CODE_NOT_ALLOWED_SCRIPT,
+                        "Name: Letters of this alphabet are not allowed",
+                    ));
+                }
+            }
+
+            // Mixing alphabets inside a word is how look-alike names are built ("Pаvel" with cyrillic "а")
+            if is_mixed(&word_scripts) {
+                return Err(ValidationFailure::new(
+                    CODE_MIXED_SCRIPTS,
+                    "Name: Letters of different alphabets are mixed",
+                ));
+            }
+
+            for script in word_scripts {
+                if !name_scripts.contains(&script) {
+                    name_scripts.push(script);
+                }
+            }
+        }
+
+        if !self.allow_mixed_scripts && is_mixed(&name_scripts) {
+            return Err(ValidationFailure::new(
+                CODE_MIXED_SCRIPTS,
+                "Name: Letters of different alphabets are mixed",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn is_name_separator(c: char) -> bool {
+    c == ' ' || APOSTROPHES.contains(&c) || HYPHENS.contains(&c)
+}
+
+fn check_name_symbols(value: &str) -> Result<(), ValidationFailure> {
+    let mut prev_is_separator = true;
+
+    for c in value.chars() {
+        if is_name_separator(c) {
+            if prev_is_separator {
+                return Err(ValidationFailure::new(
+                    CODE_INVALID_FORMAT,
+                    "Name: Spaces, hyphens and apostrophes should be between letters",
+                ));
+            }
+
+            prev_is_separator = true;
+            continue;
+        }
+
+        let is_combining_mark = c.script() == Script::Inherited;
+
+        if !c.is_alphabetic() && !is_combining_mark {
+            return Err(ValidationFailure::new(
+                CODE_INVALID_FORMAT,
+                "Name: Only letters, spaces, hyphens and apostrophes are allowed",
+            ));
+        }
+
+        prev_is_separator = false;
+    }
+
+    if prev_is_separator {
+        return Err(ValidationFailure::new(
+            CODE_INVALID_FORMAT,
+            "Name: Spaces, hyphens and apostrophes should be between letters",
+        ));
+    }
+
+    Ok(())
+}
+
+fn get_scripts(word: &str) -> Result<Vec<NameScript>, ValidationFailure> {
+    let mut result = Vec::new();
+
+    for c in word.chars() {
+        let script = c.script();
+
+        if script == Script::Common || script == Script::Inherited {
+            continue;
+        }
+
+        let Some(script) = NameScript::from_script(script) else {
+            return Err(ValidationFailure::new(
+                CODE_NOT_ALLOWED_SCRIPT,
+                "Name: Letters of this alphabet are not allowed",
+            ));
+        };
+
+        if !result.contains(&script) {
+            result.push(script);
+        }
+    }
+
+    Ok(result)
+}
+
+fn is_mixed(scripts: &[NameScript]) -> bool {
+    if scripts.len() <= 1 {
+        return false;
+    }
+
+    !scripts.iter().all(|script| script.is_japanese())
+}
+
+pub type NamePolicies = BrandPolicies<NamePolicy>;
+
+/// Returns the name in NFC form, it is the one to store.
+pub fn validate_name_with_policy(
+    ctx: &HttpContext,
+    value: &str,
+    policy: &NamePolicy,
+) -> Result<String, HttpFailResult> {
+    into_http_fail(ctx, policy.check(value))
+}
+
+pub fn validate_name_with_policy_optional(
+    ctx: &HttpContext,
+    value: &Option<String>,
+    policy: &NamePolicy,
+) -> Result<Option<String>, HttpFailResult> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+
+    validate_name_with_policy(ctx, value, policy).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_latin_extended_names() {
+        let policy = NamePolicy::default();
+
+        for name in [
+            "José",
+            "Zoë",
+            "O'Brien",
+            "Łukasz",
+            "Jean-Luc",
+            "D’Angelo",
+            "Mary Ann",
+        ] {
+            assert!(policy.check(name).is_ok(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn normalizes_to_nfc() {
+        let policy = NamePolicy::default();
+
+        // "e" + combining acute accent
+        let result = policy.check("Jose\u{0301}").unwrap();
+        assert_eq!(result, "José");
+        assert_eq!(result.chars().count(), 4);
+    }
+
+    #[test]
+    fn rejects_bad_symbols() {
+        let policy = NamePolicy::default();
+
+        for name in [
+            "John1",
+            "John_Doe",
+            "John  Doe",
+            "-John",
+            "John-",
+            "O''Brien",
+            " John",
+            "J@ne",
+        ] {
+            assert!(policy.check(name).is_err(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn checks_allowed_scripts() {
+        let latin = NamePolicy::default();
+        assert_eq!(
+            latin.check("Иван").unwrap_err().code,
+            CODE_NOT_ALLOWED_SCRIPT
+        );
+
+        let cyrillic = NamePolicy {
+            allowed_scripts: vec![NameScript::Latin, NameScript::Cyrillic],
+            ..Default::default()
+        };
+        assert!(cyrillic.check("Иван").is_ok());
+        assert_eq!(
+            cyrillic.check("Ivan Петров").unwrap_err().code,
+            CODE_MIXED_SCRIPTS
+        );
+
+        let japanese = NamePolicy {
+            allowed_scripts: vec![NameScript::Han, NameScript::Hiragana, NameScript::Katakana],
+            ..Default::default()
+        };
+        assert!(japanese.check("山田 はなこ").is_ok());
+    }
+
+    #[test]
+    fn rejects_confusable_words_even_when_mixing_is_allowed() {
+        let policy = NamePolicy {
+            allowed_scripts: vec![NameScript::Latin, NameScript::Cyrillic],
+            allow_mixed_scripts: true,
+            ..Default::default()
+        };
+
+        assert!(policy.check("Ivan Петров").is_ok());
+        // cyrillic "а" inside latin word
+        assert_eq!(
+            policy.check("P\u{0430}vel").unwrap_err().code,
+            CODE_MIXED_SCRIPTS
+        );
+    }
+
+    #[test]
+    fn ascii_only_mode_keeps_old_rules() {
+        let policy = NamePolicy::ascii_only();
+
+        assert!(policy.check("Jhon Doe").is_ok());
+        assert!(policy.check("José").is_err());
+    }
+
+    #[test]
+    fn ascii_only_mode_applies_max_length() {
+        let long_name = "Maximilian Alexander Montgomery-Smith";
+
+        assert!(NamePolicy::ascii_only().check(long_name).is_err());
+
+        let policy = NamePolicy {
+            max_length: 40,
+            ..NamePolicy::ascii_only()
+        };
+        assert_eq!(policy.check(long_name).unwrap(), long_name);
+
+        let policy = NamePolicy {
+            max_length: 8,
+            ..NamePolicy::ascii_only()
+        };
+        assert_eq!(
+            policy.check("Jhon Doe Jr").unwrap_err().code,
+            CODE_MAX_LENGTH
+        );
+    }
+
+    #[test]
+    fn brand_policy_falls_back_to_default() {
+        let policies: NamePolicies = serde_json::from_str(
+            r#"{"brands":{"brand-ua":{"allowedScripts":["Latin","Cyrillic"]}}}"#,
+        )
+        .unwrap();
+
+        assert!(policies.get("brand-ua").check("Олена").is_ok());
+        assert!(policies.get("brand-de").check("Олена").is_err());
+    }
+}
@@ -0,0 +1,8 @@
+mod one_time_code;
+pub use one_time_code::*;
+
+mod one_time_code_store;
+pub use one_time_code_store::*;
+
+mod totp;
+pub use totp::*;
@@ -0,0 +1,333 @@
+use hmac::{Hmac, Mac};
+use rand_core::{OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use service_sdk::rust_extensions::date_time::DateTimeAsMicroseconds;
+use sha2::Sha256;
+
+use crate::{ApiError, ApiResultStatus};
+
+const MICROSECONDS_IN_SECOND: i64 = 1_000_000;
+
+/// Email, SMS and 2FA code settings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OneTimeCodePolicy {
+    pub length: u32,
+    pub ttl_seconds: u32,
+    /// Wrong codes allowed before the lockout
+    pub max_attempts: u32,
+    pub lockout_seconds: u32,
+}
+
+impl Default for OneTimeCodePolicy {
+    fn default() -> Self {
+        Self {
+            length: 6,
+            ttl_seconds: 5 * 60,
+            max_attempts: 5,
+            lockout_seconds: 15 * 60,
+        }
+    }
+}
+
+/// Payload of [`ApiResultStatus::InvalidCodeEntered`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeAttemptsData {
+    pub attempts_left: u32,
+}
+
+/// Payload of [`ApiResultStatus::InvalidCode`] when too many wrong codes were entered.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeLockoutData {
+    /// Unix timestamp in seconds
+    pub locked_till: i64,
+}
+
+/// Issued code as it is stored. The code itself is not stored, only its hash.
+///
+/// Timestamps are unix microseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OneTimeCodeRecord {
+    pub code_hash: String,
+    pub expires_at: i64,
+    pub attempts: u32,
+    #[serde(default)]
+    pub locked_till: Option<i64>,
+}
+
+impl OneTimeCodeRecord {
+    /// Returns the record and the code to send to the client.
+    /// `secret_key` is the server secret the code hash is keyed with.
+    pub fn issue(
+        key: &str,
+        secret_key: &[u8],
+        policy: &OneTimeCodePolicy,
+        now: DateTimeAsMicroseconds,
+    ) -> (Self, String) {
+        let code = generate_numeric_code(policy.length);
+
+        let record = Self {
+            code_hash: get_code_hash(secret_key, key, &code),
+            expires_at: now.unix_microseconds + policy.ttl_seconds as i64 * MICROSECONDS_IN_SECOND,
+            attempts: 0,
+            locked_till: None,
+        };
+
+        (record, code)
+    }
+
+    pub fn is_locked(&self, now: DateTimeAsMicroseconds) -> bool {
+        matches!(self.locked_till, Some(locked_till) if now.unix_microseconds < locked_till)
+    }
+
+    pub fn is_expired(&self, now: DateTimeAsMicroseconds) -> bool {
+        now.unix_microseconds >= self.expires_at
+    }
+
+    /// Counts the attempt in the record, so it should be saved back on
+    /// [`ApiResultStatus::InvalidCodeEntered`] and on lockout.
+    ///
+    /// - wrong code: [`ApiResultStatus::InvalidCodeEntered`] with [`CodeAttemptsData`];
+    /// - last allowed wrong code or locked record: [`ApiResultStatus::InvalidCode`] with [`CodeLockoutData`];
+    /// - expired code: [`ApiResultStatus::InvalidCode`].
+    pub fn verify(
+        &mut self,
+        key: &str,
+        code: &str,
+        secret_key: &[u8],
+        policy: &OneTimeCodePolicy,
+        now: DateTimeAsMicroseconds,
+    ) -> Result<(), ApiError> {
+        self.check_not_locked(now)?;
+
+        if self.is_expired(now) {
+            return Err(ApiError::new(ApiResultStatus::InvalidCode));
+        }
+
+        if constant_time_eq(
+            self.code_hash.as_bytes(),
+            get_code_hash(secret_key, key, code).as_bytes(),
+        ) {
+            return Ok(());
+        }
+
+        self.attempts += 1;
+
+        if self.attempts >= policy.max_attempts {
+            self.locked_till = Some(
+                now.unix_microseconds + policy.lockout_seconds as i64 * MICROSECONDS_IN_SECOND,
+            );
+            return Err(self.get_lockout_error());
+        }
+
+        Err(
+            ApiError::new(ApiResultStatus::InvalidCodeEntered).with_data(CodeAttemptsData {
+                attempts_left: policy.max_attempts - self.attempts,
+            }),
+        )
+    }
+
+    /// [`ApiResultStatus::InvalidCode`] with [`CodeLockoutData`] while locked out
+    pub fn check_not_locked(&self, now: DateTimeAsMicroseconds) -> Result<(), ApiError> {
+        if self.is_locked(now) {
+            return Err(self.get_lockout_error());
+        }
+
+        Ok(())
+    }
+
+    fn get_lockout_error(&self) -> ApiError {
+        ApiError::new(ApiResultStatus::InvalidCode).with_data(CodeLockoutData {
+            locked_till: self.locked_till.unwrap_or_default() / MICROSECONDS_IN_SECOND,
+        })
+    }
+}
+
+/// Uniformly distributed digits from the OS random generator. Leading zeros are kept.
+pub fn generate_numeric_code(length: u32) -> String {
+    // Largest multiple of 10 below u32::MAX, so that every digit is equally likely
+    const LIMIT: u32 = u32::MAX - u32::MAX % 10;
+
+    let mut code = String::with_capacity(length as usize);
+
+    while code.len() < length as usize {
+        let value = OsRng.next_u32();
+
+        if value < LIMIT {
+            code.push((b'0' + (value % 10) as u8) as char);
+        }
+    }
+
+    code
+}
+
+/// Compares secrets in time which does not depend on the position of the first difference.
+pub fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+
+    left.iter()
+        .zip(right)
+        .fold(0u8, |diff, (l, r)| diff | (l ^ r))
+        == 0
+}
+
+// Short codes are brute-forced from a plain hash in no time, so it is keyed with the server
+// secret. The record key is hashed too, so equal codes of different clients differ.
+fn get_code_hash(secret_key: &[u8], key: &str, code: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret_key).expect("HMAC accepts keys of any length");
+    mac.update(key.as_bytes());
+    mac.update(b":");
+    mac.update(code.trim().as_bytes());
+
+    mac.finalize()
+        .into_bytes()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "email-confirmation:client-1";
+    const SECRET_KEY: &[u8] = b"server-secret";
+    const NOW: i64 = 1_718_409_600_000_000;
+
+    fn time(unix_microseconds: i64) -> DateTimeAsMicroseconds {
+        DateTimeAsMicroseconds { unix_microseconds }
+    }
+
+    fn get_wrong_code(code: &str) -> String {
+        if code == "000000" { "000001" } else { "000000" }.to_string()
+    }
+
+    #[test]
+    fn generates_numeric_codes() {
+        for length in [4, 6, 8] {
+            let code = generate_numeric_code(length);
+
+            assert_eq!(code.len(), length as usize);
+            assert!(code.bytes().all(|b| b.is_ascii_digit()));
+        }
+
+        assert_ne!(generate_numeric_code(32), generate_numeric_code(32));
+    }
+
+    #[test]
+    fn compares_in_constant_time() {
+        assert!(constant_time_eq(b"123456", b"123456"));
+        assert!(!constant_time_eq(b"123456", b"123457"));
+        assert!(!constant_time_eq(b"123456", b"12345"));
+    }
+
+    #[test]
+    fn verifies_issued_code() {
+        let policy = OneTimeCodePolicy::default();
+        let (mut record, code) = OneTimeCodeRecord::issue(KEY, SECRET_KEY, &policy, time(NOW));
+
+        assert!(!record.code_hash.contains(&code));
+        assert!(record
+            .verify(KEY, &code, SECRET_KEY, &policy, time(NOW))
+            .is_ok());
+
+        let err = record
+            .verify(
+                "email-confirmation:client-2",
+                &code,
+                SECRET_KEY,
+                &policy,
+                time(NOW),
+            )
+            .unwrap_err();
+        assert!(matches!(err.status, ApiResultStatus::InvalidCodeEntered));
+
+        let err = record
+            .verify(KEY, &code, b"other-secret", &policy, time(NOW))
+            .unwrap_err();
+        assert!(matches!(err.status, ApiResultStatus::InvalidCodeEntered));
+    }
+
+    #[test]
+    fn code_expires() {
+        let policy = OneTimeCodePolicy::default();
+        let (mut record, code) = OneTimeCodeRecord::issue(KEY, SECRET_KEY, &policy, time(NOW));
+        let expires_at = NOW + 300 * MICROSECONDS_IN_SECOND;
+
+        assert!(record
+            .verify(KEY, &code, SECRET_KEY, &policy, time(expires_at - 1))
+            .is_ok());
+
+        let err = record
+            .verify(KEY, &code, SECRET_KEY, &policy, time(expires_at))
+            .unwrap_err();
+        assert!(matches!(err.status, ApiResultStatus::InvalidCode));
+        assert!(err.data.is_none());
+    }
+
+    #[test]
+    fn counts_attempts_and_locks_out() {
+        let policy = OneTimeCodePolicy {
+            max_attempts: 3,
+            ..Default::default()
+        };
+        let (mut record, code) = OneTimeCodeRecord::issue(KEY, SECRET_KEY, &policy, time(NOW));
+        let wrong_code = get_wrong_code(&code);
+
+        for attempts_left in [2, 1] {
+            let err = record
+                .verify(KEY, &wrong_code, SECRET_KEY, &policy, time(NOW))
+                .unwrap_err();
+            assert!(matches!(err.status, ApiResultStatus::InvalidCodeEntered));
+            assert_eq!(
+                err.data.unwrap(),
+                serde_json::json!({ "attemptsLeft": attempts_left })
+            );
+        }
+
+        let locked_till = NOW / MICROSECONDS_IN_SECOND + 15 * 60;
+
+        let err = record
+            .verify(KEY, &wrong_code, SECRET_KEY, &policy, time(NOW))
+            .unwrap_err();
+        assert!(matches!(err.status, ApiResultStatus::InvalidCode));
+        assert_eq!(
+            err.data.unwrap(),
+            serde_json::json!({ "lockedTill": locked_till })
+        );
+
+        // The right code does not help while locked
+        let err = record
+            .verify(KEY, &code, SECRET_KEY, &policy, time(NOW))
+            .unwrap_err();
+        assert!(matches!(err.status, ApiResultStatus::InvalidCode));
+        assert!(record.is_locked(time(locked_till * MICROSECONDS_IN_SECOND - 1)));
+        assert!(!record.is_locked(time(locked_till * MICROSECONDS_IN_SECOND)));
+    }
+
+    #[test]
+    fn record_is_stored_as_json() {
+        let record = OneTimeCodeRecord {
+            code_hash: "hash".to_string(),
+            expires_at: NOW,
+            attempts: 1,
+            locked_till: None,
+        };
+
+        let json = serde_json::to_string(&record).unwrap();
+        assert_eq!(
+            json,
+            r#"{"codeHash":"hash","expiresAt":1718409600000000,"attempts":1,"lockedTill":null}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<OneTimeCodeRecord>(&json).unwrap(),
+            record
+        );
+    }
+}
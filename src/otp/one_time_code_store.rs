@@ -0,0 +1,399 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use service_sdk::rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use crate::{ApiError, ApiResultStatus, OneTimeCodePolicy, OneTimeCodeRecord};
+
+// Every failed compare-and-swap means another request has changed the record
+const MAX_CONCURRENT_UPDATES: usize = 10;
+
+/// Storage of issued codes. Keys are chosen by services, e.g. `"email-confirmation:{client_id}"`.
+#[async_trait::async_trait]
+pub trait OneTimeCodeStore: Send + Sync {
+    async fn get(&self, key: &str) -> Result<Option<OneTimeCodeRecord>, ApiError>;
+
+    /// Atomically writes `new` if the stored record is still equal to `current`,
+    /// `None` stands for no record on both sides. Returns `false` without writing
+    /// when a concurrent request has changed the record.
+    async fn compare_and_swap(
+        &self,
+        key: &str,
+        current: Option<&OneTimeCodeRecord>,
+        new: Option<OneTimeCodeRecord>,
+    ) -> Result<bool, ApiError>;
+}
+
+/// Store for tests and single instance services.
+#[derive(Default)]
+pub struct InMemoryOneTimeCodeStore {
+    records: Mutex<HashMap<String, OneTimeCodeRecord>>,
+}
+
+impl InMemoryOneTimeCodeStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl OneTimeCodeStore for InMemoryOneTimeCodeStore {
+    async fn get(&self, key: &str) -> Result<Option<OneTimeCodeRecord>, ApiError> {
+        Ok(self.records.lock().unwrap().get(key).cloned())
+    }
+
+    async fn compare_and_swap(
+        &self,
+        key: &str,
+        current: Option<&OneTimeCodeRecord>,
+        new: Option<OneTimeCodeRecord>,
+    ) -> Result<bool, ApiError> {
+        let mut records = self.records.lock().unwrap();
+
+        if records.get(key) != current {
+            return Ok(false);
+        }
+
+        match new {
+            Some(record) => records.insert(key.to_string(), record),
+            None => records.remove(key),
+        };
+
+        Ok(true)
+    }
+}
+
+/// Issues and verifies one-time codes kept in a [`OneTimeCodeStore`].
+///
+/// Records are updated with compare-and-swap, so concurrent requests can not
+/// use a code twice or skip counting wrong attempts.
+pub struct OneTimeCodes<TStore: OneTimeCodeStore> {
+    policy: OneTimeCodePolicy,
+    store: TStore,
+    secret_key: Vec<u8>,
+}
+
+impl<TStore: OneTimeCodeStore> OneTimeCodes<TStore> {
+    /// `secret_key` keys the code hashes, it should be a random server secret of 32 bytes or more.
+    pub fn new(policy: OneTimeCodePolicy, store: TStore, secret_key: Vec<u8>) -> Self {
+        Self {
+            policy,
+            store,
+            secret_key,
+        }
+    }
+
+    pub fn get_policy(&self) -> &OneTimeCodePolicy {
+        &self.policy
+    }
+
+    /// Replaces the previously issued code. Returns [`ApiResultStatus::InvalidCode`]
+    /// while the key is locked out, so the lockout can not be reset by requesting a new code.
+    /// Wrong attempts of a not expired code are carried over to the new one for the same reason.
+    pub async fn issue(&self, key: &str, now: DateTimeAsMicroseconds) -> Result<String, ApiError> {
+        for _ in 0..MAX_CONCURRENT_UPDATES {
+            let current = self.store.get(key).await?;
+
+            if let Some(record) = &current {
+                record.check_not_locked(now)?;
+            }
+
+            let (mut record, code) =
+                OneTimeCodeRecord::issue(key, &self.secret_key, &self.policy, now);
+
+            if let Some(current) = &current {
+                if !current.is_expired(now) && current.locked_till.is_none() {
+                    record.attempts = current.attempts;
+                }
+            }
+
+            if self
+                .store
+                .compare_and_swap(key, current.as_ref(), Some(record))
+                .await?
+            {
+                return Ok(code);
+            }
+        }
+
+        Err(get_concurrent_updates_error(key))
+    }
+
+    /// A code can be used once. See [`OneTimeCodeRecord::verify`] for the returned errors.
+    pub async fn verify(
+        &self,
+        key: &str,
+        code: &str,
+        now: DateTimeAsMicroseconds,
+    ) -> Result<(), ApiError> {
+        for _ in 0..MAX_CONCURRENT_UPDATES {
+            let Some(current) = self.store.get(key).await? else {
+                return Err(ApiError::new(ApiResultStatus::InvalidCode));
+            };
+
+            let mut record = current.clone();
+            let result = record.verify(key, code, &self.secret_key, &self.policy, now);
+
+            let new = match &result {
+                Ok(()) => None,
+                Err(_) if record.is_expired(now) && !record.is_locked(now) => None,
+                // Locked, nothing to write
+                Err(_) if record == current => return result,
+                Err(_) => Some(record),
+            };
+
+            if self
+                .store
+                .compare_and_swap(key, Some(&current), new)
+                .await?
+            {
+                return result;
+            }
+        }
+
+        Err(get_concurrent_updates_error(key))
+    }
+}
+
+fn get_concurrent_updates_error(key: &str) -> ApiError {
+    ApiError::internal(format!(
+        "One-time code {} is changed by concurrent requests, gave up after {} retries",
+        key, MAX_CONCURRENT_UPDATES
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::Future;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    use super::*;
+
+    const KEY: &str = "sms-confirmation:client-1";
+    const NOW: i64 = 1_718_409_600_000_000;
+
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    // In-memory store never suspends, so a single poll completes the future
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = std::pin::pin!(future);
+        let waker = Waker::from(Arc::new(NoopWaker));
+
+        match future.as_mut().poll(&mut Context::from_waker(&waker)) {
+            Poll::Ready(result) => result,
+            Poll::Pending => panic!("In-memory store should not suspend"),
+        }
+    }
+
+    fn time(seconds: i64) -> DateTimeAsMicroseconds {
+        DateTimeAsMicroseconds {
+            unix_microseconds: NOW + seconds * 1_000_000,
+        }
+    }
+
+    fn get_codes() -> OneTimeCodes<InMemoryOneTimeCodeStore> {
+        let policy = OneTimeCodePolicy {
+            max_attempts: 2,
+            ..Default::default()
+        };
+
+        OneTimeCodes::new(
+            policy,
+            InMemoryOneTimeCodeStore::new(),
+            b"server-secret".to_vec(),
+        )
+    }
+
+    // Applies a write of another request right before the first compare-and-swap
+    #[derive(Default)]
+    struct ConcurrentStore {
+        store: InMemoryOneTimeCodeStore,
+        concurrent_write: Mutex<Option<Option<OneTimeCodeRecord>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl OneTimeCodeStore for ConcurrentStore {
+        async fn get(&self, key: &str) -> Result<Option<OneTimeCodeRecord>, ApiError> {
+            self.store.get(key).await
+        }
+
+        async fn compare_and_swap(
+            &self,
+            key: &str,
+            current: Option<&OneTimeCodeRecord>,
+            new: Option<OneTimeCodeRecord>,
+        ) -> Result<bool, ApiError> {
+            let concurrent_write = self.concurrent_write.lock().unwrap().take();
+
+            if let Some(record) = concurrent_write {
+                let stored = self.store.get(key).await?;
+                self.store
+                    .compare_and_swap(key, stored.as_ref(), record)
+                    .await?;
+            }
+
+            self.store.compare_and_swap(key, current, new).await
+        }
+    }
+
+    fn get_concurrent_codes() -> OneTimeCodes<ConcurrentStore> {
+        let policy = OneTimeCodePolicy {
+            max_attempts: 2,
+            ..Default::default()
+        };
+
+        OneTimeCodes::new(
+            policy,
+            ConcurrentStore::default(),
+            b"server-secret".to_vec(),
+        )
+    }
+
+    fn get_status(result: Result<(), ApiError>) -> i16 {
+        result.unwrap_err().status as i16
+    }
+
+    #[test]
+    fn code_is_used_once() {
+        let codes = get_codes();
+        let code = block_on(codes.issue(KEY, time(0))).unwrap();
+
+        assert!(block_on(codes.verify(KEY, &code, time(1))).is_ok());
+        assert_eq!(
+            get_status(block_on(codes.verify(KEY, &code, time(2)))),
+            ApiResultStatus::InvalidCode as i16
+        );
+    }
+
+    #[test]
+    fn new_code_replaces_previous_one() {
+        let codes = get_codes();
+        let first = block_on(codes.issue(KEY, time(0))).unwrap();
+        let second = block_on(codes.issue(KEY, time(1))).unwrap();
+
+        if first != second {
+            assert_eq!(
+                get_status(block_on(codes.verify(KEY, &first, time(2)))),
+                ApiResultStatus::InvalidCodeEntered as i16
+            );
+        }
+
+        assert!(block_on(codes.verify(KEY, &second, time(3))).is_ok());
+    }
+
+    #[test]
+    fn expired_code_is_removed() {
+        let codes = get_codes();
+        let code = block_on(codes.issue(KEY, time(0))).unwrap();
+
+        assert_eq!(
+            get_status(block_on(codes.verify(KEY, &code, time(300)))),
+            ApiResultStatus::InvalidCode as i16
+        );
+        assert!(block_on(codes.store.get(KEY)).unwrap().is_none());
+    }
+
+    #[test]
+    fn lockout_survives_new_code_request() {
+        let codes = get_codes();
+        let code = block_on(codes.issue(KEY, time(0))).unwrap();
+        let wrong_code = if code == "000000" { "000001" } else { "000000" };
+
+        assert_eq!(
+            get_status(block_on(codes.verify(KEY, wrong_code, time(1)))),
+            ApiResultStatus::InvalidCodeEntered as i16
+        );
+        assert_eq!(
+            get_status(block_on(codes.verify(KEY, wrong_code, time(2)))),
+            ApiResultStatus::InvalidCode as i16
+        );
+
+        // Locked till time(2 + 900), which is after the code expiration
+        let err = block_on(codes.issue(KEY, time(600))).unwrap_err();
+        assert!(matches!(err.status, ApiResultStatus::InvalidCode));
+        assert!(err.data.is_some());
+
+        let code = block_on(codes.issue(KEY, time(902))).unwrap();
+        assert!(block_on(codes.verify(KEY, &code, time(903))).is_ok());
+    }
+
+    #[test]
+    fn wrong_attempts_survive_new_code_request() {
+        let codes = get_codes();
+        let code = block_on(codes.issue(KEY, time(0))).unwrap();
+        let wrong_code = if code == "000000" { "000001" } else { "000000" };
+
+        assert_eq!(
+            get_status(block_on(codes.verify(KEY, wrong_code, time(1)))),
+            ApiResultStatus::InvalidCodeEntered as i16
+        );
+
+        let code = block_on(codes.issue(KEY, time(2))).unwrap();
+        let wrong_code = if code == "000000" { "000001" } else { "000000" };
+
+        let err = block_on(codes.verify(KEY, wrong_code, time(3))).unwrap_err();
+        assert!(matches!(err.status, ApiResultStatus::InvalidCode));
+        assert!(err.data.is_some());
+        assert!(block_on(codes.issue(KEY, time(4))).is_err());
+    }
+
+    #[test]
+    fn attempts_of_expired_code_are_not_carried_over() {
+        let codes = get_codes();
+        let code = block_on(codes.issue(KEY, time(0))).unwrap();
+        let wrong_code = if code == "000000" { "000001" } else { "000000" };
+
+        assert_eq!(
+            get_status(block_on(codes.verify(KEY, wrong_code, time(1)))),
+            ApiResultStatus::InvalidCodeEntered as i16
+        );
+
+        block_on(codes.issue(KEY, time(300))).unwrap();
+        assert_eq!(block_on(codes.store.get(KEY)).unwrap().unwrap().attempts, 0);
+    }
+
+    #[test]
+    fn concurrent_wrong_attempts_are_counted() {
+        let codes = get_concurrent_codes();
+        let code = block_on(codes.issue(KEY, time(0))).unwrap();
+        let wrong_code = if code == "000000" { "000001" } else { "000000" };
+
+        // Another request has counted a wrong attempt after this one read the record
+        let mut record = block_on(codes.store.get(KEY)).unwrap().unwrap();
+        record.attempts += 1;
+        *codes.store.concurrent_write.lock().unwrap() = Some(Some(record));
+
+        let err = block_on(codes.verify(KEY, wrong_code, time(1))).unwrap_err();
+        assert!(matches!(err.status, ApiResultStatus::InvalidCode));
+        assert!(err.data.is_some());
+    }
+
+    #[test]
+    fn concurrently_used_code_is_rejected() {
+        let codes = get_concurrent_codes();
+        let code = block_on(codes.issue(KEY, time(0))).unwrap();
+
+        // Another request has used the code after this one read the record
+        *codes.store.concurrent_write.lock().unwrap() = Some(None);
+
+        assert_eq!(
+            get_status(block_on(codes.verify(KEY, &code, time(1)))),
+            ApiResultStatus::InvalidCode as i16
+        );
+    }
+
+    #[test]
+    fn unknown_key() {
+        let codes = get_codes();
+
+        assert_eq!(
+            get_status(block_on(codes.verify(KEY, "123456", time(0)))),
+            ApiResultStatus::InvalidCode as i16
+        );
+    }
+}
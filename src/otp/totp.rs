@@ -0,0 +1,393 @@
+use hmac::{Hmac, Mac};
+use rand_core::{OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use service_sdk::rust_extensions::date_time::DateTimeAsMicroseconds;
+use sha1::Sha1;
+
+use crate::{constant_time_eq, ApiError, ValidationFailure, CODE_INVALID_FORMAT};
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+// 160 bits, the HMAC-SHA1 block recommended by RFC 4226
+const SECRET_LEN: usize = 20;
+
+// RFC 4226 codes have 6 to 8 digits, the truncated value has 31 bits
+const MIN_DIGITS: u32 = 6;
+const MAX_DIGITS: u32 = 8;
+// Every period of the window is checked on verification
+const MAX_SKEW_PERIODS: u32 = 10;
+
+/// RFC 6238 parameters. Defaults are the only ones all authenticator apps support.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TotpSettings {
+    pub digits: u32,
+    pub period_seconds: u32,
+    /// Accepted clock drift in periods before and after the current one
+    pub skew_periods: u32,
+}
+
+impl Default for TotpSettings {
+    fn default() -> Self {
+        Self {
+            digits: 6,
+            period_seconds: 30,
+            skew_periods: 1,
+        }
+    }
+}
+
+/// Shared secret of the authenticator app. Stored and shown to the client as base32.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TotpSecret {
+    bytes: Vec<u8>,
+}
+
+impl TotpSecret {
+    pub fn generate() -> Self {
+        let mut bytes = vec![0; SECRET_LEN];
+        OsRng.fill_bytes(&mut bytes);
+        Self { bytes }
+    }
+
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self { bytes }
+    }
+
+    /// Spaces, lowercase letters and `=` padding are accepted, as apps display secrets in groups.
+    pub fn from_base32(value: &str) -> Result<Self, ValidationFailure> {
+        let invalid = || ValidationFailure::new(CODE_INVALID_FORMAT, "TotpSecret: Invalid base32");
+
+        let mut bytes = Vec::with_capacity(value.len() * 5 / 8);
+        let mut buffer: u32 = 0;
+        let mut bits = 0;
+
+        for c in value.bytes().filter(|c| *c != b' ' && *c != b'=') {
+            let index = BASE32_ALPHABET
+                .iter()
+                .position(|a| *a == c.to_ascii_uppercase())
+                .ok_or_else(invalid)?;
+
+            buffer = (buffer << 5) | index as u32;
+            bits += 5;
+
+            if bits >= 8 {
+                bits -= 8;
+                bytes.push((buffer >> bits) as u8);
+                buffer &= (1 << bits) - 1;
+            }
+        }
+
+        if bytes.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(Self { bytes })
+    }
+
+    /// Without padding, as `otpauth://` URIs expect
+    pub fn to_base32(&self) -> String {
+        let mut result = String::with_capacity(self.bytes.len().div_ceil(5) * 8);
+        let mut buffer: u32 = 0;
+        let mut bits = 0;
+
+        for b in &self.bytes {
+            buffer = (buffer << 8) | *b as u32;
+            bits += 8;
+
+            while bits >= 5 {
+                bits -= 5;
+                result.push(BASE32_ALPHABET[((buffer >> bits) & 31) as usize] as char);
+            }
+
+            buffer &= (1 << bits) - 1;
+        }
+
+        if bits > 0 {
+            result.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 31) as usize] as char);
+        }
+
+        result
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+// Secrets should not get into logs
+impl std::fmt::Debug for TotpSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TotpSecret(***)")
+    }
+}
+
+pub struct Totp {
+    secret: TotpSecret,
+    settings: TotpSettings,
+}
+
+impl Totp {
+    /// Fails on digits out of 6..=8, zero period or skew of more than 10 periods.
+    pub fn new(secret: TotpSecret, settings: TotpSettings) -> Result<Self, ApiError> {
+        if !(MIN_DIGITS..=MAX_DIGITS).contains(&settings.digits) {
+            return Err(ApiError::internal(format!(
+                "TotpSettings: digits should be {}..={}, got {}",
+                MIN_DIGITS, MAX_DIGITS, settings.digits
+            )));
+        }
+
+        if settings.period_seconds == 0 {
+            return Err(ApiError::internal(
+                "TotpSettings: periodSeconds should not be 0",
+            ));
+        }
+
+        if settings.skew_periods > MAX_SKEW_PERIODS {
+            return Err(ApiError::internal(format!(
+                "TotpSettings: skewPeriods should be {} or less, got {}",
+                MAX_SKEW_PERIODS, settings.skew_periods
+            )));
+        }
+
+        Ok(Self { secret, settings })
+    }
+
+    pub fn get_secret(&self) -> &TotpSecret {
+        &self.secret
+    }
+
+    /// Time step the code of `now` belongs to
+    pub fn get_step(&self, now: DateTimeAsMicroseconds) -> u64 {
+        (now.unix_microseconds.max(0) / 1_000_000) as u64 / self.settings.period_seconds as u64
+    }
+
+    pub fn generate(&self, now: DateTimeAsMicroseconds) -> String {
+        self.generate_for_step(self.get_step(now))
+    }
+
+    /// Returns the matched time step. Services should store it and pass it as `last_used_step`
+    /// next time, so the same code can not be used twice.
+    pub fn verify(
+        &self,
+        code: &str,
+        now: DateTimeAsMicroseconds,
+        last_used_step: Option<u64>,
+    ) -> Option<u64> {
+        let code = code.trim();
+
+        if code.len() != self.settings.digits as usize || !code.bytes().all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+
+        let current_step = self.get_step(now);
+        let skew = self.settings.skew_periods as u64;
+        let mut matched_step = None;
+
+        // All steps of the window are checked to keep the time constant
+        for step in current_step.saturating_sub(skew)..=current_step + skew {
+            let is_used = matches!(last_used_step, Some(last_used_step) if step <= last_used_step);
+
+            if constant_time_eq(self.generate_for_step(step).as_bytes(), code.as_bytes())
+                && !is_used
+                && matched_step.is_none()
+            {
+                matched_step = Some(step);
+            }
+        }
+
+        matched_step
+    }
+
+    /// `otpauth://` URI for the QR code of authenticator apps
+    pub fn get_provisioning_uri(&self, issuer: &str, account_name: &str) -> String {
+        format!(
+            "otpauth://totp/{}:{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+            encode_uri_component(issuer),
+            encode_uri_component(account_name),
+            self.secret.to_base32(),
+            encode_uri_component(issuer),
+            self.settings.digits,
+            self.settings.period_seconds
+        )
+    }
+
+    // RFC 4226 HOTP with dynamic truncation
+    fn generate_for_step(&self, step: u64) -> String {
+        let mut mac = Hmac::<Sha1>::new_from_slice(self.secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(&step.to_be_bytes());
+        let hash = mac.finalize().into_bytes();
+
+        let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+        let binary = u32::from_be_bytes([
+            hash[offset] & 0x7f,
+            hash[offset + 1],
+            hash[offset + 2],
+            hash[offset + 3],
+        ]);
+
+        let digits = self.settings.digits as usize;
+        let code = binary as u64 % 10_u64.pow(self.settings.digits);
+
+        format!("{:0width$}", code, width = digits)
+    }
+}
+
+fn encode_uri_component(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+
+    for b in value.bytes() {
+        if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.' | b'~') {
+            result.push(b as char);
+        } else {
+            result.push_str(&format!("%{:02X}", b));
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(seconds: i64) -> DateTimeAsMicroseconds {
+        DateTimeAsMicroseconds {
+            unix_microseconds: seconds * 1_000_000,
+        }
+    }
+
+    fn get_rfc_totp(digits: u32) -> Totp {
+        let settings = TotpSettings {
+            digits,
+            ..Default::default()
+        };
+
+        Totp::new(
+            TotpSecret::from_bytes(b"12345678901234567890".to_vec()),
+            settings,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn rfc_6238_test_vectors() {
+        let totp = get_rfc_totp(8);
+
+        for (seconds, expected) in [
+            (59, "94287082"),
+            (1111111109, "07081804"),
+            (1111111111, "14050471"),
+            (1234567890, "89005924"),
+            (2000000000, "69279037"),
+            (20000000000, "65353130"),
+        ] {
+            assert_eq!(totp.generate(time(seconds)), expected, "{}", seconds);
+        }
+
+        assert_eq!(get_rfc_totp(6).generate(time(59)), "287082");
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        for settings in [
+            TotpSettings {
+                digits: 20,
+                ..Default::default()
+            },
+            TotpSettings {
+                digits: 5,
+                ..Default::default()
+            },
+            TotpSettings {
+                period_seconds: 0,
+                ..Default::default()
+            },
+            TotpSettings {
+                skew_periods: u32::MAX,
+                ..Default::default()
+            },
+        ] {
+            assert!(
+                Totp::new(TotpSecret::generate(), settings.clone()).is_err(),
+                "{:?}",
+                settings
+            );
+        }
+
+        assert!(Totp::new(TotpSecret::generate(), TotpSettings::default()).is_ok());
+    }
+
+    #[test]
+    fn verifies_with_clock_skew() {
+        let totp = get_rfc_totp(6);
+        let code = totp.generate(time(1111111109));
+        let step = totp.get_step(time(1111111109));
+
+        assert_eq!(totp.verify(&code, time(1111111109), None), Some(step));
+        assert_eq!(totp.verify(&code, time(1111111109 + 30), None), Some(step));
+        assert_eq!(totp.verify(&code, time(1111111109 - 30), None), Some(step));
+        assert_eq!(totp.verify(&code, time(1111111109 + 60), None), None);
+        assert_eq!(totp.verify("12345", time(1111111109), None), None);
+        assert_eq!(totp.verify("abcdef", time(1111111109), None), None);
+    }
+
+    #[test]
+    fn used_code_is_rejected() {
+        let totp = get_rfc_totp(6);
+        let code = totp.generate(time(59));
+        let step = totp.verify(&code, time(59), None).unwrap();
+
+        assert_eq!(totp.verify(&code, time(59), Some(step)), None);
+        assert_eq!(totp.verify(&code, time(75), Some(step)), None);
+
+        let next_code = totp.generate(time(60));
+        assert_eq!(
+            totp.verify(&next_code, time(60), Some(step)),
+            Some(step + 1)
+        );
+    }
+
+    #[test]
+    fn base32_round_trip() {
+        let secret = TotpSecret::from_bytes(b"12345678901234567890".to_vec());
+        assert_eq!(secret.to_base32(), "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ");
+
+        assert_eq!(
+            TotpSecret::from_base32("gezd gnbv gy3t qojq gezd gnbv gy3t qojq").unwrap(),
+            secret
+        );
+
+        for value in [b"f".to_vec(), b"fo".to_vec(), b"foobar".to_vec()] {
+            let secret = TotpSecret::from_bytes(value);
+            assert_eq!(
+                TotpSecret::from_base32(&secret.to_base32()).unwrap(),
+                secret
+            );
+        }
+
+        assert_eq!(
+            TotpSecret::from_bytes(b"foobar".to_vec()).to_base32(),
+            "MZXW6YTBOI"
+        );
+        assert!(TotpSecret::from_base32("GEZD1").is_err());
+        assert!(TotpSecret::from_base32("").is_err());
+
+        let generated = TotpSecret::generate();
+        assert_eq!(generated.as_bytes().len(), 20);
+        assert_eq!(generated.to_base32().len(), 32);
+        assert_eq!(format!("{:?}", generated), "TotpSecret(***)");
+    }
+
+    #[test]
+    fn provisioning_uri() {
+        let totp = get_rfc_totp(6);
+
+        assert_eq!(
+            totp.get_provisioning_uri("Acme Broker", "john+1@example.com"),
+            "otpauth://totp/Acme%20Broker:john%2B1%40example.com?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ&issuer=Acme%20Broker&algorithm=SHA1&digits=6&period=30"
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::{de::DeserializeOwned, Serialize};
+use service_sdk::my_http_server::HttpFailResult;
+
+use crate::create_fail_http_result;
+
+/// Encodes the position of the last returned item into an opaque, url-safe cursor.
+pub fn encode_cursor<T: Serialize>(position: &T) -> String {
+    let json = serde_json::to_vec(position).unwrap();
+    URL_SAFE_NO_PAD.encode(json)
+}
+
+pub fn decode_cursor<T: DeserializeOwned>(cursor: &str) -> Result<T, HttpFailResult> {
+    let json = URL_SAFE_NO_PAD
+        .decode(cursor)
+        .map_err(|_| create_fail_http_result("Cursor: Invalid value"))?;
+
+    serde_json::from_slice(&json).map_err(|_| create_fail_http_result("Cursor: Invalid value"))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct OrderPosition {
+        created: i64,
+        id: String,
+    }
+
+    #[test]
+    fn cursor_round_trip() {
+        let position = OrderPosition {
+            created: 1700000000000000,
+            id: "order-1".to_string(),
+        };
+
+        let cursor = encode_cursor(&position);
+        assert!(!cursor.contains('='));
+        assert!(!cursor.contains('+'));
+        assert!(!cursor.contains('/'));
+
+        let decoded: OrderPosition = decode_cursor(&cursor).unwrap();
+        assert_eq!(decoded, position);
+    }
+
+    #[test]
+    fn cursor_rejects_garbage() {
+        assert!(decode_cursor::<OrderPosition>("not a cursor").is_err());
+        assert!(decode_cursor::<OrderPosition>("e30").is_err());
+    }
+}
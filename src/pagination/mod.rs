@@ -0,0 +1,7 @@
+mod page_query;
+mod paged_result;
+mod cursor;
+
+pub use page_query::*;
+pub use paged_result::*;
+pub use cursor::*;
@@ -0,0 +1,146 @@
+use service_sdk::my_http_server::HttpFailResult;
+
+use crate::create_fail_http_result;
+
+pub const DEFAULT_PAGE_SIZE: u32 = 20;
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageLimits {
+    pub default_size: u32,
+    pub max_size: u32,
+}
+
+impl PageLimits {
+    pub fn new(default_size: u32, max_size: u32) -> Self {
+        Self {
+            default_size,
+            max_size,
+        }
+    }
+}
+
+impl Default for PageLimits {
+    fn default() -> Self {
+        Self::new(DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE)
+    }
+}
+
+/// Page/size paging. Pages are 1-based as they are shown to the user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageQuery {
+    pub page: u32,
+    pub size: u32,
+}
+
+impl PageQuery {
+    pub fn parse(
+        page: Option<u32>,
+        size: Option<u32>,
+        limits: &PageLimits,
+    ) -> Result<Self, HttpFailResult> {
+        let page = page.unwrap_or(1);
+
+        if page == 0 {
+            return Err(create_fail_http_result("Page: Should be greater than 0"));
+        }
+
+        let size = parse_limit("Size", size, limits)?;
+
+        Ok(Self { page, size })
+    }
+
+    pub fn get_offset(&self) -> u64 {
+        (self.page as u64 - 1) * self.size as u64
+    }
+}
+
+/// Cursor/limit paging. Cursor is the opaque value returned in [`crate::PagedResult::next_cursor`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CursorQuery {
+    pub cursor: Option<String>,
+    pub limit: u32,
+}
+
+impl CursorQuery {
+    pub fn parse(
+        cursor: Option<String>,
+        limit: Option<u32>,
+        limits: &PageLimits,
+    ) -> Result<Self, HttpFailResult> {
+        let limit = parse_limit("Limit", limit, limits)?;
+
+        let cursor = match cursor {
+            Some(cursor) if cursor.is_empty() => None,
+            cursor => cursor,
+        };
+
+        Ok(Self { cursor, limit })
+    }
+}
+
+fn parse_limit(
+    field: &str,
+    value: Option<u32>,
+    limits: &PageLimits,
+) -> Result<u32, HttpFailResult> {
+    let Some(value) = value else {
+        return Ok(limits.default_size);
+    };
+
+    if value == 0 {
+        return Err(create_fail_http_result(&format!(
+            "{}: Should be greater than 0",
+            field
+        )));
+    }
+
+    if value > limits.max_size {
+        return Err(create_fail_http_result(&format!(
+            "{}: Max value is {}",
+            field, limits.max_size
+        )));
+    }
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn page_query_uses_defaults() {
+        let query = PageQuery::parse(None, None, &PageLimits::default()).unwrap();
+
+        assert_eq!(query.page, 1);
+        assert_eq!(query.size, DEFAULT_PAGE_SIZE);
+        assert_eq!(query.get_offset(), 0);
+    }
+
+    #[test]
+    fn page_query_calculates_offset() {
+        let query = PageQuery::parse(Some(3), Some(25), &PageLimits::default()).unwrap();
+
+        assert_eq!(query.get_offset(), 50);
+    }
+
+    #[test]
+    fn page_query_rejects_zero_page_and_oversized_page() {
+        let limits = PageLimits::new(10, 50);
+
+        assert!(PageQuery::parse(Some(0), None, &limits).is_err());
+        assert!(PageQuery::parse(None, Some(0), &limits).is_err());
+        assert!(PageQuery::parse(None, Some(51), &limits).is_err());
+        assert!(PageQuery::parse(None, Some(50), &limits).is_ok());
+    }
+
+    #[test]
+    fn cursor_query_treats_empty_cursor_as_first_page() {
+        let query =
+            CursorQuery::parse(Some("".to_string()), Some(5), &PageLimits::default()).unwrap();
+
+        assert_eq!(query.cursor, None);
+        assert_eq!(query.limit, 5);
+    }
+}
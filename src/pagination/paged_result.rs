@@ -0,0 +1,50 @@
+service_sdk::macros::use_my_http_server!();
+
+use my_http_server::controllers::documentation::DataTypeProvider;
+use serde::Serialize;
+use service_sdk::my_http_server::macros::MyHttpObjectStructure;
+
+use crate::{ApiHttpResultWithData, ApiResultStatus, PageQuery};
+
+/// List envelope. Goes into the `data` field of [`ApiHttpResultWithData`].
+#[derive(Serialize, MyHttpObjectStructure, Debug)]
+pub struct PagedResult<TItem: Serialize + DataTypeProvider> {
+    pub items: Vec<TItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    pub size: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<u64>,
+    #[serde(rename = "nextCursor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+impl<TItem: Serialize + DataTypeProvider> PagedResult<TItem> {
+    pub fn from_page(items: Vec<TItem>, query: &PageQuery, total: u64) -> Self {
+        Self {
+            items,
+            page: Some(query.page),
+            size: query.size,
+            total: Some(total),
+            next_cursor: None,
+        }
+    }
+
+    pub fn from_cursor(items: Vec<TItem>, limit: u32, next_cursor: Option<String>) -> Self {
+        Self {
+            items,
+            page: None,
+            size: limit,
+            total: None,
+            next_cursor,
+        }
+    }
+
+    pub fn into_api_result(self) -> ApiHttpResultWithData<Self> {
+        ApiHttpResultWithData {
+            result: ApiResultStatus::Ok,
+            data: Some(self),
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod password_hashing;
+mod password_policy;
+mod password_strength;
+
+pub use password_hashing::*;
+pub use password_policy::*;
+pub use password_strength::*;
@@ -0,0 +1,265 @@
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::{Algorithm, Argon2, Params, Version};
+use rand_core::OsRng;
+use serde::{Deserialize, Serialize};
+
+use crate::{ApiError, ApiResultStatus};
+
+/// Argon2id parameters. Defaults follow the OWASP recommendation (19 MiB, 2 iterations, 1 lane).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PasswordHashParams {
+    #[serde(rename = "memoryKib")]
+    pub memory_kib: u32,
+    #[serde(rename = "iterations")]
+    pub iterations: u32,
+    #[serde(rename = "parallelism")]
+    pub parallelism: u32,
+}
+
+impl Default for PasswordHashParams {
+    fn default() -> Self {
+        Self {
+            memory_kib: 19 * 1024,
+            iterations: 2,
+            parallelism: 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PasswordVerification {
+    Invalid,
+    Valid,
+    /// Password is correct but the stored hash uses old parameters. Store the new hash.
+    ValidNeedsRehash(String),
+}
+
+impl PasswordVerification {
+    pub fn is_valid(&self) -> bool {
+        !matches!(self, PasswordVerification::Invalid)
+    }
+}
+
+/// Hashes passwords into PHC strings (`$argon2id$v=19$m=...,t=...,p=...$salt$hash`).
+pub struct PasswordHashing {
+    params: PasswordHashParams,
+    argon2: Argon2<'static>,
+}
+
+impl PasswordHashing {
+    pub fn new(params: PasswordHashParams) -> Result<Self, ApiError> {
+        let argon2_params = Params::new(
+            params.memory_kib,
+            params.iterations,
+            params.parallelism,
+            None,
+        )
+        .map_err(ApiError::internal)?;
+
+        Ok(Self {
+            params,
+            argon2: Argon2::new(Algorithm::Argon2id, Version::V0x13, argon2_params),
+        })
+    }
+
+    pub fn get_params(&self) -> &PasswordHashParams {
+        &self.params
+    }
+
+    pub fn hash(&self, password: &str) -> Result<String, ApiError> {
+        let salt = SaltString::generate(&mut OsRng);
+
+        let hash = self
+            .argon2
+            .hash_password(password.as_bytes(), &salt)
+            .map_err(ApiError::internal)?;
+
+        Ok(hash.to_string())
+    }
+
+    /// Verifies the password with the parameters stored in `phc_hash`,
+    /// and rehashes it when they differ from the current ones.
+    pub fn verify(&self, password: &str, phc_hash: &str) -> Result<PasswordVerification, ApiError> {
+        let Ok(hash) = PasswordHash::new(phc_hash) else {
+            return Ok(PasswordVerification::Invalid);
+        };
+
+        if Argon2::default()
+            .verify_password(password.as_bytes(), &hash)
+            .is_err()
+        {
+            return Ok(PasswordVerification::Invalid);
+        }
+
+        if self.is_up_to_date(&hash) {
+            return Ok(PasswordVerification::Valid);
+        }
+
+        Ok(PasswordVerification::ValidNeedsRehash(self.hash(password)?))
+    }
+
+    /// Returns [`ApiResultStatus::OldPasswordIsWrong`] if the password does not match.
+    pub fn verify_old_password(
+        &self,
+        password: &str,
+        phc_hash: &str,
+    ) -> Result<PasswordVerification, ApiError> {
+        let result = self.verify(password, phc_hash)?;
+
+        if !result.is_valid() {
+            return Err(ApiResultStatus::OldPasswordIsWrong.into());
+        }
+
+        Ok(result)
+    }
+
+    pub fn needs_rehash(&self, phc_hash: &str) -> bool {
+        match PasswordHash::new(phc_hash) {
+            Ok(hash) => !self.is_up_to_date(&hash),
+            Err(_) => true,
+        }
+    }
+
+    /// Returns [`ApiResultStatus::PasswordWasUsedBefore`] if the password matches
+    /// one of the last `depth` hashes. `history` is ordered from newest to oldest.
+    pub fn check_password_history<T: AsRef<str>>(
+        &self,
+        password: &str,
+        history: &[T],
+        depth: usize,
+    ) -> Result<(), ApiError> {
+        for phc_hash in history.iter().take(depth) {
+            if self.verify_without_rehash(password, phc_hash.as_ref()) {
+                return Err(ApiResultStatus::PasswordWasUsedBefore.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn verify_without_rehash(&self, password: &str, phc_hash: &str) -> bool {
+        match PasswordHash::new(phc_hash) {
+            Ok(hash) => Argon2::default()
+                .verify_password(password.as_bytes(), &hash)
+                .is_ok(),
+            Err(_) => false,
+        }
+    }
+
+    fn is_up_to_date(&self, hash: &PasswordHash) -> bool {
+        if hash.algorithm != Algorithm::Argon2id.ident() {
+            return false;
+        }
+
+        if hash.version != Some(Version::V0x13.into()) {
+            return false;
+        }
+
+        let Ok(params) = Params::try_from(hash) else {
+            return false;
+        };
+
+        params.m_cost() == self.params.memory_kib
+            && params.t_cost() == self.params.iterations
+            && params.p_cost() == self.params.parallelism
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Small parameters to keep tests fast
+    fn get_params(memory_kib: u32) -> PasswordHashParams {
+        PasswordHashParams {
+            memory_kib,
+            iterations: 1,
+            parallelism: 1,
+        }
+    }
+
+    #[test]
+    fn hash_is_argon2id_phc_string() {
+        let hashing = PasswordHashing::new(get_params(1024)).unwrap();
+        let hash = hashing.hash("Valid123!").unwrap();
+
+        assert!(hash.starts_with("$argon2id$v=19$m=1024,t=1,p=1$"));
+        assert_ne!(hash, hashing.hash("Valid123!").unwrap());
+    }
+
+    #[test]
+    fn verify_password() {
+        let hashing = PasswordHashing::new(get_params(1024)).unwrap();
+        let hash = hashing.hash("Valid123!").unwrap();
+
+        assert_eq!(
+            hashing.verify("Valid123!", &hash).unwrap(),
+            PasswordVerification::Valid
+        );
+        assert_eq!(
+            hashing.verify("Valid1234!", &hash).unwrap(),
+            PasswordVerification::Invalid
+        );
+        assert_eq!(
+            hashing.verify("Valid123!", "not a hash").unwrap(),
+            PasswordVerification::Invalid
+        );
+        assert!(!hashing.needs_rehash(&hash));
+    }
+
+    #[test]
+    fn rehash_when_params_changed() {
+        let old_hash = PasswordHashing::new(get_params(1024))
+            .unwrap()
+            .hash("Valid123!")
+            .unwrap();
+
+        let hashing = PasswordHashing::new(get_params(2048)).unwrap();
+        assert!(hashing.needs_rehash(&old_hash));
+
+        let PasswordVerification::ValidNeedsRehash(new_hash) =
+            hashing.verify("Valid123!", &old_hash).unwrap()
+        else {
+            panic!("Rehash expected");
+        };
+
+        assert!(new_hash.starts_with("$argon2id$v=19$m=2048,t=1,p=1$"));
+        assert_eq!(
+            hashing.verify("Valid123!", &new_hash).unwrap(),
+            PasswordVerification::Valid
+        );
+    }
+
+    #[test]
+    fn wrong_old_password() {
+        let hashing = PasswordHashing::new(get_params(1024)).unwrap();
+        let hash = hashing.hash("Valid123!").unwrap();
+
+        let err = hashing.verify_old_password("Wrong123!", &hash).unwrap_err();
+        assert!(matches!(err.status, ApiResultStatus::OldPasswordIsWrong));
+    }
+
+    #[test]
+    fn password_history() {
+        let hashing = PasswordHashing::new(get_params(1024)).unwrap();
+
+        let history = vec![
+            hashing.hash("Newest123!").unwrap(),
+            hashing.hash("Middle123!").unwrap(),
+            hashing.hash("Oldest123!").unwrap(),
+        ];
+
+        let err = hashing
+            .check_password_history("Middle123!", &history, 3)
+            .unwrap_err();
+        assert!(matches!(err.status, ApiResultStatus::PasswordWasUsedBefore));
+
+        assert!(hashing
+            .check_password_history("Oldest123!", &history, 2)
+            .is_ok());
+        assert!(hashing
+            .check_password_history("Unused123!", &history, 3)
+            .is_ok());
+    }
+}
@@ -0,0 +1,358 @@
+use serde::{Deserialize, Serialize};
+use service_sdk::my_http_server::{HttpContext, HttpFailResult};
+
+use crate::{
+    contains_no_space_characters, validate_no_cyrillic, validate_no_trimm_spaces, BrandPolicies,
+    ValidationFailure, WithTraceId, CODE_CYRILLIC, CODE_MAX_LENGTH, CODE_MIN_LENGTH,
+    CODE_SPACE_CHARACTERS, CODE_SPECIAL_SYMBOL, CODE_TRIM_SPACES, CODE_UPPER_LETTER,
+};
+
+pub const CODE_LOWER_LETTER: &str = "lower_letter";
+pub const CODE_DIGIT: &str = "digit";
+pub const CODE_CONTAINS_IDENTIFIER: &str = "contains_identifier";
+pub const CODE_REPEATED_CHARS: &str = "repeated_chars";
+
+pub const DEFAULT_SPECIAL_SYMBOLS: &str = "!@#$%^&*()-+=";
+
+// Identifiers shorter than this match too many passwords by accident
+const MIN_IDENTIFIER_LEN: usize = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PasswordPolicy {
+    #[serde(rename = "minLength")]
+    pub min_length: usize,
+    #[serde(rename = "maxLength")]
+    pub max_length: usize,
+    #[serde(rename = "requireUpper")]
+    pub require_upper: bool,
+    #[serde(rename = "requireLower")]
+    pub require_lower: bool,
+    #[serde(rename = "requireDigit")]
+    pub require_digit: bool,
+    #[serde(rename = "requireSpecial")]
+    pub require_special: bool,
+    #[serde(rename = "specialSymbols")]
+    pub special_symbols: String,
+    #[serde(rename = "disallowUserIdentifiers")]
+    pub disallow_user_identifiers: bool,
+    #[serde(rename = "maxRepeatedChars")]
+    pub max_repeated_chars: Option<usize>,
+}
+
+/// Rules of today's registration form.
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: 8,
+            max_length: 50,
+            require_upper: true,
+            require_lower: false,
+            require_digit: false,
+            require_special: true,
+            special_symbols: DEFAULT_SPECIAL_SYMBOLS.to_string(),
+            disallow_user_identifiers: false,
+            max_repeated_chars: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PasswordRule {
+    MinLength(usize),
+    MaxLength(usize),
+    NoTrimSpaces,
+    NoCyrillic,
+    UpperLetter,
+    NoSpaceCharacters,
+    SpecialSymbol,
+    LowerLetter,
+    Digit,
+    NoUserIdentifiers,
+    MaxRepeatedChars(usize),
+}
+
+impl PasswordRule {
+    pub fn get_code(&self) -> &'static str {
+        match self {
+            PasswordRule::MinLength(_) => CODE_MIN_LENGTH,
+            PasswordRule::MaxLength(_) => CODE_MAX_LENGTH,
+            PasswordRule::NoTrimSpaces => CODE_TRIM_SPACES,
+            PasswordRule::NoCyrillic => CODE_CYRILLIC,
+            PasswordRule::UpperLetter => CODE_UPPER_LETTER,
+            PasswordRule::NoSpaceCharacters => CODE_SPACE_CHARACTERS,
+            PasswordRule::SpecialSymbol => CODE_SPECIAL_SYMBOL,
+            PasswordRule::LowerLetter => CODE_LOWER_LETTER,
+            PasswordRule::Digit => CODE_DIGIT,
+            PasswordRule::NoUserIdentifiers => CODE_CONTAINS_IDENTIFIER,
+            PasswordRule::MaxRepeatedChars(_) => CODE_REPEATED_CHARS,
+        }
+    }
+
+    pub fn get_message(&self) -> String {
+        match self {
+            PasswordRule::MinLength(len) => format!("Min length is {} symbols", len),
+            PasswordRule::MaxLength(len) => format!("Max length is {} symbols", len),
+            PasswordRule::NoTrimSpaces => "Should not start or end with space".to_string(),
+            PasswordRule::NoCyrillic => "No cyrillic letters are allowed".to_string(),
+            PasswordRule::UpperLetter => "Must contain upper letter".to_string(),
+            PasswordRule::NoSpaceCharacters => {
+                "Password must contain no space characters".to_string()
+            }
+            PasswordRule::SpecialSymbol => {
+                "Password must contain at least one special symbol".to_string()
+            }
+            PasswordRule::LowerLetter => "Must contain lower letter".to_string(),
+            PasswordRule::Digit => "Must contain digit".to_string(),
+            PasswordRule::NoUserIdentifiers => {
+                "Password must not contain username or email".to_string()
+            }
+            PasswordRule::MaxRepeatedChars(max) => format!(
+                "Password must not repeat the same symbol more than {} times in a row",
+                max
+            ),
+        }
+    }
+}
+
+impl Into<ValidationFailure> for PasswordRule {
+    fn into(self) -> ValidationFailure {
+        ValidationFailure::new(self.get_code(), self.get_message())
+    }
+}
+
+/// Username/email of the trader, used to reject passwords built from them.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PasswordUserIdentifiers<'s> {
+    pub username: Option<&'s str>,
+    pub email: Option<&'s str>,
+}
+
+impl PasswordPolicy {
+    /// All rules of the policy in the order they are checked. Used to render checklists.
+    pub fn get_rules(&self) -> Vec<PasswordRule> {
+        self.iter_rules().collect()
+    }
+
+    fn iter_rules(&self) -> impl Iterator<Item = PasswordRule> {
+        [
+            Some(PasswordRule::MinLength(self.min_length)),
+            Some(PasswordRule::MaxLength(self.max_length)),
+            Some(PasswordRule::NoTrimSpaces),
+            Some(PasswordRule::NoCyrillic),
+            self.require_upper.then_some(PasswordRule::UpperLetter),
+            Some(PasswordRule::NoSpaceCharacters),
+            self.require_special.then_some(PasswordRule::SpecialSymbol),
+            self.require_lower.then_some(PasswordRule::LowerLetter),
+            self.require_digit.then_some(PasswordRule::Digit),
+            self.disallow_user_identifiers
+                .then_some(PasswordRule::NoUserIdentifiers),
+            self.max_repeated_chars.map(PasswordRule::MaxRepeatedChars),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    pub fn get_unmet_rules(
+        &self,
+        value: &str,
+        identifiers: &PasswordUserIdentifiers,
+    ) -> Vec<PasswordRule> {
+        self.iter_rules()
+            .filter(|rule| !self.is_rule_met(rule, value, identifiers))
+            .collect()
+    }
+
+    pub fn check(
+        &self,
+        value: &str,
+        identifiers: &PasswordUserIdentifiers,
+    ) -> Result<(), ValidationFailure> {
+        match self
+            .iter_rules()
+            .find(|rule| !self.is_rule_met(rule, value, identifiers))
+        {
+            Some(rule) => Err(rule.into()),
+            None => Ok(()),
+        }
+    }
+
+    fn is_rule_met(
+        &self,
+        rule: &PasswordRule,
+        value: &str,
+        identifiers: &PasswordUserIdentifiers,
+    ) -> bool {
+        match rule {
+            PasswordRule::MinLength(len) => value.len() >= *len,
+            PasswordRule::MaxLength(len) => value.len() <= *len,
+            PasswordRule::NoTrimSpaces => validate_no_trimm_spaces(value),
+            PasswordRule::NoCyrillic => validate_no_cyrillic(value),
+            PasswordRule::UpperLetter => value.chars().any(|c| c.is_uppercase()),
+            PasswordRule::NoSpaceCharacters => contains_no_space_characters(value),
+            PasswordRule::SpecialSymbol => value.chars().any(|c| self.special_symbols.contains(c)),
+            PasswordRule::LowerLetter => value.chars().any(|c| c.is_lowercase()),
+            PasswordRule::Digit => value.chars().any(|c| c.is_ascii_digit()),
+            PasswordRule::NoUserIdentifiers => !contains_user_identifier(value, identifiers),
+            PasswordRule::MaxRepeatedChars(max) => get_max_repeated_chars(value) <= *max,
+        }
+    }
+}
+
+fn contains_user_identifier(value: &str, identifiers: &PasswordUserIdentifiers) -> bool {
+    let value = value.to_lowercase();
+
+    let email_local_part = identifiers
+        .email
+        .map(|email| email.split('@').next().unwrap_or(email));
+
+    [identifiers.username, identifiers.email, email_local_part]
+        .into_iter()
+        .flatten()
+        .map(|identifier| identifier.to_lowercase())
+        .filter(|identifier| identifier.chars().count() >= MIN_IDENTIFIER_LEN)
+        .any(|identifier| value.contains(identifier.as_str()))
+}
+
+fn get_max_repeated_chars(value: &str) -> usize {
+    let mut result = 0;
+    let mut current = 0;
+    let mut prev = None;
+
+    for c in value.chars() {
+        if Some(c) == prev {
+            current += 1;
+        } else {
+            current = 1;
+            prev = Some(c);
+        }
+
+        result = result.max(current);
+    }
+
+    result
+}
+
+pub type PasswordPolicies = BrandPolicies<PasswordPolicy>;
+
+pub fn validate_password_with_policy(
+    ctx: &HttpContext,
+    value: &str,
+    policy: &PasswordPolicy,
+    identifiers: &PasswordUserIdentifiers,
+) -> Result<(), HttpFailResult> {
+    match policy.check(value, identifiers) {
+        Ok(_) => Ok(()),
+        Err(failure) => {
+            Err(HttpFailResult::as_validation_error(failure.message).with_trace_id(ctx))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_identifiers() -> PasswordUserIdentifiers<'static> {
+        PasswordUserIdentifiers::default()
+    }
+
+    #[test]
+    fn default_policy_matches_current_rules() {
+        let policy = PasswordPolicy::default();
+
+        assert!(policy.check("Valid123!", &no_identifiers()).is_ok());
+        assert!(policy.check("Validpass!", &no_identifiers()).is_ok());
+
+        assert_eq!(
+            policy.check("valid123!", &no_identifiers()).unwrap_err().message,
+            "Must contain upper letter"
+        );
+        assert_eq!(
+            policy.check("Valid123", &no_identifiers()).unwrap_err().message,
+            "Password must contain at least one special symbol"
+        );
+    }
+
+    #[test]
+    fn returns_all_unmet_rules() {
+        let policy = PasswordPolicy {
+            require_digit: true,
+            require_lower: true,
+            ..Default::default()
+        };
+
+        let unmet = policy.get_unmet_rules("ab", &no_identifiers());
+
+        assert_eq!(
+            unmet,
+            vec![
+                PasswordRule::MinLength(8),
+                PasswordRule::UpperLetter,
+                PasswordRule::SpecialSymbol,
+                PasswordRule::Digit,
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_user_identifiers() {
+        let policy = PasswordPolicy {
+            disallow_user_identifiers: true,
+            ..Default::default()
+        };
+
+        let identifiers = PasswordUserIdentifiers {
+            username: Some("trader"),
+            email: Some("JohnDoe@test.tt"),
+        };
+
+        assert_eq!(
+            policy.check("Johndoe2024!", &identifiers).unwrap_err().code,
+            CODE_CONTAINS_IDENTIFIER
+        );
+        assert_eq!(
+            policy.check("MyTRADER77!", &identifiers).unwrap_err().code,
+            CODE_CONTAINS_IDENTIFIER
+        );
+        assert!(policy.check("Valid123!", &identifiers).is_ok());
+    }
+
+    #[test]
+    fn rejects_repeated_chars() {
+        let policy = PasswordPolicy {
+            max_repeated_chars: Some(2),
+            ..Default::default()
+        };
+
+        assert!(policy.check("Vaalid12!", &no_identifiers()).is_ok());
+        assert_eq!(
+            policy.check("Vaaalid12!", &no_identifiers()).unwrap_err().code,
+            CODE_REPEATED_CHARS
+        );
+    }
+
+    #[test]
+    fn custom_special_symbols() {
+        let policy = PasswordPolicy {
+            special_symbols: "_".to_string(),
+            ..Default::default()
+        };
+
+        assert!(policy.check("Valid_123", &no_identifiers()).is_ok());
+        assert!(policy.check("Valid!123", &no_identifiers()).is_err());
+    }
+
+    #[test]
+    fn brand_policy_falls_back_to_default() {
+        let policies: PasswordPolicies = serde_json::from_str(
+            r#"{"brands":{"brand-a":{"minLength":12,"requireDigit":true}}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(policies.get("brand-a").min_length, 12);
+        assert!(policies.get("brand-a").require_digit);
+        assert!(policies.get("brand-a").require_upper);
+        assert_eq!(policies.get("brand-b"), &PasswordPolicy::default());
+    }
+}
@@ -0,0 +1,764 @@
+service_sdk::macros::use_my_http_server!();
+
+use std::collections::HashSet;
+use std::io::Read;
+
+use lazy_static::lazy_static;
+use serde::Serialize;
+use service_sdk::my_http_server::macros::MyHttpObjectStructure;
+use service_sdk::my_http_server::{HttpContext, HttpFailResult, WebContentType};
+
+use crate::{ApiHttpResultWithData, ApiResultStatus, ValidationFailure, WithTraceId};
+
+/// Top ~400 of the most common breached passwords, lowercase, one per line.
+/// It catches the passwords attackers try first, it is not a full breach corpus.
+const COMMON_PASSWORDS_GZ: &[u8] = include_bytes!("common_passwords.txt.gz");
+/// ~13k English words and first/last names of 4..=16 letters, lowercase, one per line.
+/// Built from the BIP-39 English list and the word lists of the `petname`, `names` and `fake` crates.
+const ENGLISH_WORDS_GZ: &[u8] = include_bytes!("english_words.txt.gz");
+
+const MIN_DICTIONARY_WORD_LEN: usize = 4;
+const MAX_DICTIONARY_WORD_LEN: usize = 16;
+const MIN_USER_INPUT_LEN: usize = 3;
+const MIN_KEYBOARD_WALK_LEN: usize = 4;
+const MIN_SEQUENCE_LEN: usize = 3;
+const MIN_REPEAT_LEN: usize = 3;
+/// Only this prefix is estimated, matching is polynomial in the length (zxcvbn uses the same cap)
+const MAX_ESTIMATED_LEN: usize = 100;
+
+pub const MAX_PASSWORD_SCORE: u8 = 4;
+pub const CODE_WEAK_PASSWORD: &str = "weak_password";
+
+const KEYBOARD_ROWS: [&str; 4] = [
+    "`1234567890-=",
+    "qwertyuiop[]\\",
+    "asdfghjkl;'",
+    "zxcvbnm,./",
+];
+
+lazy_static! {
+    static ref COMMON_PASSWORDS: HashSet<String> = read_word_list(COMMON_PASSWORDS_GZ);
+    static ref ENGLISH_WORDS: HashSet<String> = read_word_list(ENGLISH_WORDS_GZ);
+}
+
+fn read_word_list(gz: &[u8]) -> HashSet<String> {
+    let mut decoder = flate2::read::GzDecoder::new(gz);
+    let mut content = String::new();
+    decoder.read_to_string(&mut content).unwrap();
+
+    content
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| line.to_string())
+        .collect()
+}
+
+pub fn is_common_password(value: &str) -> bool {
+    COMMON_PASSWORDS.contains(&value.to_lowercase())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordPattern {
+    CommonPassword,
+    Dictionary,
+    UserInput,
+    KeyboardWalk,
+    Sequence,
+    Repeat,
+    Date,
+}
+
+#[derive(Debug, Clone)]
+pub struct PasswordStrength {
+    /// 0 (too guessable) ..= 4 (very unguessable)
+    pub score: u8,
+    pub entropy_bits: f64,
+    pub patterns: Vec<PasswordPattern>,
+    pub warning: Option<String>,
+    pub suggestions: Vec<String>,
+}
+
+struct PatternMatch {
+    start: usize,
+    end: usize,
+    bits: f64,
+    pattern: PasswordPattern,
+}
+
+/// Offline estimation in the spirit of zxcvbn: the password is split into the cheapest
+/// combination of known patterns and brute-forced characters.
+/// `user_inputs` are values like username or email which attackers would try first.
+/// Symbols after the first 100 are ignored.
+pub fn estimate_password_strength(value: &str, user_inputs: &[&str]) -> PasswordStrength {
+    let chars: Vec<char> = value.chars().take(MAX_ESTIMATED_LEN).collect();
+    let lower: Vec<char> = chars.iter().map(|c| to_lower(*c)).collect();
+
+    if is_common_as_whole(&lower) {
+        return build_strength(0.0, vec![PasswordPattern::CommonPassword], chars.len());
+    }
+
+    let mut matches = Vec::new();
+    find_dictionary_matches(&chars, &lower, &mut matches);
+    find_user_input_matches(&lower, user_inputs, &mut matches);
+    find_keyboard_walks(&lower, &mut matches);
+    find_sequences(&lower, &mut matches);
+    find_repeats(&lower, &mut matches);
+    find_dates(&chars, &mut matches);
+
+    let (entropy_bits, patterns) = get_cheapest_cover(&chars, &matches);
+
+    build_strength(entropy_bits, patterns, chars.len())
+}
+
+fn build_strength(
+    entropy_bits: f64,
+    patterns: Vec<PasswordPattern>,
+    len: usize,
+) -> PasswordStrength {
+    let score = match entropy_bits {
+        x if x < 28.0 => 0,
+        x if x < 36.0 => 1,
+        x if x < 50.0 => 2,
+        x if x < 64.0 => 3,
+        _ => MAX_PASSWORD_SCORE,
+    };
+
+    let warning = patterns
+        .first()
+        .map(|pattern| get_warning(*pattern).to_string());
+
+    let mut suggestions: Vec<String> = Vec::new();
+
+    for pattern in &patterns {
+        let suggestion = get_suggestion(*pattern).to_string();
+        if !suggestions.contains(&suggestion) {
+            suggestions.push(suggestion);
+        }
+    }
+
+    if score < 3 {
+        if len < 12 {
+            suggestions.push("Use a longer password, 12 symbols or more".to_string());
+        }
+
+        suggestions.push("Add another word or two. Uncommon words are better".to_string());
+    }
+
+    PasswordStrength {
+        score,
+        entropy_bits,
+        patterns,
+        warning,
+        suggestions,
+    }
+}
+
+#[derive(Serialize, MyHttpObjectStructure, Debug)]
+pub struct PasswordStrengthFeedback {
+    pub score: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+    pub suggestions: Vec<String>,
+}
+
+impl Into<PasswordStrengthFeedback> for PasswordStrength {
+    fn into(self) -> PasswordStrengthFeedback {
+        PasswordStrengthFeedback {
+            score: self.score,
+            warning: self.warning,
+            suggestions: self.suggestions,
+        }
+    }
+}
+
+pub fn check_password_strength(
+    value: &str,
+    min_score: u8,
+    user_inputs: &[&str],
+) -> Result<PasswordStrength, ValidationFailure> {
+    let strength = estimate_password_strength(value, user_inputs);
+
+    if strength.score < min_score {
+        let message = strength
+            .warning
+            .clone()
+            .unwrap_or_else(|| "Password is too weak".to_string());
+        return Err(ValidationFailure::new(CODE_WEAK_PASSWORD, message));
+    }
+
+    Ok(strength)
+}
+
+/// Rejects passwords with score below `min_score`. Score and suggestions are returned in `data`.
+pub fn validate_password_strength(
+    ctx: &HttpContext,
+    value: &str,
+    min_score: u8,
+    user_inputs: &[&str],
+) -> Result<(), HttpFailResult> {
+    let strength = estimate_password_strength(value, user_inputs);
+
+    if strength.score >= min_score {
+        return Ok(());
+    }
+
+    let feedback: PasswordStrengthFeedback = strength.into();
+
+    Err(HttpFailResult::new(
+        WebContentType::Json,
+        400,
+        serde_json::to_vec(&ApiHttpResultWithData {
+            result: ApiResultStatus::RequestIsNoValid,
+            data: Some(feedback),
+        })
+        .unwrap(),
+        true,
+        true,
+    )
+    .with_trace_id(ctx))
+}
+
+fn get_warning(pattern: PasswordPattern) -> &'static str {
+    match pattern {
+        PasswordPattern::CommonPassword => "This is a very common password",
+        PasswordPattern::Dictionary => "Common words and passwords are easy to guess",
+        PasswordPattern::UserInput => "Password contains your personal data",
+        PasswordPattern::KeyboardWalk => "Straight rows of keys are easy to guess",
+        PasswordPattern::Sequence => "Sequences like abc or 6543 are easy to guess",
+        PasswordPattern::Repeat => "Repeats like \"aaa\" or \"abcabc\" are easy to guess",
+        PasswordPattern::Date => "Dates are often easy to guess",
+    }
+}
+
+fn get_suggestion(pattern: PasswordPattern) -> &'static str {
+    match pattern {
+        PasswordPattern::CommonPassword | PasswordPattern::Dictionary => {
+            "Avoid common words and passwords, predictable substitutions like '@' for 'a' do not help much"
+        }
+        PasswordPattern::UserInput => "Avoid your name, username or email",
+        PasswordPattern::KeyboardWalk => "Use a longer keyboard pattern with more turns",
+        PasswordPattern::Sequence => "Avoid sequences",
+        PasswordPattern::Repeat => "Avoid repeated words and characters",
+        PasswordPattern::Date => "Avoid dates and years that are associated with you",
+    }
+}
+
+fn to_lower(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+fn unleet(c: char) -> char {
+    match c {
+        '@' | '4' => 'a',
+        '8' => 'b',
+        '(' => 'c',
+        '3' => 'e',
+        '6' | '9' => 'g',
+        '1' | '!' | '|' => 'i',
+        '0' => 'o',
+        '$' | '5' => 's',
+        '7' | '+' => 't',
+        '2' => 'z',
+        _ => c,
+    }
+}
+
+// "Password1!", "qwerty123" and "P@ssw0rd" are the same password for an attacker
+fn is_common_as_whole(lower: &[char]) -> bool {
+    let as_is: String = lower.iter().collect();
+    if COMMON_PASSWORDS.contains(&as_is) {
+        return true;
+    }
+
+    let unleeted: String = lower.iter().map(|c| unleet(*c)).collect();
+    if COMMON_PASSWORDS.contains(&unleeted) {
+        return true;
+    }
+
+    let stripped = as_is.trim_end_matches(|c: char| !c.is_alphabetic());
+    stripped.chars().count() >= MIN_DICTIONARY_WORD_LEN && COMMON_PASSWORDS.contains(stripped)
+}
+
+fn find_dictionary_matches(chars: &[char], lower: &[char], matches: &mut Vec<PatternMatch>) {
+    for dictionary in [&*COMMON_PASSWORDS, &*ENGLISH_WORDS] {
+        find_words(dictionary, chars, lower, matches);
+    }
+}
+
+// A word costs the bits of picking it from its dictionary, plus its capitalization and leet
+fn find_words(
+    dictionary: &HashSet<String>,
+    chars: &[char],
+    lower: &[char],
+    matches: &mut Vec<PatternMatch>,
+) {
+    let dictionary_bits = (dictionary.len() as f64).log2();
+    let unleeted: Vec<char> = lower.iter().map(|c| unleet(*c)).collect();
+
+    for start in 0..lower.len() {
+        let max_end = lower.len().min(start + MAX_DICTIONARY_WORD_LEN);
+
+        for end in (start + MIN_DICTIONARY_WORD_LEN)..=max_end {
+            let as_is: String = lower[start..end].iter().collect();
+            let candidate: String = unleeted[start..end].iter().collect();
+
+            let is_leet = if dictionary.contains(&as_is) {
+                false
+            } else if dictionary.contains(&candidate) {
+                true
+            } else {
+                continue;
+            };
+
+            let mut bits = dictionary_bits;
+
+            let word = &chars[start..end];
+            let upper_count = word.iter().filter(|c| c.is_uppercase()).count();
+            if upper_count > 0 {
+                let is_capitalized = upper_count == 1 && word[0].is_uppercase();
+                bits += if is_capitalized || upper_count == word.len() {
+                    1.0
+                } else {
+                    word.len() as f64
+                };
+            }
+
+            if is_leet {
+                bits += 1.0;
+            }
+
+            matches.push(PatternMatch {
+                start,
+                end,
+                bits,
+                pattern: PasswordPattern::Dictionary,
+            });
+        }
+    }
+}
+
+fn find_user_input_matches(lower: &[char], user_inputs: &[&str], matches: &mut Vec<PatternMatch>) {
+    for input in user_inputs {
+        for part in input.to_lowercase().split(|c: char| !c.is_alphanumeric()) {
+            let part: Vec<char> = part.chars().collect();
+            if part.len() < MIN_USER_INPUT_LEN || part.len() > lower.len() {
+                continue;
+            }
+
+            for start in 0..=(lower.len() - part.len()) {
+                if lower[start..start + part.len()] == part[..] {
+                    matches.push(PatternMatch {
+                        start,
+                        end: start + part.len(),
+                        bits: 1.0,
+                        pattern: PasswordPattern::UserInput,
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn get_key_position(c: char) -> Option<(usize, usize)> {
+    KEYBOARD_ROWS
+        .iter()
+        .enumerate()
+        .find_map(|(row, keys)| keys.chars().position(|key| key == c).map(|col| (row, col)))
+}
+
+fn are_keys_adjacent(a: char, b: char) -> bool {
+    let (Some((row_a, col_a)), Some((row_b, col_b))) = (get_key_position(a), get_key_position(b))
+    else {
+        return false;
+    };
+
+    if row_a == row_b {
+        return col_a.abs_diff(col_b) == 1;
+    }
+
+    // Rows are shifted half a key to the right, so "1qaz" and "2wsx" are columns
+    row_a.abs_diff(row_b) == 1 && col_a == col_b
+}
+
+fn find_keyboard_walks(lower: &[char], matches: &mut Vec<PatternMatch>) {
+    find_runs(
+        lower,
+        MIN_KEYBOARD_WALK_LEN,
+        are_keys_adjacent,
+        |start, end| {
+            let keys_count: usize = KEYBOARD_ROWS.iter().map(|row| row.len()).sum();
+            matches.push(PatternMatch {
+                start,
+                end,
+                bits: (keys_count as f64).log2() + (end - start - 1) as f64,
+                pattern: PasswordPattern::KeyboardWalk,
+            });
+        },
+    );
+}
+
+fn find_sequences(lower: &[char], matches: &mut Vec<PatternMatch>) {
+    for direction in [1i64, -1] {
+        let is_next = |a: char, b: char| {
+            a.is_ascii_alphanumeric()
+                && b.is_ascii_alphanumeric()
+                && b as i64 - a as i64 == direction
+        };
+
+        find_runs(lower, MIN_SEQUENCE_LEN, is_next, |start, end| {
+            let alphabet = if lower[start].is_ascii_digit() {
+                10.0f64
+            } else {
+                26.0
+            };
+            matches.push(PatternMatch {
+                start,
+                end,
+                bits: alphabet.log2() + 1.0 + ((end - start) as f64).log2(),
+                pattern: PasswordPattern::Sequence,
+            });
+        });
+    }
+}
+
+fn find_repeats(lower: &[char], matches: &mut Vec<PatternMatch>) {
+    find_runs(
+        lower,
+        MIN_REPEAT_LEN,
+        |a, b| a == b,
+        |start, end| {
+            matches.push(PatternMatch {
+                start,
+                end,
+                bits: get_char_class_size(lower[start]).log2() + ((end - start) as f64).log2(),
+                pattern: PasswordPattern::Repeat,
+            });
+        },
+    );
+
+    for unit_len in 2..=(lower.len() / 2) {
+        for start in 0..=(lower.len() - unit_len * 2) {
+            let unit = &lower[start..start + unit_len];
+            let mut end = start + unit_len;
+
+            while end + unit_len <= lower.len() && &lower[end..end + unit_len] == unit {
+                end += unit_len;
+            }
+
+            let count = (end - start) / unit_len;
+            if count < 2 {
+                continue;
+            }
+
+            let unit_bits: f64 = unit.iter().map(|c| get_char_class_size(*c).log2()).sum();
+
+            matches.push(PatternMatch {
+                start,
+                end,
+                bits: unit_bits + (count as f64).log2(),
+                pattern: PasswordPattern::Repeat,
+            });
+        }
+    }
+}
+
+fn find_dates(chars: &[char], matches: &mut Vec<PatternMatch>) {
+    // 365 days in ~120 years
+    let date_bits = (365.0f64 * 120.0).log2();
+    let year_bits = 120.0f64.log2();
+
+    for start in 0..chars.len() {
+        for len in [4usize, 6, 8, 10] {
+            let end = start + len;
+            if end > chars.len() {
+                break;
+            }
+
+            let candidate: String = chars[start..end].iter().collect();
+
+            let bits = if len == 4 && is_year(&candidate) {
+                year_bits
+            } else if is_date(&candidate) {
+                date_bits
+            } else {
+                continue;
+            };
+
+            matches.push(PatternMatch {
+                start,
+                end,
+                bits,
+                pattern: PasswordPattern::Date,
+            });
+        }
+    }
+}
+
+fn is_year(src: &str) -> bool {
+    match src.parse::<u32>() {
+        Ok(year) => src.len() == 4 && (1900..=2039).contains(&year),
+        Err(_) => false,
+    }
+}
+
+fn is_day_month(day: &str, month: &str) -> bool {
+    let (Ok(day), Ok(month)) = (day.parse::<u32>(), month.parse::<u32>()) else {
+        return false;
+    };
+
+    (1..=31).contains(&day) && (1..=12).contains(&month)
+}
+
+fn is_date(src: &str) -> bool {
+    // Separators are counted in bytes below
+    if !src.is_ascii() {
+        return false;
+    }
+
+    let digits: String = src.chars().filter(|c| c.is_ascii_digit()).collect();
+    let separators = src.len() - digits.len();
+
+    let has_valid_separators = match separators {
+        0 => true,
+        2 => {
+            let separators: Vec<char> = src.chars().filter(|c| !c.is_ascii_digit()).collect();
+            separators[0] == separators[1] && ['.', '-', '/', '_'].contains(&separators[0])
+        }
+        _ => false,
+    };
+
+    if !has_valid_separators {
+        return false;
+    }
+
+    match digits.len() {
+        // ddmmyy
+        6 if separators == 0 => is_day_month(&digits[0..2], &digits[2..4]),
+        // ddmmyyyy, mmddyyyy, yyyymmdd
+        8 => {
+            (is_day_month(&digits[0..2], &digits[2..4]) && is_year(&digits[4..8]))
+                || (is_day_month(&digits[2..4], &digits[0..2]) && is_year(&digits[4..8]))
+                || (is_year(&digits[0..4]) && is_day_month(&digits[6..8], &digits[4..6]))
+        }
+        _ => false,
+    }
+}
+
+fn find_runs(
+    src: &[char],
+    min_len: usize,
+    is_next: impl Fn(char, char) -> bool,
+    mut on_run: impl FnMut(usize, usize),
+) {
+    let mut start = 0;
+
+    for index in 1..=src.len() {
+        if index < src.len() && is_next(src[index - 1], src[index]) {
+            continue;
+        }
+
+        if index - start >= min_len {
+            on_run(start, index);
+        }
+
+        start = index;
+    }
+}
+
+fn get_char_class_size(c: char) -> f64 {
+    if c.is_ascii_digit() {
+        10.0
+    } else if c.is_ascii_lowercase() || c.is_ascii_uppercase() {
+        26.0
+    } else if c.is_ascii() {
+        33.0
+    } else {
+        100.0
+    }
+}
+
+fn get_brute_force_bits_per_char(chars: &[char]) -> f64 {
+    let mut size = 0.0;
+
+    if chars.iter().any(|c| c.is_ascii_lowercase()) {
+        size += 26.0;
+    }
+
+    if chars.iter().any(|c| c.is_ascii_uppercase()) {
+        size += 26.0;
+    }
+
+    if chars.iter().any(|c| c.is_ascii_digit()) {
+        size += 10.0;
+    }
+
+    if chars
+        .iter()
+        .any(|c| c.is_ascii() && !c.is_ascii_alphanumeric())
+    {
+        size += 33.0;
+    }
+
+    if chars.iter().any(|c| !c.is_ascii()) {
+        size += 100.0;
+    }
+
+    f64::max(size, 10.0).log2()
+}
+
+fn get_cheapest_cover(chars: &[char], matches: &[PatternMatch]) -> (f64, Vec<PasswordPattern>) {
+    let brute_force_bits = get_brute_force_bits_per_char(chars);
+
+    // best[i] - cheapest way to produce first i characters, with the match used to get there
+    let mut best: Vec<(f64, Option<usize>)> = vec![(f64::MAX, None); chars.len() + 1];
+    best[0] = (0.0, None);
+
+    for end in 1..=chars.len() {
+        best[end] = (best[end - 1].0 + brute_force_bits, None);
+
+        for (index, pattern_match) in matches.iter().enumerate() {
+            if pattern_match.end != end {
+                continue;
+            }
+
+            let bits = best[pattern_match.start].0 + pattern_match.bits;
+            if bits < best[end].0 {
+                best[end] = (bits, Some(index));
+            }
+        }
+    }
+
+    let mut patterns = Vec::new();
+    let mut position = chars.len();
+
+    while position > 0 {
+        match best[position].1 {
+            Some(index) => {
+                patterns.push(matches[index].pattern);
+                position = matches[index].start;
+            }
+            None => position -= 1,
+        }
+    }
+
+    patterns.reverse();
+
+    (best[chars.len()].0, patterns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn common_passwords_are_bundled() {
+        assert!(is_common_password("password"));
+        assert!(is_common_password("Qwerty123"));
+        assert!(!is_common_password("correct-horse-battery-staple"));
+    }
+
+    #[test]
+    fn common_password_variations_score_zero() {
+        for password in [
+            "Password1!",
+            "P@ssw0rd",
+            "qwerty123",
+            "Welcome1!",
+            "Iloveyou2024",
+        ] {
+            let strength = estimate_password_strength(password, &[]);
+            assert_eq!(strength.score, 0, "{}", password);
+            assert_eq!(
+                strength.patterns,
+                vec![PasswordPattern::CommonPassword],
+                "{}",
+                password
+            );
+            assert!(strength.warning.is_some());
+        }
+    }
+
+    #[test]
+    fn detects_patterns() {
+        let strength = estimate_password_strength("Kx!sdfghjk", &[]);
+        assert!(strength.patterns.contains(&PasswordPattern::KeyboardWalk));
+
+        let strength = estimate_password_strength("Kx#mnopqr", &[]);
+        assert!(strength.patterns.contains(&PasswordPattern::Sequence));
+
+        let strength = estimate_password_strength("Kx!aaaaaaa", &[]);
+        assert!(strength.patterns.contains(&PasswordPattern::Repeat));
+
+        let strength = estimate_password_strength("Kx!14.07.1989", &[]);
+        assert!(strength.patterns.contains(&PasswordPattern::Date));
+
+        let strength = estimate_password_strength("Monkey#Kx7", &[]);
+        assert!(strength.patterns.contains(&PasswordPattern::Dictionary));
+
+        let strength = estimate_password_strength("Johnsmith#7", &["john.smith@test.tt"]);
+        assert!(strength.patterns.contains(&PasswordPattern::UserInput));
+    }
+
+    #[test]
+    fn patterns_lower_the_score() {
+        let weak = estimate_password_strength("Qwertyuiop!1", &[]);
+        let strong = estimate_password_strength("Vq7!mZ2#pL9x", &[]);
+
+        assert!(weak.score < strong.score);
+        assert_eq!(strong.score, MAX_PASSWORD_SCORE);
+        assert!(strong.suggestions.is_empty());
+        assert!(!weak.suggestions.is_empty());
+    }
+
+    #[test]
+    fn check_rejects_below_min_score() {
+        let failure = check_password_strength("Password1!", 2, &[]).unwrap_err();
+        assert_eq!(failure.code, CODE_WEAK_PASSWORD);
+        assert_eq!(failure.message, "This is a very common password");
+
+        assert!(check_password_strength("Vq7!mZ2#pL9x", 3, &[]).is_ok());
+    }
+
+    #[test]
+    fn long_input_is_cut() {
+        let started = std::time::Instant::now();
+
+        let strength = estimate_password_strength(&"ab".repeat(50_000), &["ab"]);
+        assert!(strength.score < 3, "{:?}", strength);
+
+        let strength = estimate_password_strength(&"Vq7!mZ2#pL9x".repeat(10_000), &[]);
+        assert_eq!(strength.score, MAX_PASSWORD_SCORE);
+
+        assert!(started.elapsed() < std::time::Duration::from_secs(2));
+    }
+
+    #[test]
+    fn detects_english_words() {
+        assert!(!is_common_password("goshawk"));
+
+        let strength = estimate_password_strength("G0shawk#Kx7", &[]);
+        assert!(strength.patterns.contains(&PasswordPattern::Dictionary));
+
+        let random = estimate_password_strength("Gqzhwvk#Kx7", &[]);
+        assert!(strength.entropy_bits < random.entropy_bits);
+    }
+
+    #[test]
+    fn non_ascii_date_candidates_do_not_panic() {
+        for value in ["1234567é", "12é45678"] {
+            let strength = estimate_password_strength(value, &[]);
+            assert!(
+                !strength.patterns.contains(&PasswordPattern::Date),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn long_random_passphrase_is_strong() {
+        let strength = estimate_password_strength("blue-Tundra-4-ostrich-lamp", &[]);
+        assert!(strength.score >= 3, "{:?}", strength);
+    }
+}
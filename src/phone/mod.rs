@@ -0,0 +1,2 @@
+mod phone_number;
+pub use phone_number::*;
@@ -0,0 +1,236 @@
+use phonenumber::{metadata::DATABASE, Mode, PhoneNumber, Type};
+use rust_common::country_code::CountryCode;
+use service_sdk::my_http_server::{HttpContext, HttpFailResult};
+
+use crate::{
+    get_country_iso2, into_http_fail, ValidationFailure, CODE_EMPTY, CODE_INVALID_FORMAT,
+    CODE_TRIM_SPACES,
+};
+
+pub const CODE_PHONE_NOT_MOBILE: &str = "phone_not_mobile";
+pub const CODE_PHONE_COUNTRY_MISMATCH: &str = "phone_country_mismatch";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhoneNumberType {
+    Mobile,
+    FixedLine,
+    /// Regions like US/CA where mobile and landline numbers can not be told apart
+    FixedLineOrMobile,
+    Voip,
+    TollFree,
+    Other,
+}
+
+impl PhoneNumberType {
+    pub fn can_receive_sms(&self) -> bool {
+        matches!(
+            self,
+            PhoneNumberType::Mobile | PhoneNumberType::FixedLineOrMobile
+        )
+    }
+}
+
+impl From<Type> for PhoneNumberType {
+    fn from(value: Type) -> Self {
+        match value {
+            Type::Mobile => PhoneNumberType::Mobile,
+            Type::FixedLine => PhoneNumberType::FixedLine,
+            Type::FixedLineOrMobile => PhoneNumberType::FixedLineOrMobile,
+            Type::Voip => PhoneNumberType::Voip,
+            Type::TollFree => PhoneNumberType::TollFree,
+            _ => PhoneNumberType::Other,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizedPhone {
+    /// `+<country code><national number>` without separators
+    pub e164: String,
+    pub number_type: PhoneNumberType,
+    /// ISO 3166-1 alpha-2 region the number belongs to
+    pub region: Option<String>,
+    number: PhoneNumber,
+}
+
+impl NormalizedPhone {
+    /// True if the number belongs to `iso2` region. Regions sharing the
+    /// calling code (+1, +44, +7...) are checked against the region number plan.
+    pub fn is_from_region(&self, iso2: &str) -> bool {
+        if let Some(region) = &self.region {
+            if region.eq_ignore_ascii_case(iso2) {
+                return true;
+            }
+        }
+
+        let Some(meta) = DATABASE.by_id(iso2.to_uppercase().as_str()) else {
+            return false;
+        };
+
+        if meta.country_code() != self.number.code().value() {
+            return false;
+        }
+
+        let national = self.number.national().to_string();
+        let descriptors = meta.descriptors();
+
+        [
+            descriptors.fixed_line(),
+            descriptors.mobile(),
+            descriptors.voip(),
+            descriptors.toll_free(),
+            descriptors.personal_number(),
+            descriptors.uan(),
+        ]
+        .into_iter()
+        .flatten()
+        .any(|descriptor| descriptor.is_match(&national))
+    }
+}
+
+pub fn normalize_phone(value: &str) -> Result<NormalizedPhone, ValidationFailure> {
+    if value.is_empty() {
+        return Err(ValidationFailure::new(
+            CODE_EMPTY,
+            "Phone: Should not be empty",
+        ));
+    }
+
+    if value.trim() != value {
+        return Err(ValidationFailure::new(
+            CODE_TRIM_SPACES,
+            "Phone: Should not start or end with space",
+        ));
+    }
+
+    let number = match phonenumber::parse(None, value) {
+        Ok(number) if number.is_valid() => number,
+        _ => {
+            return Err(ValidationFailure::new(
+                CODE_INVALID_FORMAT,
+                "Phone is not valid!",
+            ))
+        }
+    };
+
+    Ok(NormalizedPhone {
+        e164: number.format().mode(Mode::E164).to_string(),
+        number_type: number.number_type(&DATABASE).into(),
+        region: number.country().id().map(|id| id.as_ref().to_string()),
+        number,
+    })
+}
+
+/// For SMS OTP flows: the number has to be able to receive SMS.
+pub fn normalize_mobile_phone(value: &str) -> Result<NormalizedPhone, ValidationFailure> {
+    let phone = normalize_phone(value)?;
+
+    if !phone.number_type.can_receive_sms() {
+        return Err(ValidationFailure::new(
+            CODE_PHONE_NOT_MOBILE,
+            "Phone: Should be a mobile number",
+        ));
+    }
+
+    Ok(phone)
+}
+
+pub fn check_phone_country(
+    phone: &NormalizedPhone,
+    country: &CountryCode,
+) -> Result<(), ValidationFailure> {
+    let is_from_country = get_country_iso2(country)
+        .map(|iso2| phone.is_from_region(iso2))
+        .unwrap_or(false);
+
+    if !is_from_country {
+        return Err(ValidationFailure::new(
+            CODE_PHONE_COUNTRY_MISMATCH,
+            "Phone: Should belong to the selected country",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Returns the number in E.164 form on success.
+pub fn validate_phone_normalized(ctx: &HttpContext, value: &str) -> Result<String, HttpFailResult> {
+    into_http_fail(ctx, normalize_phone(value)).map(|phone| phone.e164)
+}
+
+/// Returns the number in E.164 form on success.
+pub fn validate_mobile_phone(ctx: &HttpContext, value: &str) -> Result<String, HttpFailResult> {
+    into_http_fail(ctx, normalize_mobile_phone(value)).map(|phone| phone.e164)
+}
+
+/// Returns the number in E.164 form on success.
+pub fn validate_phone_with_country(
+    ctx: &HttpContext,
+    value: &str,
+    country: &CountryCode,
+) -> Result<String, HttpFailResult> {
+    let phone = into_http_fail(ctx, normalize_phone(value))?;
+    into_http_fail(ctx, check_phone_country(&phone, country))?;
+
+    Ok(phone.e164)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_to_e164() {
+        let phone = normalize_phone("+1-202-555-0173").unwrap();
+        assert_eq!(phone.e164, "+12025550173");
+
+        let phone = normalize_phone("+49 30 901820").unwrap();
+        assert_eq!(phone.e164, "+4930901820");
+        assert_eq!(phone.number_type, PhoneNumberType::FixedLine);
+        assert_eq!(phone.region.as_deref(), Some("DE"));
+
+        assert!(normalize_phone("+359111").is_err());
+        assert!(normalize_phone(" +4930901820").is_err());
+    }
+
+    #[test]
+    fn detects_mobile_numbers() {
+        assert!(normalize_mobile_phone("+380 50 123 4567").is_ok());
+        assert!(normalize_mobile_phone("+1 212 555 1234").is_ok());
+
+        assert_eq!(
+            normalize_mobile_phone("+49 30 901820").unwrap_err().code,
+            CODE_PHONE_NOT_MOBILE
+        );
+        assert_eq!(
+            normalize_mobile_phone("+1 800 555 0199").unwrap_err().code,
+            CODE_PHONE_NOT_MOBILE
+        );
+    }
+
+    #[test]
+    fn checks_phone_country() {
+        let phone = normalize_phone("+49 30 901820").unwrap();
+        assert!(check_phone_country(&phone, &CountryCode::parse("DE").unwrap()).is_ok());
+        assert_eq!(
+            check_phone_country(&phone, &CountryCode::parse("AT").unwrap())
+                .unwrap_err()
+                .code,
+            CODE_PHONE_COUNTRY_MISMATCH
+        );
+    }
+
+    #[test]
+    fn checks_regions_sharing_calling_code() {
+        let toronto = normalize_phone("+1 416 555 0134").unwrap();
+        assert!(toronto.is_from_region("CA"));
+        assert!(!toronto.is_from_region("US"));
+
+        let uk_mobile = normalize_phone("+44 7400 123456").unwrap();
+        assert!(uk_mobile.is_from_region("GB"));
+
+        let guernsey_mobile = normalize_phone("+44 7911 123456").unwrap();
+        assert_eq!(guernsey_mobile.region.as_deref(), Some("GG"));
+        assert!(!guernsey_mobile.is_from_region("GB"));
+    }
+}
@@ -0,0 +1,16 @@
+service_sdk::macros::use_my_http_server!();
+use my_http_server::HttpContext;
+
+pub const KV_REQUEST_ID: &str = "REQUEST_ID";
+pub const HEADER_REQUEST_ID: &str = "X-Request-Id";
+
+pub trait GetRequestId {
+    fn get_request_id(&self) -> Option<&str>;
+}
+
+impl GetRequestId for HttpContext {
+    fn get_request_id(&self) -> Option<&str> {
+        let value = self.request.get_key_value(KV_REQUEST_ID)?;
+        std::str::from_utf8(value).ok()
+    }
+}
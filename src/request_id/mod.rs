@@ -0,0 +1,7 @@
+mod get_request_id;
+mod request_id_middleware;
+mod with_trace_id;
+
+pub use get_request_id::*;
+pub use request_id_middleware::*;
+pub use with_trace_id::*;
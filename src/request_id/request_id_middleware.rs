@@ -0,0 +1,71 @@
+service_sdk::macros::use_my_http_server!();
+
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpServerMiddleware};
+
+use super::{HEADER_REQUEST_ID, KV_REQUEST_ID};
+
+const MAX_REQUEST_ID_LEN: usize = 64;
+
+/// Takes `X-Request-Id` from the incoming request or generates a new one
+/// and keeps it in the request key-values under [`KV_REQUEST_ID`].
+/// The id is not echoed in the response headers: middlewares here only see the request.
+pub struct RequestIdMiddleware;
+
+#[async_trait::async_trait]
+impl HttpServerMiddleware for RequestIdMiddleware {
+    async fn handle_request(
+        &self,
+        ctx: &mut HttpContext,
+    ) -> Option<Result<HttpOkResult, HttpFailResult>> {
+        let request_id = ctx
+            .request
+            .get_headers()
+            .try_get_case_insensitive(HEADER_REQUEST_ID)
+            .and_then(|header| std::str::from_utf8(header.value).ok())
+            .filter(|value| is_valid_request_id(value))
+            .map(|value| value.to_string())
+            .unwrap_or_else(generate_request_id);
+
+        ctx.request
+            .set_key_value(KV_REQUEST_ID.to_string(), request_id.into_bytes());
+
+        None
+    }
+}
+
+pub fn generate_request_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+// Incoming ids end up in logs and response bodies, so only a safe subset is accepted
+fn is_valid_request_id(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= MAX_REQUEST_ID_LEN
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_' || b == b'.')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_regular_request_ids() {
+        assert!(is_valid_request_id("8c3f2b1e-0d4a-4f5b-9c1e-2a3b4c5d6e7f"));
+        assert!(is_valid_request_id("req_123.abc"));
+    }
+
+    #[test]
+    fn rejects_unsafe_request_ids() {
+        assert!(!is_valid_request_id(""));
+        assert!(!is_valid_request_id("abc def"));
+        assert!(!is_valid_request_id("abc\r\nX-Injected: 1"));
+        assert!(!is_valid_request_id(&"a".repeat(MAX_REQUEST_ID_LEN + 1)));
+    }
+
+    #[test]
+    fn generated_request_id_is_valid() {
+        assert!(is_valid_request_id(&generate_request_id()));
+    }
+}
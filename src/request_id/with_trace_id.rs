@@ -0,0 +1,86 @@
+service_sdk::macros::use_my_http_server!();
+
+use my_http_server::{HttpContext, HttpFailResult, WebContentType};
+
+use super::GetRequestId;
+
+pub const TRACE_ID_FIELD: &str = "traceId";
+
+/// Adds `traceId` to json bodies produced by [`crate::ApiResultStatus`],
+/// [`crate::ApiHttpResultWithData`] and [`crate::create_fail_http_result`].
+///
+/// Failures of the `validate_*` functions already carry it. Other handler results
+/// call `.with_trace_id(ctx)`. Auth error factories have no [`HttpContext`],
+/// so their bodies go without `traceId`.
+pub trait WithTraceId {
+    fn with_trace_id(self, ctx: &HttpContext) -> Self;
+}
+
+impl WithTraceId for HttpFailResult {
+    fn with_trace_id(mut self, ctx: &HttpContext) -> Self {
+        let Some(request_id) = ctx.get_request_id() else {
+            return self;
+        };
+
+        if !matches!(self.content_type, WebContentType::Json) {
+            return self;
+        }
+
+        if let Some(content) = inject_trace_id(&self.content, request_id) {
+            self.content = content;
+        }
+
+        self
+    }
+}
+
+impl<T> WithTraceId for Result<T, HttpFailResult> {
+    fn with_trace_id(self, ctx: &HttpContext) -> Self {
+        self.map_err(|err| err.with_trace_id(ctx))
+    }
+}
+
+fn inject_trace_id(content: &[u8], trace_id: &str) -> Option<Vec<u8>> {
+    let mut body: serde_json::Value = serde_json::from_slice(content).ok()?;
+    let object = body.as_object_mut()?;
+
+    if object.contains_key(TRACE_ID_FIELD) {
+        return None;
+    }
+
+    object.insert(
+        TRACE_ID_FIELD.to_string(),
+        serde_json::Value::String(trace_id.to_string()),
+    );
+
+    serde_json::to_vec(&body).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ApiHttpResultWithData, ApiResultStatus};
+
+    #[test]
+    fn trace_id_is_added_to_api_result() {
+        let content = serde_json::to_vec(&ApiHttpResultWithData::<String> {
+            result: ApiResultStatus::RequestIsNoValid,
+            data: Some("Invalid Email format".to_string()),
+        })
+        .unwrap();
+
+        let content = inject_trace_id(&content, "req-1").unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&content).unwrap();
+
+        assert_eq!(body["result"], -200);
+        assert_eq!(body["data"], "Invalid Email format");
+        assert_eq!(body["traceId"], "req-1");
+    }
+
+    #[test]
+    fn non_object_body_is_left_as_is() {
+        assert!(inject_trace_id(b"\"text\"", "req-1").is_none());
+        assert!(inject_trace_id(b"not json", "req-1").is_none());
+        assert!(inject_trace_id(br#"{"traceId":"req-0"}"#, "req-1").is_none());
+    }
+}
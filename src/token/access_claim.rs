@@ -1,18 +1,40 @@
+service_sdk::macros::use_my_http_server!();
+
 use std::fmt;
-use my_http_server_swagger::MyHttpIntegerEnum;
-use serde_repr::{Serialize_repr,Deserialize_repr};
 
-#[derive(Serialize_repr, Deserialize_repr, MyHttpIntegerEnum, Debug)]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use service_sdk::my_http_server::macros::MyHttpIntegerEnum;
+
+#[derive(Serialize_repr, Deserialize_repr, MyHttpIntegerEnum, Debug, Clone, Copy)]
 #[repr(i16)]
 pub enum AccessClaimType {
     #[http_enum_case(id="0"; description="EmailConfirmed")]
     EmailConfirmed,
 }
 
+impl AccessClaimType {
+    pub const ALL: [AccessClaimType; 1] = [AccessClaimType::EmailConfirmed];
+}
+
 impl fmt::Display for AccessClaimType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             AccessClaimType::EmailConfirmed => write!(f, "EmailConfirmed"),
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_lists_every_variant() {
+        let deserializable: Vec<i16> = (i16::MIN..=i16::MAX)
+            .filter(|id| serde_json::from_str::<AccessClaimType>(&id.to_string()).is_ok())
+            .collect();
+        let all: Vec<i16> = AccessClaimType::ALL.iter().map(|x| *x as i16).collect();
+
+        assert_eq!(all, deserializable);
+    }
+}
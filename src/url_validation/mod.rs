@@ -0,0 +1,5 @@
+mod url_validators;
+pub use url_validators::*;
+
+mod redirect_policy;
+pub use redirect_policy::*;
@@ -0,0 +1,5 @@
+mod validate;
+mod validation_errors;
+
+pub use validate::*;
+pub use validation_errors::*;
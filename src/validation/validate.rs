@@ -0,0 +1,72 @@
+use service_sdk::my_http_server::HttpFailResult;
+
+use super::ValidationErrors;
+
+/// Request model validation. Usually generated with `#[derive(Validate)]`.
+pub trait Validate {
+    fn validate_fields(&self, errors: &mut ValidationErrors);
+
+    fn validate(&self) -> Result<(), HttpFailResult> {
+        let mut errors = ValidationErrors::new();
+        self.validate_fields(&mut errors);
+        errors.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Validate;
+
+    #[derive(Validate, serde::Deserialize)]
+    struct RegisterRequest {
+        #[serde(rename = "email")]
+        #[validate(email)]
+        pub email: String,
+        #[validate(password)]
+        pub password: String,
+        #[validate(country)]
+        pub country: Option<String>,
+        #[validate(non_empty, max_len = 8)]
+        pub nickname: String,
+    }
+
+    #[test]
+    fn derived_validation_reports_each_field() {
+        let request = RegisterRequest {
+            email: "wrong".to_string(),
+            password: "Valid123!".to_string(),
+            country: Some("XX".to_string()),
+            nickname: "TooLongNickname".to_string(),
+        };
+
+        let mut errors = crate::ValidationErrors::new();
+        request.validate_fields(&mut errors);
+
+        let fields: Vec<(&str, &str)> = errors
+            .get_errors()
+            .iter()
+            .map(|e| (e.field.as_str(), e.code.as_str()))
+            .collect();
+
+        assert_eq!(
+            fields,
+            vec![
+                ("email", "invalid_format"),
+                ("country", "invalid_format"),
+                ("nickname", "max_length"),
+            ]
+        );
+    }
+
+    #[test]
+    fn derived_validation_skips_empty_optional() {
+        let request = RegisterRequest {
+            email: "test@test.tt".to_string(),
+            password: "Valid123!".to_string(),
+            country: None,
+            nickname: "Trader".to_string(),
+        };
+
+        assert!(request.validate().is_ok());
+    }
+}
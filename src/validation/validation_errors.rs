@@ -0,0 +1,140 @@
+service_sdk::macros::use_my_http_server!();
+
+use my_http_server::{HttpFailResult, WebContentType};
+use serde::Serialize;
+use service_sdk::my_http_server::macros::MyHttpObjectStructure;
+
+use crate::{ApiHttpResultWithData, ApiResultStatus};
+
+/// Result of a single check. `code` is stable and meant for clients, `message` is for humans.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationFailure {
+    pub code: &'static str,
+    pub message: String,
+}
+
+impl ValidationFailure {
+    pub fn new(code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Serialize, MyHttpObjectStructure, Debug, Clone, PartialEq, Eq)]
+pub struct ValidationFieldError {
+    pub field: String,
+    pub code: String,
+    pub message: String,
+}
+
+/// Collects failures of all fields so the client gets them in one response.
+#[derive(Debug, Default)]
+pub struct ValidationErrors {
+    errors: Vec<ValidationFieldError>,
+}
+
+impl ValidationErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn check(&mut self, field: &str, result: Result<(), ValidationFailure>) -> &mut Self {
+        if let Err(failure) = result {
+            self.add(field, failure);
+        }
+
+        self
+    }
+
+    pub fn check_optional<T: ?Sized>(
+        &mut self,
+        field: &str,
+        value: Option<&T>,
+        check: impl Fn(&T) -> Result<(), ValidationFailure>,
+    ) -> &mut Self {
+        if let Some(value) = value {
+            self.check(field, check(value));
+        }
+
+        self
+    }
+
+    pub fn add(&mut self, field: &str, failure: ValidationFailure) {
+        self.errors.push(ValidationFieldError {
+            field: field.to_string(),
+            code: failure.code.to_string(),
+            message: failure.message,
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn get_errors(&self) -> &[ValidationFieldError] {
+        &self.errors
+    }
+
+    pub fn into_result(self) -> Result<(), HttpFailResult> {
+        if self.errors.is_empty() {
+            return Ok(());
+        }
+
+        Err(self.into())
+    }
+}
+
+impl Into<HttpFailResult> for ValidationErrors {
+    fn into(self) -> HttpFailResult {
+        HttpFailResult::new(
+            WebContentType::Json,
+            400,
+            serde_json::to_vec(&ApiHttpResultWithData {
+                result: ApiResultStatus::RequestIsNoValid,
+                data: Some(self.errors),
+            })
+            .unwrap(),
+            true,
+            true,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check_email, check_name, check_phone};
+
+    #[test]
+    fn collects_failures_of_all_fields() {
+        let mut errors = ValidationErrors::new();
+
+        errors
+            .check("email", check_email("wrong"))
+            .check("firstName", check_name("Jhon"))
+            .check("phone", check_phone("1"))
+            .check_optional("lastName", Some("Doe1"), check_name);
+
+        let fields: Vec<&str> = errors.get_errors().iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["email", "phone", "lastName"]);
+
+        let result = errors.into_result().unwrap_err();
+        assert_eq!(result.status_code, 400);
+
+        let body: serde_json::Value = serde_json::from_slice(&result.content).unwrap();
+        assert_eq!(body["result"], -200);
+        assert_eq!(body["data"][0]["field"], "email");
+        assert_eq!(body["data"][0]["code"], "invalid_format");
+        assert_eq!(body["data"][0]["message"], "Invalid Email format");
+    }
+
+    #[test]
+    fn no_failures_is_ok() {
+        let mut errors = ValidationErrors::new();
+        errors.check("email", check_email("test@test.tt"));
+
+        assert!(errors.into_result().is_ok());
+    }
+}
@@ -1,105 +1,131 @@
 use std::str::FromStr;
 
+use lazy_static::lazy_static;
 use phonenumber::PhoneNumber;
-use service_sdk::{
-    my_http_server::{HttpContext, HttpFailResult},
-    rust_extensions::date_time::DateTimeAsMicroseconds,
+use rust_common::country_code::CountryCode;
+use service_sdk::my_http_server::{HttpContext, HttpFailResult};
+
+use crate::{
+    ApiHttpResultWithData, ApiResultStatus, CalendarDate, DateOfBirthPolicy, EmailAddress,
+    PasswordPolicy, PasswordUserIdentifiers, ValidationFailure, WithTraceId,
 };
 
-use crate::{ApiHttpResultWithData, ApiResultStatus};
+lazy_static! {
+    static ref DEFAULT_PASSWORD_POLICY: PasswordPolicy = PasswordPolicy::default();
+}
+
+pub const CODE_EMPTY: &str = "empty";
+pub const CODE_INVALID_FORMAT: &str = "invalid_format";
+pub const CODE_MIN_LENGTH: &str = "min_length";
+pub const CODE_MAX_LENGTH: &str = "max_length";
+pub const CODE_TRIM_SPACES: &str = "trim_spaces";
+pub const CODE_CYRILLIC: &str = "cyrillic";
+pub const CODE_UPPER_LETTER: &str = "upper_letter";
+pub const CODE_SPACE_CHARACTERS: &str = "space_characters";
+pub const CODE_SPECIAL_SYMBOL: &str = "special_symbol";
+pub const CODE_LATIN_LETTERS: &str = "latin_letters";
+pub const CODE_TOO_YOUNG: &str = "too_young";
 
-pub fn validate_non_empty(_ctx: &HttpContext, value: &str) -> Result<(), HttpFailResult> {
-    if validate_non_empty_text(value) {
+fn ensure(condition: bool, code: &'static str, message: &str) -> Result<(), ValidationFailure> {
+    if condition {
         return Ok(());
     }
 
-    Err(create_fail_http_result("Should not be empty"))
+    Err(ValidationFailure::new(code, message))
 }
 
-pub fn validate_email(_ctx: &HttpContext, value: &str) -> Result<(), HttpFailResult> {
-    if !validate_email_text(value) {
-        return Err(create_fail_http_result("Invalid Email format"));
+// Messages with parameters are built only on failure to keep the success path allocation free
+fn ensure_with(
+    condition: bool,
+    code: &'static str,
+    message: impl FnOnce() -> String,
+) -> Result<(), ValidationFailure> {
+    if condition {
+        return Ok(());
     }
 
-    if !validate_max(value, 64) {
-        return Err(create_fail_http_result("Max length is 64 symbols"));
-    }
+    Err(ValidationFailure::new(code, message()))
+}
 
-    if !validate_no_trimm_spaces(value) {
-        return Err(create_fail_http_result("Should not start or end with space"));
-    }
+// Validation failures carry the request id as `traceId`, see [`WithTraceId`]
+pub(crate) fn into_http_fail<T>(
+    ctx: &HttpContext,
+    result: Result<T, ValidationFailure>,
+) -> Result<T, HttpFailResult> {
+    result.map_err(|failure| create_fail_http_result(&failure.message).with_trace_id(ctx))
+}
 
-    if !validate_no_cyrillic(value) {
-        return Err(create_fail_http_result("No cyrillic letters are allowed"));
-    }
+pub fn check_non_empty(value: &str) -> Result<(), ValidationFailure> {
+    ensure(validate_non_empty_text(value), CODE_EMPTY, "Should not be empty")
+}
+
+pub fn validate_non_empty(ctx: &HttpContext, value: &str) -> Result<(), HttpFailResult> {
+    into_http_fail(ctx, check_non_empty(value))
+}
 
-    return Ok(());
+pub fn check_email(value: &str) -> Result<(), ValidationFailure> {
+    ensure(validate_email_text(value), CODE_INVALID_FORMAT, "Invalid Email format")?;
+    ensure(validate_max(value, 64), CODE_MAX_LENGTH, "Max length is 64 symbols")?;
+    ensure(validate_no_trimm_spaces(value), CODE_TRIM_SPACES, "Should not start or end with space")?;
+    ensure(validate_no_cyrillic(value), CODE_CYRILLIC, "No cyrillic letters are allowed")
+}
+
+pub fn check_max_len(value: &str, max: usize) -> Result<(), ValidationFailure> {
+    ensure_with(validate_max(value, max), CODE_MAX_LENGTH, || format!("Max length is {} symbols", max))
+}
+
+pub fn check_min_len(value: &str, min: usize) -> Result<(), ValidationFailure> {
+    ensure_with(validate_min(value, min), CODE_MIN_LENGTH, || format!("Min length is {} symbols", min))
+}
+
+pub fn validate_email(ctx: &HttpContext, value: &str) -> Result<(), HttpFailResult> {
+    into_http_fail(ctx, check_email(value))
 }
 
 pub fn validate_email_optional(
-    _ctx: &HttpContext,
+    ctx: &HttpContext,
     value: &Option<String>,
 ) -> Result<(), HttpFailResult> {
     match value {
         Some(value) => {
-            return validate_email(_ctx, value);
+            return validate_email(ctx, value);
         }
         None => Ok(()),
     }
 }
 
-pub fn validate_password(_ctx: &HttpContext, value: &str) -> Result<(), HttpFailResult> {
+pub fn validate_password(ctx: &HttpContext, value: &str) -> Result<(), HttpFailResult> {
     match validate_password_conditions(value) {
         Ok(_) => Ok(()),
-        Err(err_msg) => Err(HttpFailResult::as_validation_error(err_msg)),
+        Err(err_msg) => Err(HttpFailResult::as_validation_error(err_msg).with_trace_id(ctx)),
     }
 }
 
 pub fn validate_password_conditions(value: &str) -> Result<(), String> {
-
-    let checks: &[(fn(&str) -> bool, &str)] = &[
-        (|v| validate_min(v, 8), "Min length is 8 symbols"),
-        (|v| validate_max(v, 50), "Max length is 50 symbols"),
-        (validate_no_trimm_spaces, "Should not start or end with space"),
-        (validate_no_cyrillic, "No cyrillic letters are allowed"),
-        (contains_upper_letter, "Must contain upper letter"),
-        (contains_no_space_characters, "Password must contain no space characters"),
-        (contains_special_symbol, "Password must contain at least one special symbol"),
-    ];
-
-    for (check, message) in checks {
-        if !check(value) {
-            return Err(message.to_string());
-        }
-    }
-
-    Ok(())
+    check_password(value).map_err(|failure| failure.message)
 }
 
-pub fn validate_phone(_ctx: &HttpContext, value: &str) -> Result<(), HttpFailResult> {
-    if !validate_non_empty_text(value) {
-        return Err(create_fail_http_result("Phone: Should not be empty"));
-    }
-
-    if !validate_no_trimm_spaces(value) {
-        return Err(create_fail_http_result("Phone: Should not start or end with space"));
-    }
+pub fn check_password(value: &str) -> Result<(), ValidationFailure> {
+    DEFAULT_PASSWORD_POLICY.check(value, &PasswordUserIdentifiers::default())
+}
 
-    if validate_phone_text(value)
-    {
-        return Ok(());
-    }
+pub fn check_phone(value: &str) -> Result<(), ValidationFailure> {
+    ensure(validate_non_empty_text(value), CODE_EMPTY, "Phone: Should not be empty")?;
+    ensure(validate_no_trimm_spaces(value), CODE_TRIM_SPACES, "Phone: Should not start or end with space")?;
+    ensure(validate_phone_text(value), CODE_INVALID_FORMAT, "Phone is not valid!")
+}
 
-    Err(create_fail_http_result("Phone is not valid!"))
+pub fn validate_phone(ctx: &HttpContext, value: &str) -> Result<(), HttpFailResult> {
+    into_http_fail(ctx, check_phone(value))
 }
 
 pub fn validate_phone_optional(
-    _ctx: &HttpContext,
+    ctx: &HttpContext,
     value: &Option<String>,
 ) -> Result<(), HttpFailResult> {
     match value {
         Some(value) => {
-            return validate_phone(_ctx, value);
+            return validate_phone(ctx, value);
         }
         None => Ok(()),
     }
@@ -119,120 +145,102 @@ fn validate_phone_text(value: &str) -> bool {
     }
 }
 
-pub fn validate_name(_ctx: &HttpContext, value: &str) -> Result<(), HttpFailResult> {
-    if !validate_max(value, 32) {
-        return Err(create_fail_http_result("Name: Max length is 32 symbols"));
-    }
+pub fn check_name(value: &str) -> Result<(), ValidationFailure> {
+    check_name_with_max_len(value, 32)
+}
 
-    if !validate_no_trimm_spaces(value) {
-        return Err(create_fail_http_result("Should not start or end with space"));
-    }
-    
-    if !validate_latin_letters_with_spaces(value) {
-        return Err(create_fail_http_result("Name: Only latin letters are allowed"));
-    }
+pub fn check_name_with_max_len(value: &str, max: usize) -> Result<(), ValidationFailure> {
+    ensure_with(validate_max(value, max), CODE_MAX_LENGTH, || format!("Name: Max length is {} symbols", max))?;
+    ensure(validate_no_trimm_spaces(value), CODE_TRIM_SPACES, "Should not start or end with space")?;
+    ensure(validate_latin_letters_with_spaces(value), CODE_LATIN_LETTERS, "Name: Only latin letters are allowed")
+}
 
-    return Ok(());
+pub fn validate_name(ctx: &HttpContext, value: &str) -> Result<(), HttpFailResult> {
+    into_http_fail(ctx, check_name(value))
 }
 
 pub fn validate_name_optional(
-    _ctx: &HttpContext,
+    ctx: &HttpContext,
     value: &Option<String>,
 ) -> Result<(), HttpFailResult> {
     match value {
         Some(value) => {
-            return validate_name(_ctx, value);
+            return validate_name(ctx, value);
         }
         None => Ok(()),
     }
 }
 
-pub fn validate_name_with_spaces(_ctx: &HttpContext, value: &str) -> Result<(), HttpFailResult> {
-    if !validate_latin_letters_with_spaces(value) {
-        return Err(create_fail_http_result("Name: Only latin letters and spaces are allowed"));
-    }
-
-    if !validate_max(value, 32) {
-        return Err(create_fail_http_result("Name: Max length is 32 symbols"));
-    }
+pub fn check_name_with_spaces(value: &str) -> Result<(), ValidationFailure> {
+    ensure(validate_latin_letters_with_spaces(value), CODE_LATIN_LETTERS, "Name: Only latin letters and spaces are allowed")?;
+    ensure(validate_max(value, 32), CODE_MAX_LENGTH, "Name: Max length is 32 symbols")
+}
 
-    return Ok(());
+pub fn validate_name_with_spaces(ctx: &HttpContext, value: &str) -> Result<(), HttpFailResult> {
+    into_http_fail(ctx, check_name_with_spaces(value))
 }
 
 pub fn validate_name_with_spaces_optional(
-    _ctx: &HttpContext,
+    ctx: &HttpContext,
     value: &Option<String>,
 ) -> Result<(), HttpFailResult> {
     match value {
         Some(value) => {
-            return validate_name_with_spaces(_ctx, value);
+            return validate_name_with_spaces(ctx, value);
         }
         None => Ok(()),
     }
 }
 
+pub fn check_date_of_birth(value: &str) -> Result<(), ValidationFailure> {
+    DateOfBirthPolicy::default().check(value, None, CalendarDate::today())?;
+    Ok(())
+}
+
 pub fn validate_date_of_birth(
-    _ctx: &HttpContext,
+    ctx: &HttpContext,
     value: &str,
 ) -> Result<(), HttpFailResult> {
-    let value = match DateTimeAsMicroseconds::from_str(value) {
-        Some(x) => x,
-        None => return Err(create_fail_http_result("DateOfBirth: Not a valid date!")),
-    };
-
-    let now = DateTimeAsMicroseconds::now();
-    let diff = now.duration_since(value);
-
-    match diff {
-        service_sdk::rust_extensions::date_time::DateTimeDuration::Positive(x) => {
-            // turn secunds to years
-            let x = x.as_secs() / 60 / 60 / 24 / 365;
-            if x < 18 {
-                return Err(create_fail_http_result("DateOfBirth: Should be older than 18"));
-            }
-
-            return Ok(());
-        }
-        service_sdk::rust_extensions::date_time::DateTimeDuration::Negative(_) => {}
-        service_sdk::rust_extensions::date_time::DateTimeDuration::Zero => {}
-    }
-
-    return Err(create_fail_http_result("DateOfBirth: Should be older than 18"));
+    into_http_fail(ctx, check_date_of_birth(value))
 }
 
 pub fn validate_date_of_birth_optional(
-    _ctx: &HttpContext,
+    ctx: &HttpContext,
     value: &Option<String>,
 ) -> Result<(), HttpFailResult> {
     match value {
         Some(value) => {
-            return validate_date_of_birth(_ctx, value);
+            return validate_date_of_birth(ctx, value);
         }
         None => Ok(()),
     }
 }
 
-pub fn validate_address(
-    _ctx: &HttpContext,
+pub fn validate_date_of_birth_with_policy(
+    ctx: &HttpContext,
     value: &str,
-) -> Result<(), HttpFailResult> {
-    if !validate_max(value, 50) {
-        return Err(create_fail_http_result("Address: Max length is 50 symbols"));
-    }
-
-    if !validate_non_empty_text(value) {
-        return Err(create_fail_http_result("Address: Should not be empty"));
-    }
+    policy: &DateOfBirthPolicy,
+    country: Option<&CountryCode>,
+) -> Result<CalendarDate, HttpFailResult> {
+    into_http_fail(ctx, policy.check(value, country, CalendarDate::today()))
+}
 
-    if !validate_no_trimm_spaces(value) {
-        return Err(create_fail_http_result("Address: Should not start or end with space"));
-    }
+fn check_address_like(field: &str, value: &str, max: usize) -> Result<(), ValidationFailure> {
+    ensure_with(validate_max(value, max), CODE_MAX_LENGTH, || format!("{}: Max length is {} symbols", field, max))?;
+    ensure_with(validate_non_empty_text(value), CODE_EMPTY, || format!("{}: Should not be empty", field))?;
+    ensure_with(validate_no_trimm_spaces(value), CODE_TRIM_SPACES, || format!("{}: Should not start or end with space", field))?;
+    ensure_with(validate_no_cyrillic(value), CODE_CYRILLIC, || format!("{}: No cyrillic letters are allowed", field))
+}
 
-    if !validate_no_cyrillic(value) {
-        return Err(create_fail_http_result("Address: No cyrillic letters are allowed"));
-    }
+pub fn check_address(value: &str) -> Result<(), ValidationFailure> {
+    check_address_like("Address", value, 50)
+}
 
-    return Ok(());
+pub fn validate_address(
+    ctx: &HttpContext,
+    value: &str,
+) -> Result<(), HttpFailResult> {
+    into_http_fail(ctx, check_address(value))
 }
 
 pub fn validate_address_optional(
@@ -246,27 +254,15 @@ pub fn validate_address_optional(
     return validate_address(ctx, value);
 }
 
+pub fn check_city(value: &str) -> Result<(), ValidationFailure> {
+    check_address_like("City", value, 50)
+}
+
 pub fn validate_city(
-    _ctx: &HttpContext,
+    ctx: &HttpContext,
     value: &str,
 ) -> Result<(), HttpFailResult> {
-    if !validate_max(value, 50) {
-        return Err(create_fail_http_result("City: Max length is 50 symbols"));
-    }
-
-    if !validate_non_empty_text(value) {
-        return Err(create_fail_http_result("City: Should not be empty"));
-    }
-
-    if !validate_no_trimm_spaces(value) {
-        return Err(create_fail_http_result("City: Should not start or end with space"));
-    }
-
-    if !validate_no_cyrillic(value) {
-        return Err(create_fail_http_result("City: No cyrillic letters are allowed"));
-    }
-
-    return Ok(());
+    into_http_fail(ctx, check_city(value))
 }
 
 pub fn validate_city_optional(
@@ -280,27 +276,15 @@ pub fn validate_city_optional(
     return validate_city(ctx, value);
 }
 
+pub fn check_zip_code(value: &str) -> Result<(), ValidationFailure> {
+    check_address_like("ZipCode", value, 10)
+}
+
 pub fn validate_zip_code(
-    _ctx: &HttpContext,
+    ctx: &HttpContext,
     value: &str,
 ) -> Result<(), HttpFailResult> {
-    if !validate_max(value, 10) {
-        return Err(create_fail_http_result("ZipCode: Max length is 10 symbols"));
-    }
-
-    if !validate_non_empty_text(value) {
-        return Err(create_fail_http_result("ZipCode: Should not be empty"));
-    }
-
-    if !validate_no_trimm_spaces(value) {
-        return Err(create_fail_http_result("ZipCode: Should not start or end with space"));
-    }
-
-    if !validate_no_cyrillic(value) {
-        return Err(create_fail_http_result("ZipCode: No cyrillic letters are allowed"));
-    }
-
-    return Ok(());
+    into_http_fail(ctx, check_zip_code(value))
 }
 
 pub fn validate_zip_code_optional(
@@ -314,12 +298,23 @@ pub fn validate_zip_code_optional(
     return validate_zip_code(ctx, value);
 }
 
+fn is_latin_letter_or_hyphen(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '-'
+}
+
+/// Same as `^[a-zA-Z\-]*$`
 pub fn validate_latin_letters_only(src: &str) -> bool {
-    regex::Regex::new(r"^[a-zA-Z\-]*$").unwrap().is_match(src)
+    src.chars().all(is_latin_letter_or_hyphen)
 }
 
+/// Same as `^[a-zA-Z\-]+(\s+[a-zA-Z\-]+)*$`
 pub fn validate_latin_letters_with_spaces(src: &str) -> bool {
-    regex::Regex::new(r"^[a-zA-Z\-]+(\s+[a-zA-Z\-]+)*$").unwrap().is_match(src)
+    !src.is_empty()
+        && !src.starts_with(char::is_whitespace)
+        && !src.ends_with(char::is_whitespace)
+        && src
+            .chars()
+            .all(|c| c.is_whitespace() || is_latin_letter_or_hyphen(c))
 }
 
 pub fn validate_no_cyrillic(src: &str) -> bool {
@@ -354,9 +349,7 @@ pub fn validate_min(src: &str, min: usize) -> bool {
 }
 
 fn validate_email_text(src: &str) -> bool {
-    regex::Regex::new(r"^[^\s@]+@[^\s@]+\.[^\s@]+$")
-        .unwrap()
-        .is_match(src)
+    EmailAddress::is_valid(src)
 }
 
 const SPECIAL_SYMBOLS: [char; 13] = [
@@ -369,10 +362,7 @@ pub fn contains_no_space_characters(value: &str) -> bool {
 }
 
 pub fn contains_special_symbol(value: &str) -> bool {
-    value
-        .as_bytes()
-        .iter()
-        .any(|b| SPECIAL_SYMBOLS.iter().any(|c| *c as u8 == *b))
+    value.chars().any(|c| SPECIAL_SYMBOLS.contains(&c))
 }
 
 pub fn create_fail_http_result(error: &str) -> HttpFailResult {
@@ -424,6 +414,25 @@ mod tests {
         assert!(!validate_latin_letters_with_spaces("Jhon Doo  "));
     }
 
+    #[test]
+    fn latin_scanners_match_former_regexes() {
+        let letters_only = regex::Regex::new(r"^[a-zA-Z\-]*$").unwrap();
+        let letters_with_spaces = regex::Regex::new(r"^[a-zA-Z\-]+(\s+[a-zA-Z\-]+)*$").unwrap();
+
+        for value in [
+            "", " ", "Jhon", "Jhon Doe", "Jhon\tDoe", "Jhon \u{00A0}Doe", "Jean-Luc", "-", "Jhon ",
+            " Jhon", "José", "Jhon1", "Jhon_Doe", "Иван", "Jhon\nDoe", "Jhon  -  Doe",
+        ] {
+            assert_eq!(letters_only.is_match(value), validate_latin_letters_only(value), "{:?}", value);
+            assert_eq!(
+                letters_with_spaces.is_match(value),
+                validate_latin_letters_with_spaces(value),
+                "{:?}",
+                value
+            );
+        }
+    }
+
     #[test]
     fn valid_password_passes_all_checks() {
         let password = "Valid123!";
@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use rust_common::country_code::CountryCode;
+use service_sdk::my_http_server::{HttpContext, HttpFailResult};
+
+use super::{ZipCodeFormat, ZipCodeLayout, COUNTRIES_WITHOUT_ZIP_CODE, ZIP_CODE_FORMATS};
+use crate::{
+    check_zip_code, get_country_iso2, into_http_fail, ValidationFailure, CODE_EMPTY,
+    CODE_INVALID_FORMAT,
+};
+
+lazy_static! {
+    static ref ZIP_CODE_REGEXES: HashMap<&'static str, (Regex, &'static ZipCodeFormat)> = {
+        let mut map = HashMap::new();
+        for format in ZIP_CODE_FORMATS {
+            let regex = Regex::new(&format!("^(?:{})$", format.pattern)).unwrap();
+            map.insert(format.iso2, (regex, format));
+        }
+        map
+    };
+}
+
+pub fn country_has_zip_code(country: &CountryCode) -> bool {
+    match get_country_iso2(country) {
+        Some(iso2) => !COUNTRIES_WITHOUT_ZIP_CODE.contains(&iso2),
+        None => false,
+    }
+}
+
+/// Returns the zip code in the canonical form of the country: "sw1a1aa" -> "SW1A 1AA" for GB.
+/// Empty value is valid only for countries without postal codes.
+pub fn normalize_zip_code(country: &CountryCode, value: &str) -> Result<String, ValidationFailure> {
+    match get_country_iso2(country) {
+        Some(iso2) => normalize_zip_code_by_iso2(iso2, value),
+        None => normalize_unknown_zip_code(value),
+    }
+}
+
+fn normalize_zip_code_by_iso2(iso2: &str, value: &str) -> Result<String, ValidationFailure> {
+    let Some((regex, format)) = ZIP_CODE_REGEXES.get(iso2) else {
+        return normalize_unknown_zip_code(value);
+    };
+
+    if value.trim().is_empty() {
+        return Err(ValidationFailure::new(
+            CODE_EMPTY,
+            format!("ZipCode: Required for {}", iso2),
+        ));
+    }
+
+    let result = apply_layout(value, format.layout);
+
+    if !regex.is_match(&result) {
+        return Err(ValidationFailure::new(
+            CODE_INVALID_FORMAT,
+            format!(
+                "ZipCode: Invalid format for {}. Example: {}",
+                iso2, format.example
+            ),
+        ));
+    }
+
+    Ok(result)
+}
+
+// Countries without postal codes and countries missing in the table: any reasonable value is kept
+fn normalize_unknown_zip_code(value: &str) -> Result<String, ValidationFailure> {
+    let result = collapse_spaces(value);
+
+    if result.is_empty() {
+        return Ok(result);
+    }
+
+    check_zip_code(&result)?;
+
+    Ok(result)
+}
+
+fn collapse_spaces(value: &str) -> String {
+    value
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_uppercase()
+}
+
+fn apply_layout(value: &str, layout: ZipCodeLayout) -> String {
+    match layout {
+        ZipCodeLayout::AsIs => collapse_spaces(value),
+        ZipCodeLayout::Separator(separator, tail_len) => {
+            let compact: Vec<char> = value
+                .chars()
+                .filter(|c| !c.is_whitespace() && *c != '-')
+                .flat_map(char::to_uppercase)
+                .collect();
+
+            if compact.len() <= tail_len {
+                return compact.into_iter().collect();
+            }
+
+            let (head, tail) = compact.split_at(compact.len() - tail_len);
+
+            let mut result: String = head.iter().collect();
+            result.push(separator);
+            result.extend(tail);
+            result
+        }
+    }
+}
+
+pub fn validate_zip_code_for_country(
+    ctx: &HttpContext,
+    country: &CountryCode,
+    value: &str,
+) -> Result<String, HttpFailResult> {
+    into_http_fail(ctx, normalize_zip_code(country, value))
+}
+
+pub fn validate_zip_code_for_country_optional(
+    ctx: &HttpContext,
+    country: &CountryCode,
+    value: &Option<String>,
+) -> Result<Option<String>, HttpFailResult> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+
+    validate_zip_code_for_country(ctx, country, value).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_country_pairs;
+
+    #[test]
+    fn every_country_is_covered() {
+        for (name, iso2) in get_country_pairs() {
+            let has_format = ZIP_CODE_FORMATS.iter().any(|f| f.iso2 == iso2);
+            let without_zip = COUNTRIES_WITHOUT_ZIP_CODE.contains(&iso2);
+
+            assert!(has_format != without_zip, "{} {}", name, iso2);
+        }
+    }
+
+    #[test]
+    fn examples_are_valid() {
+        for format in ZIP_CODE_FORMATS {
+            assert_eq!(
+                normalize_zip_code_by_iso2(format.iso2, format.example).unwrap(),
+                format.example,
+                "{}",
+                format.iso2
+            );
+        }
+    }
+
+    #[test]
+    fn normalizes_to_canonical_form() {
+        let normalize = |iso2, value| normalize_zip_code_by_iso2(iso2, value).unwrap();
+
+        assert_eq!(normalize("GB", "sw1a1aa"), "SW1A 1AA");
+        assert_eq!(normalize("GB", " SW1A   1AA "), "SW1A 1AA");
+        assert_eq!(normalize("GB", "m1 1ae"), "M1 1AE");
+        assert_eq!(normalize("CA", "k1a0b1"), "K1A 0B1");
+        assert_eq!(normalize("NL", "1012ab"), "1012 AB");
+        assert_eq!(normalize("PL", "00950"), "00-950");
+        assert_eq!(normalize("PL", "00 - 950"), "00-950");
+        assert_eq!(normalize("JP", "1000001"), "100-0001");
+        assert_eq!(normalize("US", "10001-1234"), "10001-1234");
+        assert_eq!(normalize("DE", "10115"), "10115");
+    }
+
+    #[test]
+    fn rejects_wrong_formats() {
+        for (iso2, value) in [
+            ("GB", "12345"),
+            ("CA", "D1A 0B1"),
+            ("US", "1000"),
+            ("US", "100011"),
+            ("DE", "1011"),
+            ("NL", "0123 AB"),
+            ("ES", "53001"),
+            ("PL", "009501"),
+        ] {
+            let err = normalize_zip_code_by_iso2(iso2, value).unwrap_err();
+            assert_eq!(err.code, CODE_INVALID_FORMAT, "{} {}", iso2, value);
+            assert!(err.message.contains("Example"));
+        }
+    }
+
+    #[test]
+    fn empty_value_depends_on_country() {
+        assert_eq!(
+            normalize_zip_code_by_iso2("DE", "").unwrap_err().code,
+            CODE_EMPTY
+        );
+        assert_eq!(normalize_zip_code_by_iso2("AE", "").unwrap(), "");
+        assert_eq!(normalize_zip_code_by_iso2("HK", " ").unwrap(), "");
+        assert_eq!(
+            normalize_zip_code_by_iso2("AE", "po 123").unwrap(),
+            "PO 123"
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod zip_code_formats;
+pub use zip_code_formats::*;
+
+mod country_zip_code;
+pub use country_zip_code::*;
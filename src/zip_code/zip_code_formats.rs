@@ -0,0 +1,247 @@
+/// How the entered value is brought to the canonical form before matching.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZipCodeLayout {
+    /// Only uppercase and collapse of repeated spaces.
+    AsIs,
+    /// Spaces and hyphens are removed and `separator` is put before the last `tail_len` symbols:
+    /// "sw1a1aa" -> "SW1A 1AA", "00950" -> "00-950".
+    Separator(char, usize),
+}
+
+pub struct ZipCodeFormat {
+    pub iso2: &'static str,
+    /// Matched against the whole canonical value
+    pub pattern: &'static str,
+    pub example: &'static str,
+    pub layout: ZipCodeLayout,
+}
+
+const fn format(
+    iso2: &'static str,
+    pattern: &'static str,
+    example: &'static str,
+    layout: ZipCodeLayout,
+) -> ZipCodeFormat {
+    ZipCodeFormat {
+        iso2,
+        pattern,
+        example,
+        layout,
+    }
+}
+
+use ZipCodeLayout::{AsIs, Separator};
+
+const UK_PATTERN: &str = r"GIR 0AA|[A-PR-UWYZ][A-HK-Y]?\d[A-Z\d]? \d[ABD-HJLNP-UW-Z]{2}";
+const US_PATTERN: &str = r"\d{5}(-\d{4})?";
+
+pub const ZIP_CODE_FORMATS: &[ZipCodeFormat] = &[
+    format("AF", r"\d{4}", "1001", AsIs),
+    format("AX", r"22\d{3}", "22100", AsIs),
+    format("AL", r"\d{4}", "1001", AsIs),
+    format("DZ", r"\d{5}", "16000", AsIs),
+    format("AS", r"96799(-\d{4})?", "96799", AsIs),
+    format("AD", r"AD\d{3}", "AD500", AsIs),
+    format("AI", r"AI-2640", "AI-2640", AsIs),
+    format("AR", r"[A-Z]?\d{4}([A-Z]{3})?", "C1425DKF", AsIs),
+    format("AM", r"\d{4}", "0010", AsIs),
+    format("AU", r"\d{4}", "2000", AsIs),
+    format("AT", r"\d{4}", "1010", AsIs),
+    format("AZ", r"(AZ ?)?\d{4}", "AZ 1000", AsIs),
+    format("BH", r"\d{3,4}", "317", AsIs),
+    format("BD", r"\d{4}", "1000", AsIs),
+    format("BB", r"(BB)?\d{5}", "BB11000", AsIs),
+    format("BY", r"\d{6}", "220050", AsIs),
+    format("BE", r"\d{4}", "1000", AsIs),
+    format("BM", r"[A-Z]{2} [A-Z\d]{2}", "HM 12", Separator(' ', 2)),
+    format("BT", r"\d{5}", "11001", AsIs),
+    format("BA", r"\d{5}", "71000", AsIs),
+    format("BR", r"\d{5}-\d{3}", "01310-100", Separator('-', 3)),
+    format("IO", r"BBND 1ZZ", "BBND 1ZZ", Separator(' ', 3)),
+    format("BN", r"[A-Z]{2}\d{4}", "BS8811", AsIs),
+    format("BG", r"\d{4}", "1000", AsIs),
+    format("KH", r"\d{5,6}", "120101", AsIs),
+    format(
+        "CA",
+        r"[ABCEGHJ-NPRSTVXY]\d[ABCEGHJ-NPRSTV-Z] \d[ABCEGHJ-NPRSTV-Z]\d",
+        "K1A 0B1",
+        Separator(' ', 3),
+    ),
+    format("CV", r"\d{4}", "7600", AsIs),
+    format("KY", r"KY\d-\d{4}", "KY1-1100", Separator('-', 4)),
+    format("CL", r"\d{7}", "8320000", AsIs),
+    format("CN", r"\d{6}", "100000", AsIs),
+    format("CX", r"6798", "6798", AsIs),
+    format("CC", r"6799", "6799", AsIs),
+    format("CO", r"\d{6}", "110111", AsIs),
+    format("CR", r"\d{5}", "10101", AsIs),
+    format("HR", r"\d{5}", "10000", AsIs),
+    format("CU", r"\d{5}", "10400", AsIs),
+    format("CY", r"\d{4}", "1010", AsIs),
+    format("CZ", r"\d{3} \d{2}", "110 00", Separator(' ', 2)),
+    format("DK", r"\d{4}", "1050", AsIs),
+    format("DO", r"\d{5}", "10101", AsIs),
+    format("EC", r"\d{6}", "170150", AsIs),
+    format("EG", r"\d{5}", "11511", AsIs),
+    format("SV", r"\d{4}", "1101", AsIs),
+    format("EE", r"\d{5}", "10111", AsIs),
+    format("ET", r"\d{4}", "1000", AsIs),
+    format("FK", r"FIQQ 1ZZ", "FIQQ 1ZZ", Separator(' ', 3)),
+    format("FO", r"\d{3}", "100", AsIs),
+    format("FI", r"\d{5}", "00100", AsIs),
+    format("FR", r"\d{5}", "75008", AsIs),
+    format("GF", r"973\d{2}", "97300", AsIs),
+    format("PF", r"987\d{2}", "98714", AsIs),
+    format("GE", r"\d{4}", "0105", AsIs),
+    format("DE", r"\d{5}", "10115", AsIs),
+    format("GI", r"GX11 1AA", "GX11 1AA", Separator(' ', 3)),
+    format("GR", r"\d{3} \d{2}", "105 57", Separator(' ', 2)),
+    format("GL", r"39\d{2}", "3900", AsIs),
+    format("GP", r"971\d{2}", "97100", AsIs),
+    format("GU", r"969\d{2}(-\d{4})?", "96910", AsIs),
+    format("GT", r"\d{5}", "01001", AsIs),
+    format(
+        "GG",
+        r"GY\d[\dA-Z]? \d[A-Z]{2}",
+        "GY1 1AA",
+        Separator(' ', 3),
+    ),
+    format("GN", r"\d{3}", "001", AsIs),
+    format("GW", r"\d{4}", "1000", AsIs),
+    format("HT", r"\d{4}", "6110", AsIs),
+    format("HM", r"7151", "7151", AsIs),
+    format("VA", r"00120", "00120", AsIs),
+    format("HN", r"\d{5}", "11101", AsIs),
+    format("HU", r"\d{4}", "1051", AsIs),
+    format("IS", r"\d{3}", "101", AsIs),
+    format("IN", r"[1-9]\d{5}", "110001", AsIs),
+    format("ID", r"\d{5}", "10110", AsIs),
+    format("IR", r"\d{5}-\d{5}", "11369-37511", Separator('-', 5)),
+    format("IQ", r"\d{5}", "10001", AsIs),
+    format(
+        "IE",
+        r"([AC-FHKNPRTV-Y]\d{2}|D6W) [0-9AC-FHKNPRTV-Y]{4}",
+        "D02 X285",
+        Separator(' ', 4),
+    ),
+    format("IM", r"IM\d\d? \d[A-Z]{2}", "IM1 1AA", Separator(' ', 3)),
+    format("IL", r"\d{7}", "6100000", AsIs),
+    format("IT", r"\d{5}", "00144", AsIs),
+    format("JP", r"\d{3}-\d{4}", "100-0001", Separator('-', 4)),
+    format("JE", r"JE\d \d[A-Z]{2}", "JE2 3AA", Separator(' ', 3)),
+    format("JO", r"\d{5}", "11118", AsIs),
+    format("KZ", r"\d{6}|[A-Z]\d{2}[A-Z]\d[A-Z]\d", "050000", AsIs),
+    format("KE", r"\d{5}", "00100", AsIs),
+    format("KR", r"\d{5}", "03051", AsIs),
+    format("KW", r"\d{5}", "13001", AsIs),
+    format("KG", r"\d{6}", "720001", AsIs),
+    format("LA", r"\d{5}", "01000", AsIs),
+    format("LV", r"LV-\d{4}", "LV-1050", Separator('-', 4)),
+    format("LB", r"\d{4}( \d{4})?", "1107 2810", AsIs),
+    format("LS", r"\d{3}", "100", AsIs),
+    format("LR", r"\d{4}", "1000", AsIs),
+    format("LI", r"94(8[5-9]|9[0-7])", "9490", AsIs),
+    format("LT", r"LT-\d{5}", "LT-01100", Separator('-', 5)),
+    format("LU", r"\d{4}", "1009", AsIs),
+    format("MK", r"\d{4}", "1000", AsIs),
+    format("MG", r"\d{3}", "101", AsIs),
+    format("MY", r"\d{5}", "50050", AsIs),
+    format("MV", r"\d{5}", "20026", AsIs),
+    format("MT", r"[A-Z]{3} \d{4}", "VLT 1117", Separator(' ', 4)),
+    format("MH", r"969\d{2}(-\d{4})?", "96960", AsIs),
+    format("MQ", r"972\d{2}", "97200", AsIs),
+    format("MU", r"\d{5}", "11302", AsIs),
+    format("YT", r"976\d{2}", "97600", AsIs),
+    format("MX", r"\d{5}", "06600", AsIs),
+    format("FM", r"969\d{2}(-\d{4})?", "96941", AsIs),
+    format("MD", r"(MD-)?\d{4}", "MD-2001", AsIs),
+    format("MC", r"980\d{2}", "98000", AsIs),
+    format("MN", r"\d{5}", "14200", AsIs),
+    format("ME", r"\d{5}", "81000", AsIs),
+    format("MS", r"MSR \d{4}", "MSR 1110", Separator(' ', 4)),
+    format("MA", r"\d{5}", "10000", AsIs),
+    format("MZ", r"\d{4}", "1100", AsIs),
+    format("MM", r"\d{5}", "11181", AsIs),
+    format("NA", r"\d{5}", "10001", AsIs),
+    format("NP", r"\d{5}", "44600", AsIs),
+    format("NL", r"[1-9]\d{3} [A-Z]{2}", "1012 AB", Separator(' ', 2)),
+    format("NC", r"988\d{2}", "98800", AsIs),
+    format("NZ", r"\d{4}", "6011", AsIs),
+    format("NI", r"\d{5}", "11001", AsIs),
+    format("NE", r"\d{4}", "8001", AsIs),
+    format("NG", r"\d{6}", "100001", AsIs),
+    format("NF", r"2899", "2899", AsIs),
+    format("MP", r"9695\d(-\d{4})?", "96950", AsIs),
+    format("NO", r"\d{4}", "0150", AsIs),
+    format("OM", r"\d{3}", "100", AsIs),
+    format("PK", r"\d{5}", "44000", AsIs),
+    format("PW", r"969\d{2}(-\d{4})?", "96940", AsIs),
+    format("PS", r"P?\d{3,5}", "P3000", AsIs),
+    format("PA", r"\d{4}", "0801", AsIs),
+    format("PG", r"\d{3}", "111", AsIs),
+    format("PY", r"\d{4}", "1209", AsIs),
+    format("PE", r"\d{5}", "15001", AsIs),
+    format("PH", r"\d{4}", "1000", AsIs),
+    format("PN", r"PCRN 1ZZ", "PCRN 1ZZ", Separator(' ', 3)),
+    format("PL", r"\d{2}-\d{3}", "00-950", Separator('-', 3)),
+    format("PT", r"\d{4}-\d{3}", "1000-001", Separator('-', 3)),
+    format("PR", r"00[679]\d{2}(-\d{4})?", "00901", AsIs),
+    format("RE", r"974\d{2}", "97400", AsIs),
+    format("RO", r"\d{6}", "010011", AsIs),
+    format("RU", r"\d{6}", "101000", AsIs),
+    format("BL", r"97133", "97133", AsIs),
+    format("SH", r"(STHL|ASCN|TDCU) 1ZZ", "STHL 1ZZ", Separator(' ', 3)),
+    format("LC", r"LC\d{2} \d{3}", "LC01 101", Separator(' ', 3)),
+    format("MF", r"97150", "97150", AsIs),
+    format("PM", r"97500", "97500", AsIs),
+    format("VC", r"VC\d{4}", "VC0100", AsIs),
+    format("WS", r"WS\d{4}", "WS1382", AsIs),
+    format("SM", r"4789\d", "47890", AsIs),
+    format("SA", r"\d{5}(-\d{4})?", "11564", AsIs),
+    format("SN", r"\d{5}", "10200", AsIs),
+    format("RS", r"\d{5}", "11000", AsIs),
+    format("SG", r"\d{6}", "018956", AsIs),
+    format("SK", r"\d{3} \d{2}", "811 01", Separator(' ', 2)),
+    format("SI", r"\d{4}", "1000", AsIs),
+    format("SO", r"[A-Z]{2} \d{5}", "JH 09010", Separator(' ', 5)),
+    format("ZA", r"\d{4}", "0001", AsIs),
+    format("GS", r"SIQQ 1ZZ", "SIQQ 1ZZ", Separator(' ', 3)),
+    format("ES", r"(0[1-9]|[1-4]\d|5[0-2])\d{3}", "28013", AsIs),
+    format("LK", r"\d{5}", "00100", AsIs),
+    format("SD", r"\d{5}", "11111", AsIs),
+    format("SJ", r"\d{4}", "9170", AsIs),
+    format("SZ", r"[A-Z]\d{3}", "H100", AsIs),
+    format("SE", r"\d{3} \d{2}", "111 22", Separator(' ', 2)),
+    format("CH", r"\d{4}", "8001", AsIs),
+    format("TW", r"\d{3}(\d{2,3})?", "100", AsIs),
+    format("TJ", r"\d{6}", "734000", AsIs),
+    format("TZ", r"\d{5}", "11101", AsIs),
+    format("TH", r"\d{5}", "10200", AsIs),
+    format("TT", r"\d{6}", "100101", AsIs),
+    format("TN", r"\d{4}", "1000", AsIs),
+    format("TR", r"\d{5}", "34000", AsIs),
+    format("TM", r"\d{6}", "744000", AsIs),
+    format("TC", r"TKCA 1ZZ", "TKCA 1ZZ", Separator(' ', 3)),
+    format("UA", r"\d{5}", "01001", AsIs),
+    format("GB", UK_PATTERN, "SW1A 1AA", Separator(' ', 3)),
+    format("US", US_PATTERN, "10001", AsIs),
+    format("UM", r"96898", "96898", AsIs),
+    format("UY", r"\d{5}", "11000", AsIs),
+    format("UZ", r"\d{6}", "100000", AsIs),
+    format("VE", r"\d{4}", "1010", AsIs),
+    format("VN", r"\d{6}", "100000", AsIs),
+    format("VG", r"VG11[1-6]0", "VG1110", AsIs),
+    format("VI", r"008[0-5]\d(-\d{4})?", "00802", AsIs),
+    format("WF", r"986\d{2}", "98600", AsIs),
+    format("EH", r"\d{5}", "70000", AsIs),
+    format("ZM", r"\d{5}", "10101", AsIs),
+];
+
+/// Countries which do not use postal codes. Empty value is valid for them.
+pub const COUNTRIES_WITHOUT_ZIP_CODE: &[&str] = &[
+    "AO", "AQ", "AG", "AW", "BS", "BZ", "BJ", "BO", "BQ", "BW", "BV", "BF", "BI", "CM", "CF", "TD",
+    "KM", "CG", "CD", "CK", "CI", "CW", "DJ", "DM", "GQ", "ER", "FJ", "TF", "GA", "GM", "GH", "GD",
+    "GY", "HK", "JM", "KI", "KP", "LY", "MO", "MW", "ML", "MR", "NR", "NU", "QA", "RW", "KN", "ST",
+    "SC", "SL", "SX", "SB", "SS", "SR", "SY", "TL", "TG", "TK", "TO", "TV", "UG", "AE", "VU", "YE",
+    "ZW",
+];